@@ -10,7 +10,8 @@
 #![allow(clippy::uninlined_format_args)]
 #![allow(unused_imports)]
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
 use std::{env, fs, path::PathBuf, process::Command};
 use tempfile::TempDir;
 
@@ -10,11 +10,12 @@
 #![allow(clippy::uninlined_format_args)]
 #![allow(unused_imports)]
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
 use quickstart_lib::{
-    template::{TemplateLoader, TemplateVariant},
+    template::{TemplateEngine, TemplateLoader, TemplateVariables, TemplateVariant},
     ProjectConfig, ProjectType,
 };
+use std::hint::black_box;
 use std::{env, path::PathBuf};
 
 /// Get the workspace root directory
@@ -41,17 +42,15 @@ fn workspace_root() -> PathBuf {
 
 /// Benchmark template listing performance
 fn benchmark_template_listing(c: &mut Criterion) {
-    // Find the templates directory
-    let template_dir = match quickstart_lib::find_templates_dir() {
-        Ok(dir) => dir,
+    // Find the templates, falling back to the bundled set
+    let loader = match quickstart_lib::find_template_loader() {
+        Ok(loader) => loader,
         Err(e) => {
-            eprintln!("Error finding templates directory: {e}");
+            eprintln!("Error finding templates: {e}");
             return;
         }
     };
 
-    let loader = TemplateLoader::new(template_dir);
-
     c.bench_function("list_templates", |b| {
         b.iter(|| {
             // Use black_box to prevent compiler optimizations from skewing results
@@ -69,12 +68,10 @@ fn benchmark_template_listing(c: &mut Criterion) {
 fn setup_project_config() -> ProjectConfig {
     ProjectConfig {
         name: "benchmark-project".to_string(),
-        project_type: ProjectType::Binary,
-        edition: "2021".to_string(),
         license: "MIT".to_string(),
-        git: false,
         path: PathBuf::from("/tmp/bench"),
         yes: true,
+        ..Default::default()
     }
 }
 
@@ -82,16 +79,14 @@ fn setup_project_config() -> ProjectConfig {
 fn benchmark_template_rendering(c: &mut Criterion) {
     let mut group = c.benchmark_group("Template Rendering");
 
-    // Find the templates directory
-    let template_dir = match quickstart_lib::find_templates_dir() {
-        Ok(dir) => dir,
+    // Find the templates, falling back to the bundled set
+    let loader = match quickstart_lib::find_template_loader() {
+        Ok(loader) => loader,
         Err(e) => {
-            eprintln!("Error finding templates directory: {e}");
+            eprintln!("Error finding templates: {e}");
             return;
         }
     };
-
-    let loader = TemplateLoader::new(template_dir);
     let _config = setup_project_config();
 
     // Benchmark the README template which should be in all projects
@@ -130,9 +125,53 @@ fn benchmark_template_rendering(c: &mut Criterion) {
     group.finish();
 }
 
+/// Compare compiling templates on every render with compiling them once by name
+fn benchmark_template_compilation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Template Compilation");
+
+    // A large synthetic template set exercising conditionals and helpers
+    let templates: Vec<(String, String)> = (0..200)
+        .map(|i| {
+            (
+                format!("base/file_{i}.md"),
+                format!(
+                    "# {{{{name}}}} {i}\n{{{{#if project.is_binary}}}}bin{{{{else}}}}lib{{{{/if}}}} \
+                     {{{{snake_case name}}}} {{{{#if (eq edition \"2021\")}}}}2021{{{{/if}}}}\n"
+                ),
+            )
+        })
+        .collect();
+    let variables = TemplateVariables::from_config(&setup_project_config());
+
+    // Every render compiles its template again
+    let one_off = TemplateEngine::new(variables.clone());
+    group.bench_function("render_template_each_time", |b| {
+        b.iter(|| {
+            for (_, content) in &templates {
+                black_box(one_off.render_template(content).unwrap());
+            }
+        })
+    });
+
+    // Templates are compiled once up front and rendered by name
+    let mut registered = TemplateEngine::new(variables);
+    for (name, content) in &templates {
+        registered.register_template(name, content).unwrap();
+    }
+    group.bench_function("render_named_precompiled", |b| {
+        b.iter(|| {
+            for (name, _) in &templates {
+                black_box(registered.render_named(name).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     name = template_benches;
     config = Criterion::default().sample_size(20);
-    targets = benchmark_template_listing, benchmark_template_rendering
+    targets = benchmark_template_listing, benchmark_template_rendering, benchmark_template_compilation
 );
 criterion_main!(template_benches);
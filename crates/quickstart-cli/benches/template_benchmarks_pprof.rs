@@ -9,9 +9,10 @@
 #![allow(clippy::uninlined_format_args)]
 #![allow(unused_imports)]
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
 use pprof::criterion::Output;
 use pprof::criterion::PProfProfiler;
+use std::hint::black_box;
 use std::path::PathBuf;
 
 // Benchmark template loading and rendering
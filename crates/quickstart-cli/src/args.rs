@@ -2,6 +2,7 @@
 
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
+use quickstart_lib::{DependencySpec, ProjectType};
 use std::path::PathBuf;
 
 /// A cargo subcommand for quickly generating opinionated Rust projects
@@ -14,11 +15,46 @@ use std::path::PathBuf;
     long_about = "A blazing fast and opinionated cargo subcommand for bootstrapping modern Rust projects with confidence and speed.\n\nEXAMPLES:\n  cargo quickstart new my-app --bin --git\n  cargo quickstart init --lib --name my-lib --git\n\nSee https://github.com/smeya/cargo-quickstart for more info."
 )]
 pub struct Cli {
+    /// Show internal details such as template discovery
+    #[arg(
+        short,
+        long,
+        global = true,
+        conflicts_with = "quiet",
+        help = "Show verbose output (template discovery, internals)"
+    )]
+    pub verbose: bool,
+
+    /// Only report errors
+    #[arg(short, long, global = true, help = "Only report errors")]
+    pub quiet: bool,
+
+    /// Avoid anything that needs network access
+    #[arg(
+        long,
+        global = true,
+        help = "Work offline (doctor skips checks that need the network, --template-repo only uses cached clones)"
+    )]
+    pub offline: bool,
+
     /// The subcommand to execute
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Log level selected by the `--verbose`/`--quiet` flags
+    pub const fn log_level(&self) -> log::LevelFilter {
+        if self.verbose {
+            log::LevelFilter::Debug
+        } else if self.quiet {
+            log::LevelFilter::Error
+        } else {
+            log::LevelFilter::Warn
+        }
+    }
+}
+
 /// Subcommands supported by cargo-quickstart
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -38,19 +74,47 @@ pub enum Commands {
     )]
     Init(InitArgs),
 
+    /// Add templates to an existing project
+    #[command(
+        name = "add",
+        about = "Add templates (e.g. .gitignore, .vscode) to an existing project"
+    )]
+    Add(AddArgs),
+
     /// List all available project templates
     #[command(
         name = "list-templates",
         visible_alias = "ls",
         about = "List all available project templates"
     )]
-    ListTemplates,
+    ListTemplates(ListTemplatesArgs),
+
+    /// List the optional features and the templates each one enables
+    #[command(
+        name = "list-features",
+        about = "List the optional features and the templates each one enables"
+    )]
+    ListFeatures(ListFeaturesArgs),
+
+    /// Check a templates tree for errors without generating a project
+    #[command(
+        name = "validate-templates",
+        about = "Check templates for syntax errors, missing variables and destination collisions"
+    )]
+    ValidateTemplates(ValidateTemplatesArgs),
+
+    /// Manage templates fetched from remote repositories
+    #[command(
+        name = "cache",
+        about = "Manage templates fetched with --template-repo"
+    )]
+    Cache(CacheArgs),
 
     /// Generate shell completion scripts for your shell
     #[cfg(feature = "completions")]
     #[command(
         name = "completions",
-        about = "Generate shell completion scripts for your shell (bash, zsh, fish, powershell, elvish)",
+        about = "Generate shell completion scripts for your shell (bash, zsh, fish, powershell, elvish, nu)",
         long_about = "Generate shell completion scripts for your shell. Example: cargo quickstart completions bash > /usr/local/etc/bash_completion.d/cargo-quickstart"
     )]
     Completions(CompletionsArgs),
@@ -62,44 +126,52 @@ pub enum Commands {
         about = "Diagnose common project issues and misconfigurations",
         long_about = "Run a series of checks to validate your Rust project, templates, and environment. Reports missing files, misconfigurations, and actionable suggestions."
     )]
-    Doctor,
+    Doctor(DoctorArgs),
 }
 
 /// Arguments for the 'new' command
 #[derive(Args, Debug)]
 pub struct NewArgs {
     /// Name of the new project (directory will be created)
-    #[arg(help = "Name of the new project (directory will be created)")]
-    pub name: String,
-
-    /// Create a binary application (default if neither --bin nor --lib is specified)
     #[arg(
-        long,
-        help = "Create a binary (application) project",
-        conflicts_with = "lib"
+        help = "Name of the new project (directory will be created)",
+        value_parser = validate_name
     )]
-    pub bin: bool,
+    pub name: String,
 
-    /// Create a library crate
+    /// Project type: `--type`, or its `--bin`/`--lib` shorthands
+    #[command(flatten)]
+    pub kind: ProjectTypeArgs,
+
+    /// Name of the binary target, when it should differ from the package name
     #[arg(
         long,
-        help = "Create a library (crate) project",
-        conflicts_with = "bin"
+        value_name = "NAME",
+        help = "Name of the binary target (default: the project name)",
+        value_parser = validate_name
     )]
-    pub lib: bool,
+    pub bin_name: Option<String>,
 
     /// Rust edition to use (default: 2021)
     #[arg(long, help = "Rust edition (default: 2021)", default_value = "2021", value_parser = validate_edition)]
     pub edition: String,
 
-    /// License to apply (MIT, Apache-2.0, or dual MIT/Apache-2.0)
+    /// License to apply: a common choice such as MIT, or any SPDX expression
     #[arg(long, help = "License to use (default: MIT OR Apache-2.0)", default_value = "MIT OR Apache-2.0", value_parser = validate_license)]
     pub license: String,
 
     /// Initialise a Git repository
-    #[arg(long, help = "Initialise a Git repository")]
+    #[arg(long, help = "Initialise a Git repository", overrides_with = "no_git")]
     pub git: bool,
 
+    /// Skip Git initialisation, overriding a defaulted-on `--git`
+    #[arg(
+        long,
+        help = "Do not initialise a Git repository",
+        overrides_with = "git"
+    )]
+    pub no_git: bool,
+
     /// Target directory (defaults to the project name in the current directory)
     #[arg(
         long,
@@ -110,43 +182,129 @@ pub struct NewArgs {
     /// Accept all defaults without prompting
     #[arg(short, long, help = "Accept all defaults without prompting")]
     pub yes: bool,
-}
 
-/// Arguments for the 'init' command
-#[derive(Args, Debug)]
-pub struct InitArgs {
-    /// Create a binary application (default if neither --bin nor --lib is specified)
+    /// Preview the generated files without writing anything
     #[arg(
         long,
-        help = "Create a binary (application) project",
-        conflicts_with = "lib"
+        help = "Show the files that would be created without writing them"
     )]
-    pub bin: bool,
+    pub dry_run: bool,
+
+    /// Package metadata, optional features, toolchain and seeded dependencies
+    #[command(flatten)]
+    pub options: ProjectOptionsArgs,
 
-    /// Create a library crate
+    /// Template to generate from (default: extended)
     #[arg(
         long,
-        help = "Create a library (crate) project",
-        conflicts_with = "bin"
+        value_name = "NAME",
+        help = "Template to generate from, e.g. minimal or extended (default: extended)"
     )]
-    pub lib: bool,
+    #[cfg_attr(
+        feature = "completions",
+        arg(add = clap_complete::engine::ArgValueCompleter::new(
+            crate::commands::complete_template_name
+        ))
+    )]
+    pub template: Option<String>,
+
+    /// Git repository whose `templates/` directory replaces the local templates
+    #[arg(
+        long,
+        value_name = "GIT_URL",
+        help = "Generate from the templates/ directory of this git repository"
+    )]
+    pub template_repo: Option<String>,
+
+    /// Branch or tag of `--template-repo` to use
+    #[arg(
+        long,
+        value_name = "REF",
+        requires = "template_repo",
+        help = "Branch or tag of --template-repo (default: its default branch)"
+    )]
+    pub template_ref: Option<String>,
+
+    /// Fetch `--template-repo` again even if it is cached
+    #[arg(
+        long,
+        requires = "template_repo",
+        help = "Fetch --template-repo again instead of reusing the cached copy"
+    )]
+    pub refresh_templates: bool,
+
+    /// Values for the placeholders of a cargo-generate template
+    #[arg(
+        short,
+        long = "define",
+        value_name = "NAME=VALUE",
+        value_parser = parse_define,
+        help = "Set a cargo-generate template placeholder, e.g. --define gh-username=octocat"
+    )]
+    pub define: Vec<(String, String)>,
+
+    /// Write a JSON manifest of the generated project to this file
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "dry_run",
+        help = "Write the generated files, config and Git status as JSON to this file"
+    )]
+    pub emit_manifest: Option<PathBuf>,
+
+    /// Output format; `json` prints the generation report instead of progress output
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Human,
+        conflicts_with = "dry_run",
+        help = "Output format (human, json)"
+    )]
+    pub format: OutputFormat,
+}
+
+impl NewArgs {
+    /// Whether to initialise Git, falling back to the default when no flag is given
+    pub fn use_git(&self) -> bool {
+        resolve_git(self.git, self.no_git, GIT_DEFAULT)
+    }
+}
+
+/// Arguments for the 'init' command
+#[derive(Args, Debug)]
+pub struct InitArgs {
+    /// Project type: `--type`, or its `--bin`/`--lib` shorthands
+    #[command(flatten)]
+    pub kind: ProjectTypeArgs,
 
     /// Name of the project (defaults to directory name)
-    #[arg(long, help = "Project name (defaults to directory name)")]
+    #[arg(
+        long,
+        help = "Project name (defaults to directory name)",
+        value_parser = validate_name
+    )]
     pub name: Option<String>,
 
     /// Rust edition to use (default: 2021)
     #[arg(long, help = "Rust edition (default: 2021)", default_value = "2021", value_parser = validate_edition)]
     pub edition: String,
 
-    /// License to apply (MIT, Apache-2.0, or dual MIT/Apache-2.0)
+    /// License to apply: a common choice such as MIT, or any SPDX expression
     #[arg(long, help = "License to use (default: MIT OR Apache-2.0)", default_value = "MIT OR Apache-2.0", value_parser = validate_license)]
     pub license: String,
 
     /// Initialise a Git repository
-    #[arg(long, help = "Initialise a Git repository")]
+    #[arg(long, help = "Initialise a Git repository", overrides_with = "no_git")]
     pub git: bool,
 
+    /// Skip Git initialisation, overriding a defaulted-on `--git`
+    #[arg(
+        long,
+        help = "Do not initialise a Git repository",
+        overrides_with = "git"
+    )]
+    pub no_git: bool,
+
     /// Target directory (defaults to the current directory)
     #[arg(
         long,
@@ -159,22 +317,398 @@ pub struct InitArgs {
     #[arg(short, long, help = "Accept all defaults without prompting")]
     pub yes: bool,
 
+    /// Preview the generated files without writing anything
+    #[arg(
+        long,
+        help = "Show the files that would be created without writing them"
+    )]
+    pub dry_run: bool,
+
+    /// Package metadata, optional features, toolchain and seeded dependencies
+    #[command(flatten)]
+    pub options: ProjectOptionsArgs,
+
     /// Use interactive wizard mode
     #[arg(long, help = "Use enhanced interactive wizard", conflicts_with = "yes")]
     pub interactive: bool,
+
+    /// Ignore (and do not update) the answers saved by previous wizard runs
+    #[arg(
+        long,
+        requires = "interactive",
+        help = "Do not load or save wizard defaults from previous runs"
+    )]
+    pub no_saved_defaults: bool,
+
+    /// Rename files that would be overwritten to `<file>.bak` first
+    #[arg(
+        long,
+        conflicts_with = "interactive",
+        help = "Back up files that would be overwritten to <file>.bak (the wizard asks per file)"
+    )]
+    pub backup: bool,
+
+    /// Print a diff of the files that would change instead of writing them
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "dry_run"],
+        help = "Show a unified diff against existing files without writing anything"
+    )]
+    pub diff: bool,
+}
+
+impl InitArgs {
+    /// Whether to initialise Git, falling back to the default when no flag is given
+    pub fn use_git(&self) -> bool {
+        resolve_git(self.git, self.no_git, GIT_DEFAULT)
+    }
+}
+
+/// Arguments for the 'add' command
+#[derive(Args, Debug)]
+pub struct AddArgs {
+    /// Template to add, as a destination file or directory (e.g. .gitignore, .vscode)
+    #[arg(help = "Template to add, as a destination file or directory (e.g. .gitignore, .vscode)")]
+    pub template: String,
+
+    /// Target project directory (defaults to the current directory)
+    #[arg(
+        long,
+        help = "Target project directory (defaults to current directory)",
+        default_value = "."
+    )]
+    pub path: PathBuf,
+
+    /// Overwrite files that already exist
+    #[arg(long, help = "Overwrite files that already exist")]
+    pub force: bool,
+}
+
+/// Arguments for the 'list-features' command
+#[derive(Args, Debug)]
+pub struct ListFeaturesArgs {
+    /// Output format for the feature listing
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Human,
+        help = "Output format (human, json)"
+    )]
+    pub format: OutputFormat,
+}
+
+/// Arguments for the 'validate-templates' command
+#[derive(Args, Debug)]
+pub struct ValidateTemplatesArgs {
+    /// Templates directory to validate (defaults to the discovered templates)
+    #[arg(
+        long,
+        help = "Templates directory to validate (defaults to the discovered templates)"
+    )]
+    pub path: Option<PathBuf>,
+}
+
+/// Arguments for the 'cache' command
+#[derive(Args, Debug)]
+pub struct CacheArgs {
+    /// The cache operation to run
+    #[command(subcommand)]
+    pub command: CacheCommand,
+}
+
+/// Operations on the template cache
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheCommand {
+    /// Delete all cached template repositories
+    #[command(about = "Delete all cached template repositories")]
+    Clear,
+}
+
+/// Arguments for the 'list-templates' command
+#[derive(Args, Debug)]
+pub struct ListTemplatesArgs {
+    /// Output format for the template listing
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Human,
+        help = "Output format (human, json)"
+    )]
+    pub format: OutputFormat,
+
+    /// Show details for each template in the human-readable listing
+    #[arg(
+        long,
+        help = "Show each template's type, variant, source and destination path"
+    )]
+    pub long: bool,
+}
+
+/// Project type selection shared by 'new' and 'init'
+///
+/// `--bin` and `--lib` may be combined to create both; `--type` stands alone.
+#[derive(Args, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProjectTypeArgs {
+    /// Project type to create
+    #[arg(
+        long = "type",
+        value_enum,
+        value_name = "TYPE",
+        conflicts_with_all = ["bin", "lib"],
+        help = "Project type: binary, library or both (default: binary)"
+    )]
+    pub project_type: Option<ProjectType>,
+
+    /// Shorthand for `--type binary`
+    #[arg(
+        long,
+        help = "Create a binary (application) project; same as --type binary, or --type both with --lib"
+    )]
+    pub bin: bool,
+
+    /// Shorthand for `--type library`
+    #[arg(
+        long,
+        help = "Create a library (crate) project; same as --type library, or --type both with --bin"
+    )]
+    pub lib: bool,
+}
+
+impl ProjectTypeArgs {
+    /// The project type chosen on the command line, if any
+    pub fn selected(&self) -> Option<ProjectType> {
+        match (self.bin, self.lib) {
+            (true, true) => Some(ProjectType::Both),
+            (false, true) => Some(ProjectType::Library),
+            (true, false) => Some(ProjectType::Binary),
+            (false, false) => self.project_type,
+        }
+    }
+}
+
+impl From<ProjectType> for ProjectTypeArgs {
+    fn from(project_type: ProjectType) -> Self {
+        Self {
+            project_type: Some(project_type),
+            ..Self::default()
+        }
+    }
+}
+
+/// Package metadata, optional features and dependencies shared by 'new' and 'init'
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct ProjectOptionsArgs {
+    /// Short description used in Cargo.toml and the README
+    #[arg(long, value_name = "TEXT", help = "Project description")]
+    pub description: Option<String>,
+
+    /// Author name for Cargo.toml and the license
+    #[arg(long, value_name = "NAME", help = "Author name")]
+    pub author_name: Option<String>,
+
+    /// Author email for Cargo.toml
+    #[arg(long, value_name = "EMAIL", help = "Author email")]
+    pub author_email: Option<String>,
+
+    /// Optional features to include instead of the defaults
+    #[arg(
+        long,
+        value_name = "FEATURES",
+        value_delimiter = ',',
+        value_parser = validate_feature,
+        help = "Comma-separated optional features to include (see list-features)"
+    )]
+    pub features: Option<Vec<String>>,
+
+    /// Toolchain channel to pin in rust-toolchain.toml
+    #[arg(
+        long,
+        value_name = "CHANNEL",
+        value_parser = validate_toolchain,
+        help = "Pin a toolchain in rust-toolchain.toml, e.g. stable or 1.75.0"
+    )]
+    pub toolchain: Option<String>,
+
+    /// Minimum supported Rust version to declare in Cargo.toml
+    #[arg(
+        long,
+        value_name = "VERSION",
+        value_parser = validate_msrv,
+        help = "Set rust-version in Cargo.toml, e.g. 1.74.0"
+    )]
+    pub msrv: Option<String>,
+
+    /// crates.io keywords to list in Cargo.toml
+    #[arg(
+        long = "keyword",
+        value_name = "KEYWORD",
+        value_parser = validate_keyword,
+        help = "Add a crates.io keyword to Cargo.toml (repeatable, at most 5)"
+    )]
+    pub keywords: Vec<String>,
+
+    /// crates.io categories to list in Cargo.toml
+    #[arg(
+        long = "category",
+        value_name = "SLUG",
+        value_parser = validate_category,
+        help = "Add a crates.io category to Cargo.toml, e.g. command-line-utilities (repeatable)"
+    )]
+    pub categories: Vec<String>,
+
+    /// Crates to add to `[dependencies]`
+    #[arg(
+        long = "dep",
+        value_name = "NAME[@VERSION]",
+        value_parser = parse_dependency,
+        help = "Add a dependency, e.g. serde@1; without a version any (*) is allowed (repeatable)"
+    )]
+    pub dependencies: Vec<DependencySpec>,
+
+    /// Crates to add to `[dev-dependencies]`
+    #[arg(
+        long = "dev-dep",
+        value_name = "NAME[@VERSION]",
+        value_parser = parse_dependency,
+        help = "Add a dev-dependency, e.g. proptest@1 (repeatable)"
+    )]
+    pub dev_dependencies: Vec<DependencySpec>,
+
+    /// Add `--dep`/`--dev-dep` entries with `cargo add` so their versions are current
+    #[arg(
+        long,
+        help = "Add --dep and --dev-dep entries with cargo add after generating (skipped with --offline)"
+    )]
+    pub cargo_add: bool,
+}
+
+/// Output formats for commands that support machine-readable output
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Decorated output intended for humans
+    #[default]
+    Human,
+    /// JSON output intended for tooling
+    Json,
 }
 
 /// Arguments for the 'completions' command
 #[cfg(feature = "completions")]
 #[derive(Args, Debug)]
 pub struct CompletionsArgs {
-    /// The shell to generate completions for (bash, zsh, fish, powershell, elvish)
-    #[arg(value_enum, help = "Shell type (bash, zsh, fish, powershell, elvish)")]
+    /// The shell to generate completions for (bash, zsh, fish, powershell, elvish, nu)
+    #[arg(
+        value_enum,
+        help = "Shell type (bash, zsh, fish, powershell, elvish, nu)"
+    )]
     pub shell: Shell,
 
     /// Output file path (optional, defaults to stdout)
     #[arg(long, help = "Output file path (optional, defaults to stdout)")]
     pub output: Option<PathBuf>,
+
+    /// Install the script into the shell's conventional completions directory
+    #[arg(
+        long,
+        conflicts_with = "output",
+        help = "Install into the shell's conventional completions directory"
+    )]
+    pub install: bool,
+
+    /// Directory to install into instead of the shell's default location
+    #[arg(
+        long,
+        requires = "install",
+        value_name = "DIR",
+        help = "Install into this directory instead of the default location"
+    )]
+    pub install_dir: Option<PathBuf>,
+}
+
+/// Arguments for the 'doctor' command
+#[cfg(feature = "doctor")]
+#[derive(Args, Debug, Default)]
+pub struct DoctorArgs {
+    /// Project directory to diagnose
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Project directory to diagnose (default: current directory)"
+    )]
+    pub path: Option<PathBuf>,
+
+    /// Print the report as JSON instead of decorated text
+    #[arg(long, help = "Print the report as JSON instead of decorated text")]
+    pub json: bool,
+
+    /// Treat warnings as failures
+    #[arg(long, help = "Exit with an error when warnings are found")]
+    pub strict: bool,
+
+    /// Automatically fix issues where possible before reporting
+    #[arg(
+        long,
+        help = "Automatically fix issues where possible (e.g. missing .gitignore)"
+    )]
+    pub fix: bool,
+
+    /// Only run checks in these categories
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "CATEGORY",
+        help = "Only run checks in these categories (files, metadata, msrv, edition, rust, lints, format, dependencies, security, tests, ci, templates)"
+    )]
+    pub only: Vec<String>,
+
+    /// Skip checks in these categories
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "CATEGORY",
+        help = "Skip checks in these categories"
+    )]
+    pub skip: Vec<String>,
+
+    /// Seconds a check may take before it is reported as slow
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Flag checks slower than this many seconds (default: 5)"
+    )]
+    pub slow_threshold: Option<u64>,
+
+    /// Re-run the dependency tools even if Cargo.lock is unchanged
+    #[arg(
+        long,
+        help = "Ignore cached cargo outdated/udeps results and run them again"
+    )]
+    pub no_cache: bool,
+
+    /// Seconds cargo outdated/udeps may run before they are killed
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Kill cargo outdated/udeps after this many seconds (default: 120)"
+    )]
+    pub tool_timeout: Option<u64>,
+
+    /// Write the clippy findings of the lints check as a SARIF report
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write clippy findings to this file as SARIF (for CI annotations)"
+    )]
+    pub sarif: Option<PathBuf>,
+
+    /// Lowest score for each health rating, overriding the user config file
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "SCORES",
+        help = "Minimum scores for excellent,good,moderate,needs-attention (default: 90,70,50,30)"
+    )]
+    pub health_thresholds: Vec<u8>,
 }
 
 /// Supported shells for completions
@@ -191,6 +725,8 @@ pub enum Shell {
     Powershell,
     /// Elvish shell completion script generation
     Elvish,
+    /// Nushell completion script generation
+    Nu,
 }
 
 #[cfg(feature = "completions")]
@@ -202,28 +738,106 @@ impl std::fmt::Display for Shell {
             Shell::Fish => "fish",
             Shell::Powershell => "powershell",
             Shell::Elvish => "elvish",
+            Shell::Nu => "nu",
         };
         write!(f, "{s}")
     }
 }
 
+/// Whether Git is initialised when neither `--git` nor `--no-git` is passed
+const GIT_DEFAULT: bool = false;
+
+/// Resolve the `--git`/`--no-git` pair; clap keeps only the last flag given
+const fn resolve_git(git: bool, no_git: bool, default: bool) -> bool {
+    if git {
+        true
+    } else if no_git {
+        false
+    } else {
+        default
+    }
+}
+
+/// Validate a project name against Cargo's crate name rules
+fn validate_name(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_crate_name(val).map(|()| val.to_string())
+}
+
 /// Validate Rust edition
 fn validate_edition(val: &str) -> Result<String, String> {
-    match val {
-        "2015" | "2018" | "2021" | "2024" => Ok(val.to_string()),
-        _ => Err("Invalid edition: must be one of 2015, 2018, 2021, 2024".to_string()),
+    if quickstart_lib::RUST_EDITIONS.contains(&val) {
+        Ok(val.to_string())
+    } else {
+        let mut editions = quickstart_lib::RUST_EDITIONS;
+        editions.sort_unstable();
+        Err(format!(
+            "Invalid edition: must be one of {}",
+            editions.join(", ")
+        ))
     }
 }
 
-/// Validate license identifier
-fn validate_license(val: &str) -> Result<String, String> {
-    match val {
-        "MIT" | "Apache-2.0" | "MIT OR Apache-2.0" => Ok(val.to_string()),
-        _ => Err("Invalid license: must be MIT, Apache-2.0, or MIT OR Apache-2.0".to_string()),
+/// Validate an optional feature name
+fn validate_feature(val: &str) -> Result<String, String> {
+    let val = val.trim();
+    if quickstart_lib::template::FEATURES
+        .iter()
+        .any(|feature| feature.name == val)
+    {
+        Ok(val.to_string())
+    } else {
+        let names: Vec<&str> = quickstart_lib::template::FEATURES
+            .iter()
+            .map(|feature| feature.name)
+            .collect();
+        Err(format!(
+            "Unknown feature '{val}': must be one of {}",
+            names.join(", ")
+        ))
     }
 }
 
-#[cfg(test)]
+/// Validate a rustup toolchain channel
+fn validate_toolchain(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_toolchain(val).map(|()| val.to_string())
+}
+
+/// Validate a minimum supported Rust version
+fn validate_msrv(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_msrv(val).map(|()| val.to_string())
+}
+
+/// Validate a crates.io keyword
+fn validate_keyword(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_keyword(val).map(|()| val.to_string())
+}
+
+/// Validate a crates.io category slug
+fn validate_category(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_category(val).map(|()| val.to_string())
+}
+
+/// Parse a `NAME` or `NAME@VERSION` dependency
+fn parse_dependency(val: &str) -> Result<DependencySpec, String> {
+    val.parse()
+}
+
+/// Split a `NAME=VALUE` placeholder definition
+fn parse_define(val: &str) -> Result<(String, String), String> {
+    match val.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("Invalid definition '{val}': expected NAME=VALUE")),
+    }
+}
+
+/// Validate license identifier
+fn validate_license(val: &str) -> Result<String, String> {
+    quickstart_lib::validate_license(val).map(|()| val.to_string())
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
     use clap::CommandFactory;
@@ -241,7 +855,76 @@ mod tests {
         match cli.command {
             Commands::New(new_args) => {
                 assert_eq!(new_args.name, "my-project");
-                assert!(new_args.bin);
+                assert!(new_args.kind.bin);
+                assert_eq!(new_args.template, None);
+            }
+            _ => panic!("Expected New command"),
+        }
+    }
+
+    #[test]
+    fn test_project_type_option() {
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-lib", "--type", "library"]);
+        match cli.command {
+            Commands::New(new_args) => {
+                assert_eq!(new_args.kind.selected(), Some(ProjectType::Library));
+            }
+            _ => panic!("Expected New command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "init", "--type", "bin"]);
+        match cli.command {
+            Commands::Init(init_args) => {
+                assert_eq!(init_args.kind.selected(), Some(ProjectType::Binary));
+            }
+            _ => panic!("Expected Init command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app"]);
+        match cli.command {
+            Commands::New(new_args) => assert_eq!(new_args.kind.selected(), None),
+            _ => panic!("Expected New command"),
+        }
+    }
+
+    #[test]
+    fn test_project_type_both() {
+        for flags in [["--bin", "--lib"].as_slice(), &["--type", "both"]] {
+            let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app"].iter().chain(flags));
+            match cli.command {
+                Commands::New(new_args) => {
+                    assert_eq!(new_args.kind.selected(), Some(ProjectType::Both));
+                }
+                _ => panic!("Expected New command"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_project_type_flags_conflict() {
+        for flags in [
+            ["--type", "binary", "--lib"].as_slice(),
+            &["--type", "library", "--bin"],
+            &["--type", "both", "--bin", "--lib"],
+        ] {
+            let result =
+                Cli::try_parse_from(["cargo-quickstart", "new", "my-app"].iter().chain(flags));
+            assert!(result.is_err(), "{flags:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_new_command_with_template() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-project",
+            "--template",
+            "minimal",
+        ]);
+        match cli.command {
+            Commands::New(new_args) => {
+                assert_eq!(new_args.template, Some("minimal".to_string()));
             }
             _ => panic!("Expected New command"),
         }
@@ -253,7 +936,7 @@ mod tests {
         match cli.command {
             Commands::Init(init_args) => {
                 assert_eq!(init_args.name, Some("my-lib".to_string()));
-                assert!(!init_args.bin);
+                assert!(!init_args.kind.bin);
             }
             _ => panic!("Expected Init command"),
         }
@@ -298,20 +981,213 @@ mod tests {
         match cli.command {
             Commands::New(new_args) => {
                 assert_eq!(new_args.name, "custom-project");
-                assert!(!new_args.bin);
-                assert!(new_args.lib);
+                assert!(!new_args.kind.bin);
+                assert!(new_args.kind.lib);
                 assert_eq!(new_args.path, Some(PathBuf::from("/tmp/projects")));
             }
             _ => panic!("Expected New command"),
         }
     }
 
+    #[test]
+    fn test_bin_name_option() {
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app", "--bin-name", "cli"]);
+        match cli.command {
+            Commands::New(new_args) => assert_eq!(new_args.bin_name, Some("cli".to_string())),
+            _ => panic!("Expected New command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "--bin-name", "1cli"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_msrv_option() {
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app", "--msrv", "1.74.0"]);
+        match cli.command {
+            Commands::New(new_args) => {
+                assert_eq!(new_args.options.msrv, Some("1.74.0".to_string()))
+            }
+            _ => panic!("Expected New command"),
+        }
+
+        assert!(Cli::try_parse_from(["cargo-quickstart", "init", "--msrv", "stable"]).is_err());
+    }
+
+    #[test]
+    fn test_keyword_and_category_options() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--keyword",
+            "cli",
+            "--keyword",
+            "tool",
+            "--category",
+            "command-line-utilities",
+        ]);
+        match cli.command {
+            Commands::New(new_args) => {
+                assert_eq!(new_args.options.keywords, vec!["cli", "tool"]);
+                assert_eq!(new_args.options.categories, vec!["command-line-utilities"]);
+            }
+            _ => panic!("Expected New command"),
+        }
+
+        for flags in [["--keyword", "2d"], ["--category", "Command Line"]] {
+            let result =
+                Cli::try_parse_from(["cargo-quickstart", "new", "my-app"].iter().chain(&flags));
+            assert!(result.is_err(), "{flags:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_dependency_options() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "init",
+            "--dep",
+            "serde@1",
+            "--dep",
+            "anyhow",
+            "--dev-dep",
+            "proptest@1.4",
+        ]);
+        match cli.command {
+            Commands::Init(init_args) => {
+                let deps: Vec<String> = init_args
+                    .options
+                    .dependencies
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                assert_eq!(deps, vec!["serde@1", "anyhow@*"]);
+                assert_eq!(init_args.options.dev_dependencies[0].name, "proptest");
+            }
+            _ => panic!("Expected Init command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "--dep", "serde@"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_add_command() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "add",
+            ".gitignore",
+            "--path",
+            "/tmp/project",
+            "--force",
+        ]);
+        match cli.command {
+            Commands::Add(add_args) => {
+                assert_eq!(add_args.template, ".gitignore");
+                assert_eq!(add_args.path, PathBuf::from("/tmp/project"));
+                assert!(add_args.force);
+            }
+            _ => panic!("Expected Add command"),
+        }
+    }
+
+    #[test]
+    fn test_list_features_command() {
+        let cli = Cli::parse_from(["cargo-quickstart", "list-features", "--format", "json"]);
+        match cli.command {
+            Commands::ListFeatures(args) => assert_eq!(args.format, OutputFormat::Json),
+            _ => panic!("Expected ListFeatures command"),
+        }
+    }
+
+    #[test]
+    fn test_validate_templates_command() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "validate-templates",
+            "--path",
+            "/tmp/templates",
+        ]);
+        match cli.command {
+            Commands::ValidateTemplates(args) => {
+                assert_eq!(args.path, Some(PathBuf::from("/tmp/templates")));
+            }
+            _ => panic!("Expected ValidateTemplates command"),
+        }
+    }
+
+    #[test]
+    fn test_define_placeholders() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--define",
+            "gh-username=octocat",
+            "-d",
+            "greeting=a=b",
+        ]);
+        match cli.command {
+            Commands::New(args) => assert_eq!(
+                args.define,
+                vec![
+                    ("gh-username".to_string(), "octocat".to_string()),
+                    ("greeting".to_string(), "a=b".to_string()),
+                ]
+            ),
+            _ => panic!("Expected New command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "-d", "novalue"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "-d", "=value"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_cache_clear_command() {
+        let cli = Cli::parse_from(["cargo-quickstart", "cache", "clear"]);
+        match cli.command {
+            Commands::Cache(args) => assert_eq!(args.command, CacheCommand::Clear),
+            _ => panic!("Expected Cache command"),
+        }
+
+        assert!(Cli::try_parse_from(["cargo-quickstart", "cache"]).is_err());
+    }
+
+    #[test]
+    fn test_refresh_templates_requires_repo() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--template-repo",
+            "https://example.com/templates.git",
+            "--refresh-templates",
+        ]);
+        match cli.command {
+            Commands::New(args) => assert!(args.refresh_templates),
+            _ => panic!("Expected New command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "--refresh-templates"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_list_templates_command() {
         let cli = Cli::parse_from(["cargo-quickstart", "list-templates"]);
         match cli.command {
-            Commands::ListTemplates => {
-                // Command parsed correctly
+            Commands::ListTemplates(args) => {
+                assert_eq!(args.format, OutputFormat::Human);
             }
             _ => panic!("Expected ListTemplates command"),
         }
@@ -321,20 +1197,141 @@ mod tests {
     fn test_list_templates_alias() {
         let cli = Cli::parse_from(["cargo-quickstart", "ls"]);
         match cli.command {
-            Commands::ListTemplates => {
+            Commands::ListTemplates(_) => {
                 // Command alias parsed correctly
             }
             _ => panic!("Expected ListTemplates command"),
         }
     }
 
+    #[test]
+    fn test_list_templates_json_format() {
+        let cli = Cli::parse_from(["cargo-quickstart", "list-templates", "--format", "json"]);
+        match cli.command {
+            Commands::ListTemplates(args) => {
+                assert_eq!(args.format, OutputFormat::Json);
+            }
+            _ => panic!("Expected ListTemplates command"),
+        }
+    }
+
     #[cfg(feature = "doctor")]
     #[test]
     fn test_doctor_command() {
         let cli = Cli::parse_from(["cargo-quickstart", "doctor"]);
         match cli.command {
-            Commands::Doctor => {
-                // Command parsed correctly
+            Commands::Doctor(args) => {
+                assert!(!args.json);
+            }
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_json_flag() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--json", "--strict", "--fix"]);
+        match cli.command {
+            Commands::Doctor(args) => {
+                assert!(args.json);
+                assert!(args.strict);
+                assert!(args.fix);
+                assert!(args.only.is_empty());
+            }
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_category_filters() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "doctor",
+            "--only",
+            "rust,lints",
+            "--only",
+            "files",
+            "--skip",
+            "lints",
+        ]);
+        match cli.command {
+            Commands::Doctor(args) => {
+                assert_eq!(args.only, vec!["rust", "lints", "files"]);
+                assert_eq!(args.skip, vec!["lints"]);
+            }
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_slow_threshold() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--slow-threshold", "30"]);
+        match cli.command {
+            Commands::Doctor(args) => assert_eq!(args.slow_threshold, Some(30)),
+            _ => panic!("Expected Doctor command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor"]);
+        match cli.command {
+            Commands::Doctor(args) => assert_eq!(args.slow_threshold, None),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_no_cache_flag() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--no-cache"]);
+        match cli.command {
+            Commands::Doctor(args) => assert!(args.no_cache),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_tool_timeout() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--tool-timeout", "300"]);
+        match cli.command {
+            Commands::Doctor(args) => assert_eq!(args.tool_timeout, Some(300)),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_path() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--path", "../other"]);
+        match cli.command {
+            Commands::Doctor(args) => assert_eq!(args.path, Some(PathBuf::from("../other"))),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_health_thresholds() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "doctor",
+            "--health-thresholds",
+            "95,80,60,40",
+        ]);
+        match cli.command {
+            Commands::Doctor(args) => assert_eq!(args.health_thresholds, vec![95, 80, 60, 40]),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
+    #[cfg(feature = "doctor")]
+    #[test]
+    fn test_doctor_sarif() {
+        let cli = Cli::parse_from(["cargo-quickstart", "doctor", "--sarif", "clippy.sarif"]);
+        match cli.command {
+            Commands::Doctor(args) => {
+                assert_eq!(args.sarif, Some(PathBuf::from("clippy.sarif")));
             }
             _ => panic!("Expected Doctor command"),
         }
@@ -374,6 +1371,36 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "completions")]
+    #[test]
+    fn test_completions_install_flags() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "completions",
+            "bash",
+            "--install",
+            "--install-dir",
+            "/tmp/completions",
+        ]);
+        match cli.command {
+            Commands::Completions(args) => {
+                assert!(args.install);
+                assert_eq!(args.install_dir, Some(PathBuf::from("/tmp/completions")));
+            }
+            _ => panic!("Expected Completions command"),
+        }
+
+        let conflict = Cli::try_parse_from([
+            "cargo-quickstart",
+            "completions",
+            "bash",
+            "--install",
+            "--output",
+            "out.bash",
+        ]);
+        assert!(conflict.is_err());
+    }
+
     #[cfg(feature = "completions")]
     #[test]
     fn test_completions_all_shells() {
@@ -384,6 +1411,7 @@ mod tests {
             ("fish", "fish"),
             ("powershell", "powershell"),
             ("elvish", "elvish"),
+            ("nu", "nu"),
         ];
 
         for (shell_arg, expected_str) in shells {
@@ -398,6 +1426,249 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_rejects_invalid_name() {
+        let result = Cli::try_parse_from(["cargo-quickstart", "new", "std", "--bin"]);
+        assert!(result.is_err());
+
+        let result = Cli::try_parse_from(["cargo-quickstart", "new", "bad@name"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verbosity_flags() {
+        let cli = Cli::parse_from(["cargo-quickstart", "list-templates"]);
+        assert_eq!(cli.log_level(), log::LevelFilter::Warn);
+
+        // Global flags are accepted before or after the subcommand
+        let cli = Cli::parse_from(["cargo-quickstart", "-v", "list-templates"]);
+        assert_eq!(cli.log_level(), log::LevelFilter::Debug);
+
+        let cli = Cli::parse_from(["cargo-quickstart", "list-templates", "--quiet"]);
+        assert_eq!(cli.log_level(), log::LevelFilter::Error);
+
+        let conflict = Cli::try_parse_from(["cargo-quickstart", "-v", "-q", "list-templates"]);
+        assert!(conflict.is_err());
+    }
+
+    #[test]
+    fn test_offline_flag() {
+        assert!(!Cli::parse_from(["cargo-quickstart", "list-templates"]).offline);
+        assert!(Cli::parse_from(["cargo-quickstart", "list-templates", "--offline"]).offline);
+    }
+
+    #[test]
+    fn test_dry_run_flag() {
+        match Cli::parse_from(["cargo-quickstart", "new", "foo", "--dry-run"]).command {
+            Commands::New(args) => assert!(args.dry_run),
+            _ => panic!("Expected New command"),
+        }
+        match Cli::parse_from(["cargo-quickstart", "init", "--dry-run"]).command {
+            Commands::Init(args) => assert!(args.dry_run),
+            _ => panic!("Expected Init command"),
+        }
+    }
+
+    #[test]
+    fn test_init_diff_flag() {
+        match Cli::parse_from(["cargo-quickstart", "init", "--diff"]).command {
+            Commands::Init(args) => assert!(args.diff),
+            _ => panic!("Expected Init command"),
+        }
+        assert!(Cli::try_parse_from(["cargo-quickstart", "init", "--diff", "--dry-run"]).is_err());
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "init", "--diff", "--interactive"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_description_and_author_flags() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--description",
+            "A tiny tool",
+            "--author-name",
+            "Jane Doe",
+            "--author-email",
+            "jane@example.com",
+        ]);
+        match cli.command {
+            Commands::New(args) => {
+                assert_eq!(args.options.description.as_deref(), Some("A tiny tool"));
+                assert_eq!(args.options.author_name.as_deref(), Some("Jane Doe"));
+                assert_eq!(
+                    args.options.author_email.as_deref(),
+                    Some("jane@example.com")
+                );
+            }
+            _ => panic!("Expected New command"),
+        }
+
+        match Cli::parse_from(["cargo-quickstart", "init", "--description", "Lib"]).command {
+            Commands::Init(args) => {
+                assert_eq!(args.options.description.as_deref(), Some("Lib"));
+                assert!(args.options.author_name.is_none());
+            }
+            _ => panic!("Expected Init command"),
+        }
+    }
+
+    #[test]
+    fn test_no_saved_defaults_requires_interactive() {
+        match Cli::parse_from([
+            "cargo-quickstart",
+            "init",
+            "--interactive",
+            "--no-saved-defaults",
+        ])
+        .command
+        {
+            Commands::Init(args) => assert!(args.no_saved_defaults),
+            _ => panic!("Expected Init command"),
+        }
+
+        assert!(Cli::try_parse_from(["cargo-quickstart", "init", "--no-saved-defaults"]).is_err());
+    }
+
+    #[test]
+    fn test_features_flag() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--features",
+            "ci,examples,benchmarks",
+        ]);
+        match cli.command {
+            Commands::New(args) => assert_eq!(
+                args.options.features,
+                Some(vec![
+                    "ci".to_string(),
+                    "examples".to_string(),
+                    "benchmarks".to_string()
+                ])
+            ),
+            _ => panic!("Expected New command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "init", "--features", "readme"]);
+        match cli.command {
+            Commands::Init(args) => {
+                assert_eq!(args.options.features, Some(vec!["readme".to_string()]))
+            }
+            _ => panic!("Expected Init command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app"]);
+        match cli.command {
+            Commands::New(args) => assert_eq!(args.options.features, None),
+            _ => panic!("Expected New command"),
+        }
+    }
+
+    #[test]
+    fn test_features_flag_rejects_unknown_names() {
+        let err = Cli::try_parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--features",
+            "ci,docker",
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown feature 'docker'"));
+        assert!(err.to_string().contains("benchmarks"));
+    }
+
+    #[test]
+    fn test_toolchain_flag() {
+        let cli = Cli::parse_from(["cargo-quickstart", "new", "my-app", "--toolchain", "1.75.0"]);
+        match cli.command {
+            Commands::New(args) => assert_eq!(args.options.toolchain, Some("1.75.0".to_string())),
+            _ => panic!("Expected New command"),
+        }
+
+        let cli = Cli::parse_from(["cargo-quickstart", "init", "--toolchain", "nightly"]);
+        match cli.command {
+            Commands::Init(args) => assert_eq!(args.options.toolchain, Some("nightly".to_string())),
+            _ => panic!("Expected Init command"),
+        }
+
+        let err =
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "--toolchain", "latest"])
+                .unwrap_err();
+        assert!(err.to_string().contains("not a valid toolchain"));
+    }
+
+    #[test]
+    fn test_template_repo_flags() {
+        let cli = Cli::parse_from([
+            "cargo-quickstart",
+            "new",
+            "my-app",
+            "--template-repo",
+            "https://example.com/templates.git",
+            "--template-ref",
+            "v2",
+        ]);
+        match cli.command {
+            Commands::New(args) => {
+                assert_eq!(
+                    args.template_repo.as_deref(),
+                    Some("https://example.com/templates.git")
+                );
+                assert_eq!(args.template_ref.as_deref(), Some("v2"));
+            }
+            _ => panic!("Expected New command"),
+        }
+
+        // A ref without a repository is meaningless
+        assert!(
+            Cli::try_parse_from(["cargo-quickstart", "new", "my-app", "--template-ref", "v2"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_git_flags() {
+        let parse = |extra: &[&str]| -> NewArgs {
+            let mut args = vec!["cargo-quickstart", "new", "my-app"];
+            args.extend_from_slice(extra);
+            match Cli::parse_from(args).command {
+                Commands::New(args) => args,
+                _ => panic!("Expected New command"),
+            }
+        };
+
+        let git = parse(&["--git"]);
+        assert!(git.use_git());
+        assert!(!git.no_git);
+
+        let no_git = parse(&["--no-git"]);
+        assert!(!no_git.use_git());
+        assert!(!no_git.git);
+
+        // The last flag wins when both are given
+        assert!(!parse(&["--git", "--no-git"]).use_git());
+        assert!(parse(&["--no-git", "--git"]).use_git());
+
+        let neither = parse(&[]);
+        assert_eq!(neither.use_git(), GIT_DEFAULT);
+        assert!(resolve_git(neither.git, neither.no_git, true));
+        assert!(!resolve_git(false, true, true));
+    }
+
+    #[test]
+    fn test_init_no_git_flag() {
+        let cli = Cli::parse_from(["cargo-quickstart", "init", "--git", "--no-git"]);
+        match cli.command {
+            Commands::Init(args) => assert!(!args.use_git()),
+            _ => panic!("Expected Init command"),
+        }
+    }
+
     #[test]
     fn test_validate_edition_valid() {
         // Convert the Result<String, String> to a boolean result for each test case
@@ -443,12 +1714,21 @@ mod tests {
         assert!(result_mit);
         assert!(result_apache);
         assert!(result_dual);
+
+        // Every license the wizard offers is accepted, as are custom SPDX expressions
+        for license in quickstart_lib::LICENSES {
+            assert_eq!(validate_license(license), Ok(license.to_string()));
+        }
+        assert!(validate_license("GPL-3.0").is_ok());
+        assert!(validate_license("BSD-3-Clause").is_ok());
+        assert!(validate_license("MPL-2.0").is_ok());
     }
 
     #[test]
     fn test_validate_license_invalid() {
-        assert!(validate_license("GPL").is_err());
-        assert!(validate_license("BSD").is_err());
-        assert!(validate_license("invalid").is_err());
+        assert!(validate_license("").is_err());
+        assert!(validate_license("GPL 3").is_err());
+        assert!(validate_license("MIT OR").is_err());
+        assert!(validate_license("MIT/Apache-2.0").is_err());
     }
 }
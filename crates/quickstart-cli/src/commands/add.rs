@@ -0,0 +1,202 @@
+//! Implementation of the 'add' command for scaffolding templates into an existing project
+
+use color_eyre::Result;
+use quickstart_lib::{
+    template::{TemplateEngine, TemplateLoader, TemplateVariables, TemplateVariant},
+    ProjectConfig, ProjectType,
+};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    args::AddArgs,
+    commands::manifest,
+    errors::{CommandErrorExt, ErrorKind},
+    ui::output,
+};
+
+/// Execute the 'add' command
+pub fn execute(args: AddArgs) -> Result<()> {
+    output::header("Adding templates");
+
+    let package = manifest::read_package(&args.path)?.ok_or_else(|| {
+        ErrorKind::FileSystem.error(format!(
+            "No Cargo.toml with a [package] section found in '{}'",
+            args.path.display()
+        ))
+    })?;
+
+    let project_type = detect_project_type(&args.path);
+
+    let config = ProjectConfig {
+        name: package.name,
+        project_type,
+        edition: package.edition.unwrap_or_else(|| "2021".to_string()),
+        license: package
+            .license
+            .unwrap_or_else(|| "MIT OR Apache-2.0".to_string()),
+        path: args.path.clone(),
+        yes: true,
+        variant: TemplateVariant::Extended,
+        ..Default::default()
+    };
+
+    output::section("Project configuration");
+    output::key_value("Name", &config.name);
+    output::key_value("Type", &config.project_type.to_string());
+    output::key_value("Path", &config.path.display().to_string());
+
+    let loader = super::find_template_loader()?;
+    let templates = loader.list_templates(config.project_type, TemplateVariant::Extended)?;
+
+    let selected = select_templates(&loader, &templates, &args.template, &config.path);
+    if selected.is_empty() {
+        return Err(ErrorKind::Template.error(format!("No template matches '{}'", args.template)));
+    }
+
+    // Register every template so the selected ones can include others as partials
+    let mut engine = TemplateEngine::new(TemplateVariables::from_config(&config));
+    for template_path in &templates {
+        let rel_path = loader.relative_path(template_path);
+        let content = loader.load_template(&rel_path).command_context("add")?;
+        engine
+            .register_template_file(&rel_path, &content)
+            .command_context("add")?;
+    }
+
+    // Render templated file and directory names, e.g. `src/{{crate_name}}.rs`
+    let selected = selected
+        .into_iter()
+        .map(|(template, dest)| Ok((template, engine.render_path(&dest)?)))
+        .collect::<Result<Vec<_>>>()
+        .command_context("add")?;
+
+    // Refuse to clobber existing files unless explicitly forced
+    if !args.force {
+        let existing: Vec<String> = selected
+            .iter()
+            .filter(|(_, dest)| dest.exists())
+            .map(|(_, dest)| dest.display().to_string())
+            .collect();
+        if !existing.is_empty() {
+            return Err(ErrorKind::FileSystem.error(format!(
+                "Refusing to overwrite existing files (use --force): {}",
+                existing.join(", ")
+            )));
+        }
+    }
+
+    output::section("Files");
+    for (template_path, dest) in selected {
+        let rel_path = loader.relative_path(&template_path);
+        let rendered = engine
+            .render_named(TemplateLoader::partial_name(&rel_path))
+            .command_context("add")?;
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, rendered)?;
+        output::list_item(&dest.display().to_string());
+    }
+
+    output::success("Templates added successfully!");
+    Ok(())
+}
+
+/// Guess the project type from the existing source layout
+fn detect_project_type(path: &Path) -> ProjectType {
+    match (
+        path.join("src/main.rs").exists(),
+        path.join("src/lib.rs").exists(),
+    ) {
+        (true, true) => ProjectType::Both,
+        (false, true) => ProjectType::Library,
+        _ => ProjectType::Binary,
+    }
+}
+
+/// Select the templates whose destination matches `name`
+///
+/// `name` may be a file (e.g. `.gitignore`) or a directory (e.g. `.vscode`),
+/// relative to the project root.
+fn select_templates(
+    loader: &TemplateLoader,
+    templates: &[PathBuf],
+    name: &str,
+    dest_root: &Path,
+) -> Vec<(PathBuf, PathBuf)> {
+    let wanted = Path::new(name.trim_start_matches("./").trim_end_matches('/'));
+
+    templates
+        .iter()
+        .filter(|template| !TemplateLoader::is_partial(template))
+        .filter_map(|template| {
+            let rel_dest = loader.get_destination_path(template, Path::new(""));
+            rel_dest
+                .starts_with(wanted)
+                .then(|| (template.clone(), dest_root.join(rel_dest)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_project_type() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        std::fs::create_dir_all(temp_dir.path().join("src"))?;
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Binary);
+
+        std::fs::write(temp_dir.path().join("src/lib.rs"), "")?;
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Library);
+
+        std::fs::write(temp_dir.path().join("src/main.rs"), "")?;
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Both);
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_templates_by_file_and_dir() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let base = temp_dir.path().join("base");
+        std::fs::create_dir_all(base.join(".vscode"))?;
+        std::fs::write(base.join(".gitignore.hbs"), "target/")?;
+        std::fs::write(base.join(".vscode/settings.json.hbs"), "{}")?;
+        std::fs::write(base.join(".vscode/tasks.json.hbs"), "{}")?;
+
+        let loader = TemplateLoader::new(temp_dir.path());
+        let templates = vec![
+            base.join(".gitignore.hbs"),
+            base.join(".vscode/settings.json.hbs"),
+            base.join(".vscode/tasks.json.hbs"),
+        ];
+        let dest_root = Path::new("/tmp/project");
+
+        let gitignore = select_templates(&loader, &templates, ".gitignore", dest_root);
+        assert_eq!(gitignore.len(), 1);
+        assert_eq!(gitignore[0].1, dest_root.join(".gitignore"));
+
+        let vscode = select_templates(&loader, &templates, "./.vscode/", dest_root);
+        assert_eq!(vscode.len(), 2);
+
+        let none = select_templates(&loader, &templates, "missing", dest_root);
+        assert!(none.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+//! Implementation of the 'cache' command
+
+use color_eyre::{eyre::Context, Result};
+use std::path::Path;
+
+use crate::args::{CacheArgs, CacheCommand};
+use crate::errors::ErrorKind;
+use crate::ui::output;
+
+/// Execute the 'cache' command
+pub fn execute(args: CacheArgs) -> Result<()> {
+    let cache_root = super::template_cache_root().ok_or_else(|| {
+        ErrorKind::FileSystem.error("Could not determine the user cache directory")
+    })?;
+
+    match args.command {
+        CacheCommand::Clear => {
+            if clear(&cache_root)? {
+                output::success(&format!(
+                    "Removed cached templates from {}",
+                    cache_root.display()
+                ));
+            } else {
+                output::info("The template cache is already empty");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete every cached template repository under `cache_root`
+///
+/// Returns whether there was anything to delete.
+pub fn clear(cache_root: &Path) -> Result<bool> {
+    if !cache_root.exists() {
+        return Ok(false);
+    }
+
+    std::fs::remove_dir_all(cache_root)
+        .with_context(|| format!("Failed to remove {}", cache_root.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_clear_removes_cached_repositories() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let cache_root = temp_dir.path().join("templates");
+        std::fs::create_dir_all(cache_root.join("0123456789abcdef/templates/base"))?;
+
+        assert!(clear(&cache_root)?);
+        assert!(!cache_root.exists());
+
+        // Clearing again is not an error
+        assert!(!clear(&cache_root)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+//! Resolve seeded dependencies with `cargo add` after generation
+
+use crate::ui::output;
+use color_eyre::{eyre::eyre, Result};
+use quickstart_lib::DependencySpec;
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Trait for executing `cargo add`, allowing for easier mocking in tests
+pub trait CommandExecutor {
+    /// Run `cargo add <args>` in `dir`
+    fn cargo_add(&self, dir: &Path, args: &[String]) -> std::io::Result<ExitStatus>;
+}
+
+/// Default implementation that calls the actual `cargo add` command
+pub struct RealCommandExecutor {
+    /// Hide cargo's progress output instead of streaming it to stderr
+    pub quiet: bool,
+}
+
+impl CommandExecutor for RealCommandExecutor {
+    fn cargo_add(&self, dir: &Path, args: &[String]) -> std::io::Result<ExitStatus> {
+        // cargo reports on stderr; stdout is kept clean for `--format json`
+        let stderr = if self.quiet {
+            Stdio::null()
+        } else {
+            Stdio::inherit()
+        };
+        Command::new("cargo")
+            .arg("add")
+            .args(args)
+            .current_dir(dir)
+            .stdout(Stdio::null())
+            .stderr(stderr)
+            .status()
+    }
+}
+
+/// Whether `--cargo-add` was `requested` and can run, warning unless `quiet` when
+/// `offline` prevents it and the `--dep` versions are written as given instead
+pub fn should_run(requested: bool, offline: bool, quiet: bool) -> bool {
+    if requested && offline && !quiet {
+        output::warning(
+            "Working offline: writing --dep versions as given instead of running cargo add",
+        );
+    }
+    requested && !offline
+}
+
+/// Add `dependencies` and `dev_dependencies` to the project in `dir` with `cargo add`
+///
+/// Entries without a version get the latest one cargo resolves. Fails as soon as an
+/// invocation does.
+pub fn add_dependencies(
+    executor: &dyn CommandExecutor,
+    dir: &Path,
+    dependencies: &[DependencySpec],
+    dev_dependencies: &[DependencySpec],
+) -> Result<()> {
+    for (dev, specs) in [(false, dependencies), (true, dev_dependencies)] {
+        if specs.is_empty() {
+            continue;
+        }
+
+        let mut args: Vec<String> = Vec::new();
+        if dev {
+            args.push("--dev".to_string());
+        }
+        args.extend(specs.iter().map(cargo_add_arg));
+
+        let status = executor
+            .cargo_add(dir, &args)
+            .map_err(|e| eyre!("Could not run cargo add: {e}"))?;
+        if !status.success() {
+            return Err(eyre!(
+                "`cargo add {}` failed in {} ({status})",
+                args.join(" "),
+                dir.display()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The `cargo add` argument for `spec`: its name, plus the version if one was given
+fn cargo_add_arg(spec: &DependencySpec) -> String {
+    if spec.version == "*" {
+        spec.name.clone()
+    } else {
+        spec.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::cell::RefCell;
+    use std::os::unix::process::ExitStatusExt;
+
+    /// Records each invocation and reports `code` for all of them
+    struct MockCommandExecutor {
+        calls: RefCell<Vec<Vec<String>>>,
+        code: i32,
+    }
+
+    impl MockCommandExecutor {
+        fn new(code: i32) -> Self {
+            Self {
+                calls: RefCell::new(Vec::new()),
+                code,
+            }
+        }
+    }
+
+    impl CommandExecutor for MockCommandExecutor {
+        fn cargo_add(&self, _dir: &Path, args: &[String]) -> std::io::Result<ExitStatus> {
+            self.calls.borrow_mut().push(args.to_vec());
+            Ok(ExitStatus::from_raw(self.code << 8))
+        }
+    }
+
+    fn specs(specs: &[&str]) -> Result<Vec<DependencySpec>> {
+        specs
+            .iter()
+            .map(|spec| spec.parse().map_err(|e: String| eyre!(e)))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_run_only_when_requested_and_online() {
+        assert!(should_run(true, false, true));
+        assert!(!should_run(true, true, true));
+        assert!(!should_run(false, false, true));
+    }
+
+    #[test]
+    fn test_add_dependencies_invokes_cargo_add() -> Result<()> {
+        let executor = MockCommandExecutor::new(0);
+        add_dependencies(
+            &executor,
+            Path::new("/tmp/project"),
+            &specs(&["serde", "tokio@1"])?,
+            &specs(&["proptest@1.4"])?,
+        )?;
+
+        assert_eq!(
+            executor.calls.into_inner(),
+            vec![vec!["serde", "tokio@1"], vec!["--dev", "proptest@1.4"]]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dependencies_skips_empty_lists() -> Result<()> {
+        let executor = MockCommandExecutor::new(0);
+        add_dependencies(&executor, Path::new("/tmp/project"), &[], &[])?;
+        assert!(executor.calls.into_inner().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dependencies_fails_when_cargo_add_fails() -> Result<()> {
+        let executor = MockCommandExecutor::new(101);
+        let err = add_dependencies(
+            &executor,
+            Path::new("/tmp/project"),
+            &specs(&["not-a-real-crate"])?,
+            &specs(&["proptest"])?,
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("`cargo add not-a-real-crate` failed"),
+            "{err}"
+        );
+        // The dev-dependencies are not attempted after a failure
+        assert_eq!(executor.calls.into_inner().len(), 1);
+        Ok(())
+    }
+}
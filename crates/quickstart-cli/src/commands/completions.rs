@@ -2,11 +2,15 @@
 
 use crate::args::{CompletionsArgs, Shell};
 use clap::CommandFactory;
-use clap_complete::{generate, Shell as ClapShell};
-use color_eyre::eyre::Context;
+use clap_complete::{engine::CompletionCandidate, generate, Shell as ClapShell};
+use clap_complete_nushell::Nushell;
+use color_eyre::eyre::{eyre, Context};
+use directories::BaseDirs;
+use quickstart_lib::template::{TemplateLoader, TemplateVariant};
+use std::ffi::OsStr;
 use std::fs::File;
 use std::io::{self};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::args::Cli;
 use crate::ui::output;
@@ -22,16 +26,20 @@ enum CompletionOutput {
 /// Execute the 'completions' command
 pub fn execute(args: CompletionsArgs) -> color_eyre::Result<()> {
     let mut cmd = Cli::command();
-    let shell = match args.shell {
-        Shell::Bash => ClapShell::Bash,
-        Shell::Zsh => ClapShell::Zsh,
-        Shell::Fish => ClapShell::Fish,
-        Shell::Powershell => ClapShell::PowerShell,
-        Shell::Elvish => ClapShell::Elvish,
-    };
 
     // Convert the Option<PathBuf> to our new enum
-    let output = if let Some(path) = args.output {
+    let output = if args.install {
+        let path = install_path(&args.shell, args.install_dir.as_deref())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create completions directory {}",
+                    parent.display()
+                )
+            })?;
+        }
+        CompletionOutput::File(path)
+    } else if let Some(path) = args.output {
         CompletionOutput::File(path)
     } else {
         CompletionOutput::Stdout
@@ -45,7 +53,7 @@ pub fn execute(args: CompletionsArgs) -> color_eyre::Result<()> {
             })?;
 
             let mut writer = io::BufWriter::new(file);
-            generate(shell, &mut cmd, "cargo-quickstart", &mut writer);
+            write_completions(&args.shell, &mut cmd, &mut writer);
 
             output::success(&format!(
                 "Shell completions for {} written to {}",
@@ -55,7 +63,7 @@ pub fn execute(args: CompletionsArgs) -> color_eyre::Result<()> {
         }
         CompletionOutput::Stdout => {
             let mut stdout = io::stdout();
-            generate(shell, &mut cmd, "cargo-quickstart", &mut stdout);
+            write_completions(&args.shell, &mut cmd, &mut stdout);
 
             output::info(&format!(
                 "Shell completions for {} written to stdout",
@@ -66,6 +74,76 @@ pub fn execute(args: CompletionsArgs) -> color_eyre::Result<()> {
     Ok(())
 }
 
+/// File name a completion script is installed under for `shell`
+const fn install_file_name(shell: &Shell) -> &'static str {
+    match shell {
+        Shell::Bash => "cargo-quickstart",
+        Shell::Zsh => "_cargo-quickstart",
+        Shell::Fish => "cargo-quickstart.fish",
+        Shell::Powershell => "cargo-quickstart.ps1",
+        Shell::Elvish => "cargo-quickstart.elv",
+        Shell::Nu => "cargo-quickstart.nu",
+    }
+}
+
+/// Resolve where `--install` writes the script, honouring an explicit directory override
+fn install_path(shell: &Shell, dir_override: Option<&Path>) -> color_eyre::Result<PathBuf> {
+    let dir = match dir_override {
+        Some(dir) => dir.to_path_buf(),
+        None => {
+            let dirs =
+                BaseDirs::new().ok_or_else(|| eyre!("Could not determine the home directory"))?;
+            match shell {
+                Shell::Bash => dirs.data_dir().join("bash-completion/completions"),
+                Shell::Zsh => dirs.home_dir().join(".zfunc"),
+                Shell::Fish => dirs.config_dir().join("fish/completions"),
+                Shell::Powershell => dirs.data_dir().join("powershell/completions"),
+                Shell::Elvish => dirs.config_dir().join("elvish/lib"),
+                Shell::Nu => dirs.config_dir().join("nushell/completions"),
+            }
+        }
+    };
+
+    Ok(dir.join(install_file_name(shell)))
+}
+
+/// Generate the completion script for `shell` into `writer`
+fn write_completions(shell: &Shell, cmd: &mut clap::Command, writer: &mut dyn io::Write) {
+    let bin_name = "cargo-quickstart";
+    match shell {
+        Shell::Bash => generate(ClapShell::Bash, cmd, bin_name, writer),
+        Shell::Zsh => generate(ClapShell::Zsh, cmd, bin_name, writer),
+        Shell::Fish => generate(ClapShell::Fish, cmd, bin_name, writer),
+        Shell::Powershell => generate(ClapShell::PowerShell, cmd, bin_name, writer),
+        Shell::Elvish => generate(ClapShell::Elvish, cmd, bin_name, writer),
+        Shell::Nu => generate(Nushell, cmd, bin_name, writer),
+    }
+}
+
+/// Complete `--template` values from the discovered (or bundled) templates
+pub fn complete_template_name(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str() else {
+        return Vec::new();
+    };
+
+    quickstart_lib::find_template_loader()
+        .map(|loader| template_candidates(&loader, prefix))
+        .unwrap_or_default()
+}
+
+/// Template names offered by `loader` starting with `prefix`
+///
+/// Only directories naming a [`TemplateVariant`] are offered, since `--template`
+/// rejects anything else.
+fn template_candidates(loader: &TemplateLoader, prefix: &str) -> Vec<CompletionCandidate> {
+    loader
+        .available_templates()
+        .into_iter()
+        .filter(|name| name.starts_with(prefix) && name.parse::<TemplateVariant>().is_ok())
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +166,7 @@ mod tests {
             Shell::Fish,
             Shell::Powershell,
             Shell::Elvish,
+            Shell::Nu,
         ] {
             let tmp = NamedTempFile::new().with_context(|| {
                 format!("Failed to create temporary file for {shell:?} completions")
@@ -96,6 +175,8 @@ mod tests {
             let args = CompletionsArgs {
                 shell: shell.clone(),
                 output: Some(path.clone()),
+                install: false,
+                install_dir: None,
             };
             let result = execute(args);
             assert!(result.is_ok(), "execute() should succeed for {shell:?}");
@@ -123,10 +204,13 @@ mod tests {
             Shell::Fish,
             Shell::Powershell,
             Shell::Elvish,
+            Shell::Nu,
         ] {
             let args = CompletionsArgs {
                 shell: shell.clone(),
                 output: None,
+                install: false,
+                install_dir: None,
             };
             let result = execute(args);
             assert!(
@@ -136,4 +220,83 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_template_candidates_from_fixture() -> Result<()> {
+        // Skip this test when running under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        for sub in [
+            "binary/minimal",
+            "binary/extended",
+            "library/minimal",
+            "library/web",
+        ] {
+            fs::create_dir_all(dir.path().join(sub))?;
+        }
+
+        let names = |prefix: &str| -> Vec<String> {
+            template_candidates(&TemplateLoader::new(dir.path()), prefix)
+                .iter()
+                .map(|c| c.get_value().to_string_lossy().into_owned())
+                .collect()
+        };
+
+        assert_eq!(names(""), vec!["extended", "minimal"]);
+        assert_eq!(names("mi"), vec!["minimal"]);
+        assert!(names("web").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_installs_to_override_dir() -> Result<()> {
+        // Skip this test when running under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file I/O test under Miri");
+            return Ok(());
+        }
+
+        let tmp = tempfile::tempdir()?;
+        let install_dir = tmp.path().join("nested/completions");
+
+        for (shell, file) in [
+            (Shell::Bash, "cargo-quickstart"),
+            (Shell::Zsh, "_cargo-quickstart"),
+            (Shell::Fish, "cargo-quickstart.fish"),
+        ] {
+            let args = CompletionsArgs {
+                shell,
+                output: None,
+                install: true,
+                install_dir: Some(install_dir.clone()),
+            };
+            execute(args)?;
+
+            let contents = fs::read(install_dir.join(file))?;
+            assert!(!contents.is_empty(), "{file} should not be empty");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_install_path_defaults() -> Result<()> {
+        let Some(dirs) = BaseDirs::new() else {
+            return Ok(());
+        };
+
+        assert_eq!(
+            install_path(&Shell::Fish, None)?,
+            dirs.config_dir()
+                .join("fish/completions/cargo-quickstart.fish")
+        );
+        assert_eq!(
+            install_path(&Shell::Zsh, None)?,
+            dirs.home_dir().join(".zfunc/_cargo-quickstart")
+        );
+        Ok(())
+    }
 }
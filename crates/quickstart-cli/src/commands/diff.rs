@@ -0,0 +1,134 @@
+//! Preview how generation would change files that already exist
+
+use crate::ui::output;
+use color_eyre::Result;
+use quickstart_lib::ProjectConfig;
+use similar::TextDiff;
+use std::path::PathBuf;
+
+/// Changes generating `config` would make to its project directory
+#[derive(Debug, Default)]
+pub struct ProjectDiff {
+    /// Unified diffs of the existing files whose content would change
+    pub changed: Vec<(PathBuf, String)>,
+    /// Files that do not exist yet, relative to the project root
+    pub created: Vec<PathBuf>,
+    /// Number of existing files that already match their template
+    pub unchanged: usize,
+}
+
+/// Render `config` in memory and diff each file against what is on disk
+pub fn project_diff(config: &ProjectConfig) -> Result<ProjectDiff> {
+    let mut diff = ProjectDiff::default();
+
+    for (file, rendered) in quickstart_lib::render_project(config)? {
+        let path = config.path.join(&file);
+        if !path.exists() {
+            diff.created.push(file);
+            continue;
+        }
+
+        let existing = String::from_utf8_lossy(&std::fs::read(&path)?).into_owned();
+        let name = file.display().to_string();
+        let unified = TextDiff::from_lines(&existing, &rendered)
+            .unified_diff()
+            .header(&format!("a/{name}"), &format!("b/{name}"))
+            .to_string();
+        if unified.is_empty() {
+            diff.unchanged += 1;
+        } else {
+            diff.changed.push((file, unified));
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Print the changes generating `config` would make, without writing anything
+pub fn preview_diff(config: &ProjectConfig) -> Result<()> {
+    let diff = project_diff(config)?;
+
+    for (_, unified) in &diff.changed {
+        output::diff(unified);
+    }
+    if !diff.created.is_empty() {
+        output::section("Files that would be created");
+        output::file_tree(&config.path.display().to_string(), &diff.created);
+    }
+    output::info(&format!(
+        "{} changed, {} new, {} unchanged: no files were written",
+        diff.changed.len(),
+        diff.created.len(),
+        diff.unchanged
+    ));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn diff_config(path: PathBuf) -> ProjectConfig {
+        ProjectConfig {
+            name: "diff-test".to_string(),
+            license: "MIT".to_string(),
+            path,
+            yes: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_project_diff_for_empty_directory_only_creates() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempdir()?;
+        let diff = project_diff(&diff_config(dir.path().to_path_buf()))?;
+
+        assert!(diff.changed.is_empty());
+        assert_eq!(diff.unchanged, 0);
+        assert!(diff.created.contains(&PathBuf::from("Cargo.toml")));
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_diff_shows_modified_file() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempdir()?;
+        let config = diff_config(dir.path().to_path_buf());
+        quickstart_lib::generate_project(diff_config(dir.path().to_path_buf()))?;
+
+        // Freshly generated files match their templates
+        let diff = project_diff(&config)?;
+        assert!(diff.changed.is_empty());
+        assert!(diff.created.is_empty());
+        assert!(diff.unchanged > 0);
+
+        let readme = dir.path().join("README.md");
+        let edited = std::fs::read_to_string(&readme)?.replace("diff-test", "renamed");
+        std::fs::write(&readme, &edited)?;
+
+        let diff = project_diff(&config)?;
+        assert_eq!(diff.changed.len(), 1);
+        let (file, unified) = &diff.changed[0];
+        assert_eq!(file, &PathBuf::from("README.md"));
+        assert!(unified.contains("--- a/README.md"), "{unified}");
+        assert!(unified.contains("-# renamed"), "{unified}");
+        assert!(unified.contains("+# diff-test"), "{unified}");
+        // Previewing leaves the edit in place
+        assert_eq!(std::fs::read_to_string(&readme)?, edited);
+        Ok(())
+    }
+}
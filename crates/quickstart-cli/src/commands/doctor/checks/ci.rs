@@ -0,0 +1,153 @@
+//! Check for continuous integration configuration
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::path::Path;
+
+/// Single-file CI configurations recognised by the check
+const CI_FILES: [(&str, &str); 2] = [
+    (".gitlab-ci.yml", "GitLab CI"),
+    (".circleci/config.yml", "CircleCI"),
+];
+
+/// Directory containing GitHub Actions workflows
+const GITHUB_WORKFLOWS_DIR: &str = ".github/workflows";
+
+/// A check for CI configuration files
+pub struct CiCheck {
+    dir: Box<Path>,
+}
+
+impl CiCheck {
+    /// Create a new CiCheck for the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Whether any GitHub Actions workflow file exists
+    fn has_github_workflow(&self) -> bool {
+        std::fs::read_dir(self.dir.join(GITHUB_WORKFLOWS_DIR))
+            .map(|entries| {
+                entries.filter_map(|e| e.ok()).any(|e| {
+                    e.path()
+                        .extension()
+                        .is_some_and(|ext| ext == "yml" || ext == "yaml")
+                })
+            })
+            .unwrap_or(false)
+    }
+}
+
+impl Check for CiCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let mut providers: Vec<&str> = Vec::new();
+        if self.has_github_workflow() {
+            providers.push("GitHub Actions");
+        }
+        providers.extend(
+            CI_FILES
+                .iter()
+                .filter(|(file, _)| self.dir.join(file).is_file())
+                .map(|(_, provider)| *provider),
+        );
+
+        let diagnostic = if providers.is_empty() {
+            Diagnostic::new(
+                self.name(),
+                Severity::Suggestion,
+                "No CI configuration found.",
+                self.category(),
+            )
+            .with_suggestion(
+                "Add a CI workflow (e.g. .github/workflows/ci.yml) to build and test on every push.",
+            )
+        } else {
+            Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                format!("CI configuration found: {}.", providers.join(", ")),
+                self.category(),
+            )
+        };
+
+        vec![diagnostic]
+    }
+
+    fn name(&self) -> &str {
+        "Continuous Integration"
+    }
+
+    fn description(&self) -> &str {
+        "Check for CI configuration (GitHub Actions, GitLab CI, CircleCI)"
+    }
+
+    fn category(&self) -> &str {
+        "ci"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use std::io::Result as IoResult;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_no_ci_configuration() -> IoResult<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempdir()?;
+        // An empty workflows directory does not count
+        fs::create_dir_all(dir.path().join(GITHUB_WORKFLOWS_DIR))?;
+
+        let diagnostics = CiCheck::new(dir.path()).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Suggestion);
+        assert!(diagnostics[0].suggestion.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_github_workflow_found() -> IoResult<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempdir()?;
+        fs::create_dir_all(dir.path().join(GITHUB_WORKFLOWS_DIR))?;
+        fs::write(dir.path().join(".github/workflows/ci.yml"), "on: push")?;
+
+        let diagnostics = CiCheck::new(dir.path()).run();
+
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("GitHub Actions"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gitlab_ci_found() -> IoResult<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempdir()?;
+        fs::write(dir.path().join(".gitlab-ci.yml"), "stages: []")?;
+
+        let diagnostics = CiCheck::new(dir.path()).run();
+
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("GitLab CI"));
+        Ok(())
+    }
+}
@@ -4,86 +4,254 @@ use crate::commands::doctor::diagnosis::Check;
 use crate::commands::doctor::types::{Diagnostic, Severity};
 #[cfg(test)]
 use mockall;
-use std::process::{Command, Output};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use quickstart_lib::tools::fnv1a;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 use which;
 
 /// Constants for dependency checking
 const CARGO_OUTDATED_RECOMMENDATION: &str = "Install cargo-outdated: cargo install cargo-outdated";
 const CARGO_UDEPS_RECOMMENDATION: &str = "Install cargo-udeps: cargo install cargo-udeps";
 
+/// Cached outdated/unused results, relative to the project root
+const CACHE_FILE: &str = "target/cargo-quickstart/doctor-dependencies.json";
+
+/// How long `cargo outdated` and `cargo udeps` may run unless overridden
+pub const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How often a running tool is polled for completion
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// Trait for executing cargo commands, allowing for easier mocking in tests
+///
+/// Commands still running after `timeout` are killed and reported as
+/// [`std::io::ErrorKind::TimedOut`].
 #[cfg_attr(test, allow(clippy::disallowed_methods))]
 #[cfg_attr(test, mockall::automock)]
 pub trait CommandExecutor: Send + Sync {
-    fn execute_outdated(&self) -> std::io::Result<Output>;
-    fn execute_udeps(&self) -> std::io::Result<Output>;
+    fn execute_outdated(&self, timeout: Duration) -> std::io::Result<Output>;
+    fn execute_udeps(&self, timeout: Duration) -> std::io::Result<Output>;
     fn is_command_available(&self, command: &str) -> bool;
 }
 
 /// Default implementation that calls the actual cargo commands
+///
+/// Every child is tracked by its [`Child`] handle until it has been reaped, so only
+/// processes this executor started are ever killed, and their ids cannot be reused
+/// by unrelated processes in the meantime. Children still running when the executor
+/// is dropped are killed.
 pub struct RealCommandExecutor {
-    is_running: Arc<AtomicBool>,
+    /// Project directory the tools run in
+    dir: PathBuf,
+    children: Mutex<HashMap<u32, Child>>,
+}
+
+/// A tracked child and the threads draining its output
+struct Spawned {
+    id: u32,
+    stdout: Option<JoinHandle<Vec<u8>>>,
+    stderr: Option<JoinHandle<Vec<u8>>>,
+}
+
+impl CommandExecutor for RealCommandExecutor {
+    fn execute_outdated(&self, timeout: Duration) -> std::io::Result<Output> {
+        let mut command = Command::new("cargo");
+        command
+            .current_dir(&self.dir)
+            .args(["outdated", "--exit-code", "1"]);
+        self.output_with_timeout(command, timeout)
+    }
+
+    fn execute_udeps(&self, timeout: Duration) -> std::io::Result<Output> {
+        let mut command = Command::new("cargo");
+        command
+            .current_dir(&self.dir)
+            .args(["udeps", "--", "--no-run", "--no-fail-fast"]);
+        self.output_with_timeout(command, timeout)
+    }
+
+    fn is_command_available(&self, command: &str) -> bool {
+        which::which(command).is_ok()
+    }
 }
 
 impl RealCommandExecutor {
-    fn new() -> Self {
+    /// Create an executor that runs the tools in the given project directory
+    pub fn new(dir: &Path) -> Self {
         Self {
-            is_running: Arc::new(AtomicBool::new(false)),
+            dir: dir.into(),
+            children: Mutex::default(),
+        }
+    }
+
+    /// Run `command` to completion, killing it once `timeout` has elapsed
+    fn output_with_timeout(&self, command: Command, timeout: Duration) -> std::io::Result<Output> {
+        let spawned = self.spawn(command)?;
+
+        let deadline = Instant::now() + timeout;
+        let status = loop {
+            if let Some(status) = self.try_wait(spawned.id)? {
+                break status;
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                self.kill(spawned.id)?;
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("timed out after {timeout:?}"),
+                ));
+            }
+            std::thread::sleep(POLL_INTERVAL.min(deadline - now));
+        };
+
+        let collect = |handle: Option<JoinHandle<Vec<u8>>>| {
+            handle
+                .and_then(|handle| handle.join().ok())
+                .unwrap_or_default()
+        };
+        Ok(Output {
+            status,
+            stdout: collect(spawned.stdout),
+            stderr: collect(spawned.stderr),
+        })
+    }
+
+    /// Start `command` with piped output and track it until it is reaped
+    fn spawn(&self, mut command: Command) -> std::io::Result<Spawned> {
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain the pipes while waiting so a chatty child cannot block on a full buffer
+        let spawned = Spawned {
+            id: child.id(),
+            stdout: child.stdout.take().map(read_in_background),
+            stderr: child.stderr.take().map(read_in_background),
+        };
+        self.children().insert(spawned.id, child);
+        Ok(spawned)
+    }
+
+    /// Poll a tracked child, forgetting it once it has exited
+    fn try_wait(&self, id: u32) -> std::io::Result<Option<ExitStatus>> {
+        let mut children = self.children();
+        let Some(child) = children.get_mut(&id) else {
+            return Err(std::io::Error::other(format!(
+                "process {id} is not tracked by this executor"
+            )));
+        };
+
+        let status = child.try_wait()?;
+        if status.is_some() {
+            children.remove(&id);
         }
+        Ok(status)
+    }
+
+    /// Kill and reap a tracked child; ids this executor did not spawn are ignored
+    fn kill(&self, id: u32) -> std::io::Result<()> {
+        if let Some(mut child) = self.children().remove(&id) {
+            // The child may exit between the poll and the kill; either way, reap it
+            let _ = child.kill();
+            child.wait()?;
+        }
+        Ok(())
+    }
+
+    fn children(&self) -> MutexGuard<'_, HashMap<u32, Child>> {
+        self.children.lock().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
 impl Drop for RealCommandExecutor {
     fn drop(&mut self) {
-        if self.is_running.load(Ordering::SeqCst) {
-            // Ensure any running processes are cleaned up
-            if let Ok(ps) = std::process::Command::new("ps").args(["aux"]).output() {
-                let output = String::from_utf8_lossy(&ps.stdout);
-                for line in output.lines() {
-                    if line.contains("cargo outdated") || line.contains("cargo udeps") {
-                        if let Some(pid) = line.split_whitespace().nth(1) {
-                            if let Ok(pid) = pid.parse::<i32>() {
-                                unsafe {
-                                    libc::kill(pid, libc::SIGTERM);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        for (_, mut child) in self.children().drain() {
+            let _ = child.kill();
+            let _ = child.wait();
         }
     }
 }
 
-impl CommandExecutor for RealCommandExecutor {
-    fn execute_outdated(&self) -> std::io::Result<Output> {
-        self.is_running.store(true, Ordering::SeqCst);
-        let output = Command::new("cargo")
-            .args(["outdated", "--exit-code", "1"])
-            .output();
-        self.is_running.store(false, Ordering::SeqCst);
-        output
+/// Read `pipe` to the end on a separate thread
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// Outdated and unused dependency results recorded for one `Cargo.lock`
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResults {
+    /// Hash of the lockfile the results were produced for
+    lockfile_hash: String,
+    /// Diagnostics from `cargo outdated` and `cargo udeps`
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Results cache for the external dependency tools, keyed by a hash of `Cargo.lock`
+pub struct DependencyCache {
+    lockfile: PathBuf,
+    path: PathBuf,
+}
+
+impl DependencyCache {
+    /// Cache for the project at `root`, stored in its `target` directory
+    pub fn in_project(root: &Path) -> Self {
+        Self {
+            lockfile: root.join("Cargo.lock"),
+            path: root.join(CACHE_FILE),
+        }
     }
 
-    fn execute_udeps(&self) -> std::io::Result<Output> {
-        self.is_running.store(true, Ordering::SeqCst);
-        let output = Command::new("cargo")
-            .args(["udeps", "--", "--no-run", "--no-fail-fast"])
-            .output();
-        self.is_running.store(false, Ordering::SeqCst);
-        output
+    /// Hash of the current lockfile, or `None` if there is none to key on
+    fn lockfile_hash(&self) -> Option<String> {
+        let content = std::fs::read(&self.lockfile).ok()?;
+        Some(format!("{:016x}", fnv1a(&content)))
     }
 
-    fn is_command_available(&self, command: &str) -> bool {
-        which::which(command).is_ok()
+    /// Diagnostics cached for `hash`, if any
+    fn load(&self, hash: &str) -> Option<Vec<Diagnostic>> {
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        let cached: CachedResults = serde_json::from_str(&content).ok()?;
+        (cached.lockfile_hash == hash).then_some(cached.diagnostics)
+    }
+
+    /// Record `diagnostics` for `hash`; failing to write only costs a re-run next time
+    fn store(&self, hash: &str, diagnostics: &[Diagnostic]) {
+        let cached = CachedResults {
+            lockfile_hash: hash.to_string(),
+            diagnostics: diagnostics.to_vec(),
+        };
+        let written = self
+            .path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                let json = serde_json::to_string_pretty(&cached).map_err(std::io::Error::other)?;
+                std::fs::write(&self.path, json)
+            });
+        if let Err(err) = written {
+            log::debug!("Could not write {}: {err}", self.path.display());
+        }
     }
 }
 
 /// Check for dependency issues in the project
 pub struct DependenciesCheck {
     executor: Box<dyn CommandExecutor>,
+    cache: Option<DependencyCache>,
+    timeout: Duration,
 }
 
 impl Drop for DependenciesCheck {
@@ -93,17 +261,75 @@ impl Drop for DependenciesCheck {
 }
 
 impl DependenciesCheck {
-    /// Create a new DependenciesCheck with the default command executor
-    pub fn new() -> Self {
+    /// Create a new DependenciesCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
         Self {
-            executor: Box::new(RealCommandExecutor::new()),
+            executor: Box::new(RealCommandExecutor::new(dir)),
+            cache: None,
+            timeout: DEFAULT_TOOL_TIMEOUT,
         }
     }
 
     /// Create a new DependenciesCheck with a custom command executor (for testing)
     #[cfg(test)]
     pub fn with_executor(executor: Box<dyn CommandExecutor>) -> Self {
-        Self { executor }
+        Self {
+            executor,
+            cache: None,
+            timeout: DEFAULT_TOOL_TIMEOUT,
+        }
+    }
+
+    /// Reuse tool results from `cache` while `Cargo.lock` is unchanged
+    pub fn with_cache(mut self, cache: DependencyCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Kill `cargo outdated` and `cargo udeps` if they run longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Report a tool that was killed for exceeding the timeout
+    fn timed_out(&self, tool: &str) -> Diagnostic {
+        Diagnostic::new(
+            self.name(),
+            Severity::Suggestion,
+            format!("cargo {tool} timed out after {:?}", self.timeout),
+            self.category(),
+        )
+        .with_suggestion(format!(
+            "Run 'cargo {tool}' directly, or raise the limit with --tool-timeout"
+        ))
+    }
+
+    /// Run `cargo outdated` and `cargo udeps`, or reuse their cached results
+    ///
+    /// Returns the diagnostics and whether they came from the cache. Missing tools and
+    /// failed runs are not cached, so they are retried on the next run.
+    fn tool_diagnostics(&self) -> (Vec<Diagnostic>, bool) {
+        let keyed = self
+            .cache
+            .as_ref()
+            .and_then(|cache| Some((cache, cache.lockfile_hash()?)));
+
+        if let Some(cached) = keyed.as_ref().and_then(|(cache, hash)| cache.load(hash)) {
+            return (cached, true);
+        }
+
+        let diagnostics = vec![self.check_outdated(), self.check_unused()];
+        if let Some((cache, hash)) = keyed {
+            if diagnostics
+                .iter()
+                .all(|d| d.severity != Severity::Suggestion)
+            {
+                cache.store(&hash, &diagnostics);
+            }
+        }
+
+        (diagnostics, false)
     }
 
     /// Check for outdated dependencies using cargo-outdated
@@ -119,7 +345,7 @@ impl DependenciesCheck {
             .with_suggestion(CARGO_OUTDATED_RECOMMENDATION);
         }
 
-        match self.executor.execute_outdated() {
+        match self.executor.execute_outdated(self.timeout) {
             Ok(output) => {
                 if output.status.success() {
                     Diagnostic::new(
@@ -138,6 +364,7 @@ impl DependenciesCheck {
                     .with_suggestion("Run 'cargo outdated' to see details and update dependencies")
                 }
             }
+            Err(err) if err.kind() == std::io::ErrorKind::TimedOut => self.timed_out("outdated"),
             Err(_) => Diagnostic::new(
                 self.name(),
                 Severity::Suggestion,
@@ -161,7 +388,7 @@ impl DependenciesCheck {
             .with_suggestion(CARGO_UDEPS_RECOMMENDATION);
         }
 
-        match self.executor.execute_udeps() {
+        match self.executor.execute_udeps(self.timeout) {
             Ok(output) => {
                 if output.status.success() {
                     Diagnostic::new(
@@ -185,6 +412,7 @@ impl DependenciesCheck {
                     )
                 }
             }
+            Err(err) if err.kind() == std::io::ErrorKind::TimedOut => self.timed_out("udeps"),
             Err(_) => Diagnostic::new(
                 self.name(),
                 Severity::Suggestion,
@@ -210,11 +438,22 @@ impl DependenciesCheck {
 
 impl Check for DependenciesCheck {
     fn run(&self) -> Vec<Diagnostic> {
-        vec![
-            self.check_outdated(),
-            self.check_unused(),
-            self.check_dependency_versions(),
-        ]
+        let (mut diagnostics, cached) = self.tool_diagnostics();
+        diagnostics.push(self.check_dependency_versions());
+
+        if cached {
+            diagnostics.push(
+                Diagnostic::new(
+                    self.name(),
+                    Severity::Info,
+                    "Reused outdated/unused results cached for the current Cargo.lock",
+                    self.category(),
+                )
+                .with_suggestion("Run with --no-cache to check again"),
+            );
+        }
+
+        diagnostics
     }
 
     fn name(&self) -> &str {
@@ -228,6 +467,10 @@ impl Check for DependenciesCheck {
     fn category(&self) -> &str {
         "dependencies"
     }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -254,13 +497,13 @@ mod tests {
 
     #[test]
     fn test_name_method() {
-        let check = DependenciesCheck::new();
+        let check = DependenciesCheck::new(Path::new("."));
         assert_eq!(check.name(), "Dependencies");
     }
 
     #[test]
     fn test_description_method() {
-        let check = DependenciesCheck::new();
+        let check = DependenciesCheck::new(Path::new("."));
         assert_eq!(
             check.description(),
             "Check for dependency issues (outdated, unused)"
@@ -269,7 +512,7 @@ mod tests {
 
     #[test]
     fn test_category_method() {
-        let check = DependenciesCheck::new();
+        let check = DependenciesCheck::new(Path::new("."));
         assert_eq!(check.category(), "dependencies");
     }
 
@@ -283,7 +526,7 @@ mod tests {
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -299,7 +542,7 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(false);
@@ -318,12 +561,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -339,12 +582,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(failure_output()));
+            .return_once(|_| Ok(failure_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -362,12 +605,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -383,12 +626,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(failure_output()));
+            .return_once(|_| Ok(failure_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -404,12 +647,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Err(std::io::Error::other("command failed")));
+            .return_once(|_| Err(std::io::Error::other("command failed")));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -427,12 +670,12 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Err(std::io::Error::other("command failed")));
+            .return_once(|_| Err(std::io::Error::other("command failed")));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
@@ -450,15 +693,200 @@ mod tests {
             .with(mockall::predicate::eq("cargo-outdated"))
             .return_const(true);
         mock.expect_execute_outdated()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
         mock.expect_is_command_available()
             .with(mockall::predicate::eq("cargo-udeps"))
             .return_const(true);
         mock.expect_execute_udeps()
-            .return_once(|| Ok(success_output()));
+            .return_once(|_| Ok(success_output()));
 
         let check = DependenciesCheck::with_executor(Box::new(mock));
         let diagnostics = check.run();
         assert_eq!(diagnostics.len(), 3);
     }
+
+    /// A mock whose tools are installed and must run exactly `runs` times
+    fn counting_mock(runs: usize) -> MockCommandExecutor {
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(true);
+        mock.expect_execute_outdated()
+            .times(runs)
+            .returning(|_| Ok(failure_output()));
+        mock.expect_execute_udeps()
+            .times(runs)
+            .returning(|_| Ok(success_output()));
+        mock
+    }
+
+    #[test]
+    fn test_unchanged_lockfile_uses_cache() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("Cargo.lock"), "version = 4\n")?;
+
+        let check = DependenciesCheck::with_executor(Box::new(counting_mock(2)))
+            .with_cache(DependencyCache::in_project(dir.path()));
+
+        let first = check.run();
+        assert_eq!(first.len(), 3);
+        assert!(dir.path().join(CACHE_FILE).is_file());
+
+        // Same lockfile: the tools are not run again
+        let second = check.run();
+        assert_eq!(second.len(), 4);
+        assert_eq!(second[0].message, first[0].message);
+        assert_eq!(second[0].severity, Severity::Warning);
+        assert_eq!(second[1].message, first[1].message);
+        assert!(second[3].message.contains("cached"));
+
+        // A changed lockfile invalidates the cache
+        std::fs::write(dir.path().join("Cargo.lock"), "version = 4\n# changed\n")?;
+        assert_eq!(check.run().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_tools_are_not_cached() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("Cargo.lock"), "version = 4\n")?;
+
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(false);
+        let check = DependenciesCheck::with_executor(Box::new(mock))
+            .with_cache(DependencyCache::in_project(dir.path()));
+
+        check.run();
+        assert!(!dir.path().join(CACHE_FILE).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_lockfile_skips_cache() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        let check = DependenciesCheck::with_executor(Box::new(counting_mock(2)))
+            .with_cache(DependencyCache::in_project(dir.path()));
+
+        check.run();
+        check.run();
+        assert!(!dir.path().join(CACHE_FILE).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tool_timeout() {
+        let timeout = Duration::from_secs(7);
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(true);
+        mock.expect_execute_outdated()
+            .with(mockall::predicate::eq(timeout))
+            .return_once(|_| {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "timed out",
+                ))
+            });
+        mock.expect_execute_udeps()
+            .with(mockall::predicate::eq(timeout))
+            .return_once(|_| Ok(success_output()));
+
+        let check = DependenciesCheck::with_executor(Box::new(mock)).with_timeout(timeout);
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics[0].severity, Severity::Suggestion);
+        assert_eq!(diagnostics[0].message, "cargo outdated timed out after 7s");
+        assert!(diagnostics[0]
+            .suggestion
+            .as_deref()
+            .is_some_and(|s| s.contains("--tool-timeout")));
+        assert_eq!(diagnostics[1].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_output_with_timeout_kills_slow_command() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping process test under Miri");
+            return Ok(());
+        }
+
+        let mut command = Command::new("sleep");
+        command.arg("10");
+
+        let start = Instant::now();
+        let err = RealCommandExecutor::new(Path::new("."))
+            .output_with_timeout(command, Duration::from_millis(100))
+            .expect_err("sleep should time out");
+
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_with_timeout_collects_output() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping process test under Miri");
+            return Ok(());
+        }
+
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo out; echo err >&2; exit 3"]);
+
+        let output = RealCommandExecutor::new(Path::new("."))
+            .output_with_timeout(command, Duration::from_secs(10))?;
+
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_executor_drop_kills_only_its_own_children() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping process test under Miri");
+            return Ok(());
+        }
+
+        let mut unrelated = Command::new("sleep").arg("10").spawn()?;
+
+        let executor = RealCommandExecutor::new(Path::new("."));
+        let mut command = Command::new("sleep");
+        command.arg("10");
+        let spawned = executor.spawn(command)?;
+        let proc_entry = PathBuf::from(format!("/proc/{}", spawned.id));
+        assert!(proc_entry.exists());
+
+        // Ids the executor did not start are left alone
+        executor.kill(unrelated.id())?;
+        drop(executor);
+
+        // Dropping kills and reaps the tracked child, so its process entry is gone
+        assert!(!proc_entry.exists());
+        assert!(unrelated.try_wait()?.is_none());
+
+        unrelated.kill()?;
+        unrelated.wait()?;
+        Ok(())
+    }
 }
@@ -0,0 +1,309 @@
+//! Check that all crates in the project use a consistent, modern edition
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Oldest edition that does not trigger a warning
+const MINIMUM_EDITION: &str = "2021";
+
+/// Edition cargo assumes when a manifest does not declare one
+const DEFAULT_EDITION: &str = "2015";
+
+/// Trait for collecting manifests in the project, allowing for easier mocking in tests
+pub trait ManifestSource: Send + Sync {
+    /// Return `(path, content)` pairs, with the root manifest first
+    fn manifests(&self) -> Vec<(PathBuf, String)>;
+}
+
+/// Default implementation that reads the root manifest and any workspace members
+pub struct WorkspaceManifestSource {
+    root: PathBuf,
+}
+
+impl WorkspaceManifestSource {
+    /// Create a source rooted at the given directory
+    pub fn new(root: &Path) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Resolve workspace member patterns to directories (supports trailing `/*`)
+    fn member_dirs(&self, members: &[toml::Value]) -> Vec<PathBuf> {
+        members
+            .iter()
+            .filter_map(|m| m.as_str())
+            .flat_map(|member| match member.strip_suffix("/*") {
+                Some(parent) => std::fs::read_dir(self.root.join(parent))
+                    .map(|entries| {
+                        let mut dirs: Vec<PathBuf> = entries
+                            .filter_map(|e| e.ok().map(|e| e.path()))
+                            .filter(|p| p.is_dir())
+                            .collect();
+                        dirs.sort();
+                        dirs
+                    })
+                    .unwrap_or_default(),
+                None => vec![self.root.join(member)],
+            })
+            .collect()
+    }
+}
+
+impl ManifestSource for WorkspaceManifestSource {
+    fn manifests(&self) -> Vec<(PathBuf, String)> {
+        let root_path = self.root.join("Cargo.toml");
+        let Ok(root_content) = std::fs::read_to_string(&root_path) else {
+            return Vec::new();
+        };
+
+        let members = toml::from_str::<toml::Table>(&root_content)
+            .ok()
+            .and_then(|t| {
+                t.get("workspace")?
+                    .get("members")?
+                    .as_array()
+                    .map(|m| self.member_dirs(m))
+            })
+            .unwrap_or_default();
+
+        let mut manifests = vec![(root_path, root_content)];
+        for dir in members {
+            let path = dir.join("Cargo.toml");
+            if let Ok(content) = std::fs::read_to_string(&path) {
+                manifests.push((path, content));
+            }
+        }
+
+        manifests
+    }
+}
+
+/// Check for mixed or outdated editions across the project
+pub struct EditionCheck {
+    source: Box<dyn ManifestSource>,
+}
+
+impl EditionCheck {
+    /// Create a new EditionCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            source: Box::new(WorkspaceManifestSource::new(dir)),
+        }
+    }
+
+    /// Create a new EditionCheck with a custom manifest source (for testing)
+    #[cfg(test)]
+    pub fn with_source(source: Box<dyn ManifestSource>) -> Self {
+        Self { source }
+    }
+
+    /// Collect the effective edition of every package, keyed by manifest path
+    fn editions(&self) -> Vec<(PathBuf, String)> {
+        let manifests: Vec<(PathBuf, toml::Table)> = self
+            .source
+            .manifests()
+            .into_iter()
+            .filter_map(|(path, content)| Some((path, toml::from_str(&content).ok()?)))
+            .collect();
+
+        // Editions inherited with `edition.workspace = true` come from the root
+        let workspace_edition = manifests
+            .first()
+            .and_then(|(_, root)| root.get("workspace")?.get("package")?.get("edition"))
+            .and_then(|e| e.as_str())
+            .map(str::to_string);
+
+        manifests
+            .into_iter()
+            .filter_map(|(path, manifest)| {
+                let package = manifest.get("package")?.as_table()?;
+                let edition = match package.get("edition") {
+                    Some(toml::Value::String(edition)) => edition.clone(),
+                    Some(_) => workspace_edition
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_EDITION.to_string()),
+                    None => DEFAULT_EDITION.to_string(),
+                };
+                Some((path, edition))
+            })
+            .collect()
+    }
+}
+
+impl Check for EditionCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let editions = self.editions();
+        if editions.is_empty() {
+            return vec![Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "No packages found, skipping edition check.",
+                self.category(),
+            )];
+        }
+
+        let mut diagnostics: Vec<Diagnostic> = editions
+            .iter()
+            .filter(|(_, edition)| edition.as_str() < MINIMUM_EDITION)
+            .map(|(path, edition)| {
+                Diagnostic::new(
+                    self.name(),
+                    Severity::Warning,
+                    format!("{} uses edition {edition}.", path.display()),
+                    self.category(),
+                )
+                .with_suggestion(format!(
+                    "Migrate to edition {MINIMUM_EDITION} or newer with 'cargo fix --edition'."
+                ))
+            })
+            .collect();
+
+        let distinct: BTreeSet<&str> = editions.iter().map(|(_, e)| e.as_str()).collect();
+        if distinct.len() > 1 {
+            let list: Vec<&str> = distinct.into_iter().collect();
+            diagnostics.push(
+                Diagnostic::new(
+                    self.name(),
+                    Severity::Warning,
+                    format!("Crates use mixed editions: {}.", list.join(", ")),
+                    self.category(),
+                )
+                .with_suggestion(
+                    "Use the same edition everywhere, e.g. via `edition.workspace = true`.",
+                ),
+            );
+        } else if diagnostics.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                format!("All crates use edition {}.", editions[0].1),
+                self.category(),
+            ));
+        }
+
+        diagnostics
+    }
+
+    fn name(&self) -> &str {
+        "Edition Consistency"
+    }
+
+    fn description(&self) -> &str {
+        "Check that all crates use the same, modern Rust edition"
+    }
+
+    fn category(&self) -> &str {
+        "edition"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    struct MockManifestSource {
+        manifests: Vec<(&'static str, &'static str)>,
+    }
+
+    impl ManifestSource for MockManifestSource {
+        fn manifests(&self) -> Vec<(PathBuf, String)> {
+            self.manifests
+                .iter()
+                .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+                .collect()
+        }
+    }
+
+    fn check_for(manifests: Vec<(&'static str, &'static str)>) -> EditionCheck {
+        EditionCheck::with_source(Box::new(MockManifestSource { manifests }))
+    }
+
+    #[test]
+    fn test_consistent_editions() {
+        let check =
+            check_for(vec![
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"a\", \"b\"]\n[workspace.package]\nedition = \"2021\"\n",
+            ),
+            (
+                "a/Cargo.toml",
+                "[package]\nname = \"a\"\nedition.workspace = true\n",
+            ),
+            ("b/Cargo.toml", "[package]\nname = \"b\"\nedition = \"2021\"\n"),
+        ]);
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("2021"));
+    }
+
+    #[test]
+    fn test_inconsistent_editions() {
+        let check = check_for(vec![
+            ("Cargo.toml", "[workspace]\nmembers = [\"a\", \"b\"]\n"),
+            (
+                "a/Cargo.toml",
+                "[package]\nname = \"a\"\nedition = \"2024\"\n",
+            ),
+            (
+                "b/Cargo.toml",
+                "[package]\nname = \"b\"\nedition = \"2021\"\n",
+            ),
+        ]);
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].message.contains("2021, 2024"));
+    }
+
+    #[test]
+    fn test_old_edition_warns() {
+        // Missing edition means cargo falls back to 2015
+        let check = check_for(vec![("Cargo.toml", "[package]\nname = \"old\"\n")]);
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].message.contains("2015"));
+    }
+
+    #[test]
+    fn test_no_manifests() {
+        let diagnostics = check_for(Vec::new()).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_workspace_source_reads_members() -> std::io::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        for name in ["one", "two"] {
+            let member = dir.path().join("crates").join(name);
+            std::fs::create_dir_all(&member)?;
+            std::fs::write(
+                member.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nedition = \"2021\"\n"),
+            )?;
+        }
+
+        let manifests = WorkspaceManifestSource::new(dir.path()).manifests();
+        assert_eq!(manifests.len(), 3);
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::commands::doctor::diagnosis::Check;
 use crate::commands::doctor::types::{Diagnostic, Severity};
+use color_eyre::{eyre::WrapErr, Result};
 use std::path::Path;
 
 /// A check for required project files
@@ -75,6 +76,32 @@ impl Check for FilesCheck {
     fn category(&self) -> &str {
         "files"
     }
+
+    fn fix(&self) -> Result<bool> {
+        let project_name = self
+            .dir
+            .canonicalize()
+            .ok()
+            .and_then(|dir| dir.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "project".to_string());
+
+        let stubs = [
+            (".gitignore", "/target\n".to_string()),
+            ("README.md", format!("# {project_name}\n")),
+        ];
+
+        let mut changed = false;
+        for (file, content) in stubs {
+            let path = self.dir.join(file);
+            if !path.exists() {
+                std::fs::write(&path, content)
+                    .wrap_err_with(|| format!("Failed to create {}", path.display()))?;
+                changed = true;
+            }
+        }
+
+        Ok(changed)
+    }
 }
 
 #[cfg(test)]
@@ -200,4 +227,32 @@ mod tests {
         assert_eq!(license.severity, Severity::Warning);
         Ok(())
     }
+
+    #[test]
+    fn test_fix_creates_stub_files() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = setup_test_dir(&["Cargo.toml"])?;
+        let check = FilesCheck::new(dir.path());
+
+        assert!(check.fix()?);
+        assert!(dir.path().join(".gitignore").exists());
+        assert!(dir.path().join("README.md").exists());
+
+        // A second fix has nothing left to do
+        assert!(!check.fix()?);
+
+        let diagnostics = check.run();
+        let warnings: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.severity != Severity::Info)
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("LICENSE"));
+        Ok(())
+    }
 }
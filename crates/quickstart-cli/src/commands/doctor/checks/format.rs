@@ -0,0 +1,231 @@
+//! Check for formatting issues in the project
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Trait for executing rustfmt command, allowing for easier mocking in tests
+pub trait CommandExecutor: Send + Sync {
+    fn execute_fmt(&self) -> std::io::Result<Output>;
+}
+
+/// Default implementation that calls the actual cargo fmt command
+pub struct RealCommandExecutor {
+    /// Project directory the command runs in
+    dir: PathBuf,
+}
+
+impl CommandExecutor for RealCommandExecutor {
+    fn execute_fmt(&self) -> std::io::Result<Output> {
+        Command::new("cargo")
+            .current_dir(&self.dir)
+            .args(["fmt", "--all", "--", "--check"])
+            .output()
+    }
+}
+
+/// Check for formatting issues in the project
+pub struct FormatCheck {
+    executor: Box<dyn CommandExecutor>,
+}
+
+impl FormatCheck {
+    /// Create a new FormatCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            executor: Box::new(RealCommandExecutor { dir: dir.into() }),
+        }
+    }
+
+    /// Create a new FormatCheck with a custom command executor (for testing)
+    #[cfg(test)]
+    pub fn with_executor(executor: Box<dyn CommandExecutor>) -> Self {
+        Self { executor }
+    }
+}
+
+impl Check for FormatCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Try to run cargo fmt in check mode
+        let fmt_result = self.executor.execute_fmt();
+
+        match fmt_result {
+            Ok(output) => {
+                if output.status.success() {
+                    diagnostics.push(Diagnostic::new(
+                        self.name(),
+                        Severity::Info,
+                        "Code is formatted according to rustfmt",
+                        self.category(),
+                    ));
+                } else {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            self.name(),
+                            Severity::Warning,
+                            "Code is not formatted according to rustfmt",
+                            self.category(),
+                        )
+                        .with_suggestion("Run 'cargo fmt --all' to format the code"),
+                    );
+                }
+            }
+            Err(_) => {
+                diagnostics.push(
+                    Diagnostic::new(
+                        self.name(),
+                        Severity::Warning,
+                        "Failed to run cargo fmt",
+                        self.category(),
+                    )
+                    .with_suggestion(
+                        "Make sure rustfmt is installed: 'rustup component add rustfmt'",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+
+    fn name(&self) -> &str {
+        "Formatting"
+    }
+
+    fn description(&self) -> &str {
+        "Check code formatting with rustfmt"
+    }
+
+    fn category(&self) -> &str {
+        "format"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::{Error, ErrorKind};
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    // Mock command executor for testing different scenarios
+    enum MockExecutorResult {
+        Success(Output),
+        Failure(Output),
+        Error,
+    }
+
+    struct MockCommandExecutor {
+        result: MockExecutorResult,
+    }
+
+    impl CommandExecutor for MockCommandExecutor {
+        fn execute_fmt(&self) -> std::io::Result<Output> {
+            match &self.result {
+                MockExecutorResult::Success(output) => Ok(output.clone()),
+                MockExecutorResult::Failure(output) => Ok(output.clone()),
+                MockExecutorResult::Error => {
+                    Err(Error::new(ErrorKind::NotFound, "command not found"))
+                }
+            }
+        }
+    }
+
+    // Helper to create a success output
+    fn success_output() -> Output {
+        Output {
+            status: ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        }
+    }
+
+    // Helper to create a failure output
+    fn failure_output() -> Output {
+        Output {
+            status: ExitStatus::from_raw(1),
+            stdout: Vec::new(),
+            stderr: b"Diff in src/main.rs".to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_format_check_success() {
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Success(success_output()),
+        };
+        let check = FormatCheck::with_executor(Box::new(executor));
+
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("Code is formatted"));
+    }
+
+    #[test]
+    fn test_format_check_warning() {
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Failure(failure_output()),
+        };
+        let check = FormatCheck::with_executor(Box::new(executor));
+
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0]
+            .message
+            .contains("Code is not formatted according to rustfmt"));
+
+        // Check if suggestion exists and contains expected text
+        if let Some(suggestion) = &diagnostics[0].suggestion {
+            assert!(suggestion.contains("Run 'cargo fmt --all'"));
+        } else {
+            panic!("Expected suggestion to be present");
+        }
+    }
+
+    #[test]
+    fn test_format_check_error() {
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Error,
+        };
+        let check = FormatCheck::with_executor(Box::new(executor));
+
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].message.contains("Failed to run cargo fmt"));
+
+        // Check if suggestion exists and contains expected text
+        if let Some(suggestion) = &diagnostics[0].suggestion {
+            assert!(suggestion.contains("Make sure rustfmt is installed"));
+        } else {
+            panic!("Expected suggestion to be present");
+        }
+    }
+
+    #[test]
+    fn test_name_method() {
+        let check = FormatCheck::new(Path::new("."));
+        assert_eq!(check.name(), "Formatting");
+    }
+
+    #[test]
+    fn test_description_method() {
+        let check = FormatCheck::new(Path::new("."));
+        assert_eq!(check.description(), "Check code formatting with rustfmt");
+    }
+
+    #[test]
+    fn test_category_method() {
+        let check = FormatCheck::new(Path::new("."));
+        assert_eq!(check.category(), "format");
+    }
+}
@@ -2,41 +2,314 @@
 
 use crate::commands::doctor::diagnosis::Check;
 use crate::commands::doctor::types::{Diagnostic, Severity};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 
+/// How many individual clippy findings are reported as diagnostics
+const MAX_REPORTED_FINDINGS: usize = 5;
+
 /// Trait for executing clippy command, allowing for easier mocking in tests
-pub trait CommandExecutor {
+pub trait CommandExecutor: Send + Sync {
     fn execute_clippy(&self) -> std::io::Result<Output>;
 }
 
 /// Default implementation that calls the actual cargo clippy command
-pub struct RealCommandExecutor;
+pub struct RealCommandExecutor {
+    /// Project directory the command runs in
+    dir: PathBuf,
+}
 
 impl CommandExecutor for RealCommandExecutor {
     fn execute_clippy(&self) -> std::io::Result<Output> {
         Command::new("cargo")
+            .current_dir(&self.dir)
             .args(["clippy", "--quiet", "--message-format=json"])
             .output()
     }
 }
 
+/// One line of `cargo --message-format=json` output
+#[derive(Debug, Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<CompilerMessage>,
+}
+
+/// A rustc or clippy diagnostic inside a `compiler-message` line
+#[derive(Debug, Deserialize)]
+struct CompilerMessage {
+    level: String,
+    message: String,
+    code: Option<CompilerCode>,
+    spans: Vec<CompilerSpan>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompilerCode {
+    code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompilerSpan {
+    file_name: String,
+    line_start: u32,
+    column_start: u32,
+    is_primary: bool,
+}
+
+/// A clippy finding located in a source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClippyFinding {
+    /// `error` or `warning`
+    pub level: String,
+    /// Lint name, e.g. `clippy::needless_return`, if the finding has one
+    pub code: Option<String>,
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl ClippyFinding {
+    /// Location as `file:line:column`
+    fn location(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// Parse the findings from `cargo clippy --message-format=json` output
+///
+/// Returns `None` when the output contains no cargo JSON messages at all. Messages
+/// without a primary span (such as "N warnings emitted") are skipped, and findings
+/// reported once per target are only kept once.
+pub fn parse_clippy_output(stdout: &str) -> Option<Vec<ClippyFinding>> {
+    let messages: Vec<CargoMessage> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    if messages.is_empty() {
+        return None;
+    }
+
+    let mut findings: Vec<ClippyFinding> = Vec::new();
+    let compiler_messages = messages
+        .into_iter()
+        .filter(|m| m.reason == "compiler-message")
+        .filter_map(|m| m.message)
+        .filter(|m| m.level == "error" || m.level == "warning");
+
+    for message in compiler_messages {
+        let Some(span) = message.spans.iter().find(|span| span.is_primary) else {
+            continue;
+        };
+
+        let finding = ClippyFinding {
+            level: message.level,
+            code: message.code.map(|code| code.code),
+            message: message.message,
+            file: span.file_name.clone(),
+            line: span.line_start,
+            column: span.column_start,
+        };
+        if !findings.contains(&finding) {
+            findings.push(finding);
+        }
+    }
+
+    Some(findings)
+}
+
+/// SARIF 2.1.0 log, reduced to the fields CI code annotations use
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: u32,
+    start_column: u32,
+}
+
+/// Render findings as a SARIF log for CI code annotations
+pub fn to_sarif(findings: &[ClippyFinding]) -> SarifLog {
+    let results = findings
+        .iter()
+        .map(|finding| SarifResult {
+            rule_id: finding.code.clone().unwrap_or_else(|| "rustc".to_string()),
+            level: if finding.level == "error" {
+                "error"
+            } else {
+                "warning"
+            },
+            message: SarifMessage {
+                text: finding.message.clone(),
+            },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: finding.file.clone(),
+                    },
+                    region: SarifRegion {
+                        start_line: finding.line,
+                        start_column: finding.column,
+                    },
+                },
+            }],
+        })
+        .collect();
+
+    SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "clippy",
+                    information_uri: "https://rust-lang.github.io/rust-clippy/",
+                },
+            },
+            results,
+        }],
+    }
+}
+
 /// Check for linting issues in the project
 pub struct LintsCheck {
     executor: Box<dyn CommandExecutor>,
+    sarif_path: Option<PathBuf>,
 }
 
 impl LintsCheck {
-    /// Create a new LintsCheck with the default command executor
-    pub fn new() -> Self {
+    /// Create a new LintsCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
         Self {
-            executor: Box::new(RealCommandExecutor),
+            executor: Box::new(RealCommandExecutor { dir: dir.into() }),
+            sarif_path: None,
         }
     }
 
     /// Create a new LintsCheck with a custom command executor (for testing)
     #[cfg(test)]
     pub fn with_executor(executor: Box<dyn CommandExecutor>) -> Self {
-        Self { executor }
+        Self {
+            executor,
+            sarif_path: None,
+        }
+    }
+
+    /// Also write the parsed clippy findings to `path` as SARIF
+    pub fn with_sarif(mut self, path: &Path) -> Self {
+        self.sarif_path = Some(path.into());
+        self
+    }
+
+    /// Summarise the findings and report the first few individually, errors first
+    fn findings_diagnostics(&self, findings: &[ClippyFinding]) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![Diagnostic::new(
+            self.name(),
+            Severity::Warning,
+            format!("Clippy found {} linting issue(s)", findings.len()),
+            self.category(),
+        )
+        .with_suggestion("Run 'cargo clippy' to see and fix the issues")];
+
+        let mut reported: Vec<&ClippyFinding> = findings.iter().collect();
+        reported.sort_by_key(|finding| finding.level != "error");
+
+        diagnostics.extend(
+            reported
+                .into_iter()
+                .take(MAX_REPORTED_FINDINGS)
+                .map(|finding| {
+                    let severity = if finding.level == "error" {
+                        Severity::Error
+                    } else {
+                        Severity::Warning
+                    };
+                    let message = match &finding.code {
+                        Some(code) => format!("{} ({code})", finding.message),
+                        None => finding.message.clone(),
+                    };
+                    Diagnostic::new(self.name(), severity, message, self.category())
+                        .with_details(finding.location())
+                }),
+        );
+
+        diagnostics
+    }
+
+    /// Write the SARIF report if one was requested, reporting a failure to do so
+    fn export_sarif(&self, findings: &[ClippyFinding]) -> Option<Diagnostic> {
+        let path = self.sarif_path.as_ref()?;
+        let written = serde_json::to_string_pretty(&to_sarif(findings))
+            .map_err(std::io::Error::from)
+            .and_then(|sarif| std::fs::write(path, sarif));
+
+        written.err().map(|e| {
+            Diagnostic::new(
+                self.name(),
+                Severity::Warning,
+                format!("Failed to write SARIF report to '{}'", path.display()),
+                self.category(),
+            )
+            .with_details(e.to_string())
+        })
     }
 }
 
@@ -49,24 +322,35 @@ impl Check for LintsCheck {
 
         match clippy_result {
             Ok(output) => {
-                if output.status.success() {
-                    diagnostics.push(Diagnostic::new(
-                        self.name(),
-                        Severity::Info,
-                        "No linting issues found with clippy",
-                        self.category(),
-                    ));
-                } else {
-                    // Parse output for issues
-                    diagnostics.push(
-                        Diagnostic::new(
+                let findings = parse_clippy_output(&String::from_utf8_lossy(&output.stdout));
+                if let Some(findings) = &findings {
+                    diagnostics.extend(self.export_sarif(findings));
+                }
+
+                match findings {
+                    Some(findings) if !findings.is_empty() => {
+                        diagnostics.extend(self.findings_diagnostics(&findings));
+                    }
+                    _ if output.status.success() => {
+                        diagnostics.push(Diagnostic::new(
                             self.name(),
-                            Severity::Warning,
-                            "Clippy found linting issues",
+                            Severity::Info,
+                            "No linting issues found with clippy",
                             self.category(),
-                        )
-                        .with_suggestion("Run 'cargo clippy' to see and fix the issues"),
-                    );
+                        ));
+                    }
+                    // Nothing could be parsed, so fall back to a summary of the failure
+                    _ => {
+                        diagnostics.push(
+                            Diagnostic::new(
+                                self.name(),
+                                Severity::Warning,
+                                "Clippy found linting issues",
+                                self.category(),
+                            )
+                            .with_suggestion("Run 'cargo clippy' to see and fix the issues"),
+                        );
+                    }
                 }
             }
             Err(_) => {
@@ -209,21 +493,167 @@ mod tests {
         }
     }
 
+    /// Captured `cargo clippy --message-format=json` output: a clippy lint, the same
+    /// lint again from the test target, a compile error, and build bookkeeping lines
+    const CLIPPY_JSON: &str = concat!(
+        r#"{"reason":"compiler-artifact","package_id":"demo 0.1.0","target":{"name":"demo"},"fresh":true}"#,
+        "\n",
+        r#"{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"rendered":"warning: unneeded `return` statement","$message_type":"diagnostic","children":[],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"byte_end":52,"byte_start":40,"column_end":17,"column_start":5,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":3,"line_start":3,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}"#,
+        "\n",
+        r#"{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"rendered":"warning: unneeded `return` statement","$message_type":"diagnostic","children":[],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"byte_end":52,"byte_start":40,"column_end":17,"column_start":5,"expansion":null,"file_name":"src/lib.rs","is_primary":true,"label":null,"line_end":3,"line_start":3,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}"#,
+        "\n",
+        r#"{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"rendered":"error[E0425]: cannot find value `x`","$message_type":"diagnostic","children":[],"code":{"code":"E0425","explanation":"..."},"level":"error","message":"cannot find value `x` in this scope","spans":[{"byte_end":90,"byte_start":89,"column_end":14,"column_start":13,"expansion":null,"file_name":"src/main.rs","is_primary":true,"label":"not found in this scope","line_end":7,"line_start":7,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}"#,
+        "\n",
+        r#"{"reason":"compiler-message","package_id":"demo 0.1.0","message":{"rendered":"warning: 1 warning emitted","$message_type":"diagnostic","children":[],"code":null,"level":"warning","message":"1 warning emitted","spans":[]}}"#,
+        "\n",
+        r#"{"reason":"build-finished","success":false}"#,
+        "\n",
+    );
+
+    fn output_with(code: i32, stdout: &str) -> Output {
+        Output {
+            status: ExitStatus::from_raw(code),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_clippy_output() {
+        let findings = parse_clippy_output(CLIPPY_JSON).unwrap_or_default();
+
+        assert_eq!(
+            findings,
+            vec![
+                ClippyFinding {
+                    level: "warning".to_string(),
+                    code: Some("clippy::needless_return".to_string()),
+                    message: "unneeded `return` statement".to_string(),
+                    file: "src/lib.rs".to_string(),
+                    line: 3,
+                    column: 5,
+                },
+                ClippyFinding {
+                    level: "error".to_string(),
+                    code: Some("E0425".to_string()),
+                    message: "cannot find value `x` in this scope".to_string(),
+                    file: "src/main.rs".to_string(),
+                    line: 7,
+                    column: 13,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_clippy_output_without_json() {
+        assert_eq!(parse_clippy_output("error: could not compile"), None);
+        assert_eq!(
+            parse_clippy_output(r#"{"reason":"build-finished","success":true}"#),
+            Some(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_lints_check_reports_findings() {
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Failure(output_with(101, CLIPPY_JSON)),
+        };
+        let check = LintsCheck::with_executor(Box::new(executor));
+
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].message, "Clippy found 2 linting issue(s)");
+        // Errors are reported before warnings
+        assert_eq!(diagnostics[1].severity, Severity::Error);
+        assert_eq!(diagnostics[1].details.as_deref(), Some("src/main.rs:7:13"));
+        assert_eq!(diagnostics[2].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[2].message,
+            "unneeded `return` statement (clippy::needless_return)"
+        );
+        assert_eq!(diagnostics[2].details.as_deref(), Some("src/lib.rs:3:5"));
+    }
+
+    #[test]
+    fn test_lints_check_caps_reported_findings() {
+        let line = CLIPPY_JSON.lines().nth(1).unwrap_or_default();
+        let stdout: String = (1..=8)
+            .map(|n| line.replace("\"line_start\":3", &format!("\"line_start\":{n}")) + "\n")
+            .collect();
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Success(output_with(0, &stdout)),
+        };
+        let check = LintsCheck::with_executor(Box::new(executor));
+
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics[0].message, "Clippy found 8 linting issue(s)");
+        assert_eq!(diagnostics.len(), 1 + MAX_REPORTED_FINDINGS);
+    }
+
+    #[test]
+    fn test_to_sarif() -> serde_json::Result<()> {
+        let findings = parse_clippy_output(CLIPPY_JSON).unwrap_or_default();
+        let sarif = serde_json::to_value(to_sarif(&findings))?;
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = &sarif["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "clippy::needless_return");
+        assert_eq!(results[0]["level"], "warning");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/lib.rs"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            3
+        );
+        assert_eq!(results[1]["level"], "error");
+        Ok(())
+    }
+
+    #[test]
+    fn test_lints_check_writes_sarif() -> std::io::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let path = temp_dir.path().join("clippy.sarif");
+        let executor = MockCommandExecutor {
+            result: MockExecutorResult::Success(output_with(0, CLIPPY_JSON)),
+        };
+        let check = LintsCheck::with_executor(Box::new(executor)).with_sarif(&path);
+
+        check.run();
+
+        let sarif: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        assert_eq!(
+            sarif["runs"][0]["results"].as_array().map(Vec::len),
+            Some(2)
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_name_method() {
-        let check = LintsCheck::new();
+        let check = LintsCheck::new(Path::new("."));
         assert_eq!(check.name(), "Linting");
     }
 
     #[test]
     fn test_description_method() {
-        let check = LintsCheck::new();
+        let check = LintsCheck::new(Path::new("."));
         assert_eq!(check.description(), "Check for linting issues with clippy");
     }
 
     #[test]
     fn test_category_method() {
-        let check = LintsCheck::new();
+        let check = LintsCheck::new(Path::new("."));
         assert_eq!(check.category(), "lints");
     }
 }
@@ -0,0 +1,220 @@
+//! Check for publish metadata in Cargo.toml
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::path::{Path, PathBuf};
+
+/// Trait for reading the project manifest, allowing for easier mocking in tests
+pub trait ManifestReader: Send + Sync {
+    fn read_manifest(&self) -> std::io::Result<String>;
+}
+
+/// Default implementation that reads Cargo.toml from disk
+pub struct FileManifestReader {
+    path: PathBuf,
+}
+
+impl FileManifestReader {
+    /// Create a reader for the Cargo.toml in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            path: dir.join("Cargo.toml"),
+        }
+    }
+}
+
+impl ManifestReader for FileManifestReader {
+    fn read_manifest(&self) -> std::io::Result<String> {
+        std::fs::read_to_string(&self.path)
+    }
+}
+
+/// Fields a crate needs before it can be published, with suggestions
+const PUBLISH_FIELDS: [(&str, &str); 4] = [
+    (
+        "description",
+        "Add a short `description` so crates.io can summarize the crate.",
+    ),
+    (
+        "license",
+        "Add a `license` SPDX expression (or `license-file`).",
+    ),
+    (
+        "repository",
+        "Add a `repository` URL so users can find the source.",
+    ),
+    (
+        "keywords",
+        "Add up to five `keywords` to improve discoverability on crates.io.",
+    ),
+];
+
+/// Check that Cargo.toml contains the metadata needed for publishing
+pub struct MetadataCheck {
+    reader: Box<dyn ManifestReader>,
+}
+
+impl MetadataCheck {
+    /// Create a new MetadataCheck for the Cargo.toml in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            reader: Box::new(FileManifestReader::new(dir)),
+        }
+    }
+
+    /// Create a new MetadataCheck with a custom manifest reader (for testing)
+    #[cfg(test)]
+    pub fn with_reader(reader: Box<dyn ManifestReader>) -> Self {
+        Self { reader }
+    }
+}
+
+impl Check for MetadataCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let content = match self.reader.read_manifest() {
+            Ok(content) => content,
+            Err(_) => {
+                // Missing manifests are already reported by the files check
+                return vec![Diagnostic::new(
+                    self.name(),
+                    Severity::Info,
+                    "No Cargo.toml found, skipping metadata check.",
+                    self.category(),
+                )];
+            }
+        };
+
+        let manifest: toml::Table = match toml::from_str(&content) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                return vec![Diagnostic::new(
+                    self.name(),
+                    Severity::Warning,
+                    "Cargo.toml could not be parsed.",
+                    self.category(),
+                )
+                .with_details(e.to_string())];
+            }
+        };
+
+        let Some(package) = manifest.get("package").and_then(|p| p.as_table()) else {
+            return vec![Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "Cargo.toml has no [package] section, skipping metadata check.",
+                self.category(),
+            )];
+        };
+
+        PUBLISH_FIELDS
+            .iter()
+            .map(|(field, suggestion)| {
+                let present = package.contains_key(*field)
+                    || (*field == "license" && package.contains_key("license-file"));
+
+                if present {
+                    Diagnostic::new(
+                        self.name(),
+                        Severity::Info,
+                        format!("`{field}` is set."),
+                        self.category(),
+                    )
+                } else {
+                    Diagnostic::new(
+                        self.name(),
+                        Severity::Warning,
+                        format!("`{field}` is missing from [package]."),
+                        self.category(),
+                    )
+                    .with_suggestion(*suggestion)
+                }
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        "Package Metadata"
+    }
+
+    fn description(&self) -> &str {
+        "Check Cargo.toml for metadata required to publish the crate"
+    }
+
+    fn category(&self) -> &str {
+        "metadata"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::{Error, ErrorKind};
+
+    struct MockManifestReader {
+        content: Option<&'static str>,
+    }
+
+    impl ManifestReader for MockManifestReader {
+        fn read_manifest(&self) -> std::io::Result<String> {
+            self.content
+                .map(str::to_string)
+                .ok_or_else(|| Error::new(ErrorKind::NotFound, "no manifest"))
+        }
+    }
+
+    fn check_for(content: Option<&'static str>) -> MetadataCheck {
+        MetadataCheck::with_reader(Box::new(MockManifestReader { content }))
+    }
+
+    #[test]
+    fn test_minimal_manifest_warns() {
+        let check = check_for(Some("[package]\nname = \"demo\"\nversion = \"0.1.0\"\n"));
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 4);
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+        assert!(diagnostics.iter().all(|d| d.suggestion.is_some()));
+    }
+
+    #[test]
+    fn test_complete_manifest_is_info() {
+        let check = check_for(Some(
+            r#"[package]
+name = "demo"
+version = "0.1.0"
+description = "A demo crate"
+license-file = "LICENSE"
+repository = "https://example.com/demo"
+keywords = ["demo"]
+"#,
+        ));
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 4);
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Info));
+    }
+
+    #[test]
+    fn test_missing_manifest_is_skipped() {
+        let diagnostics = check_for(None).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_invalid_manifest_warns() {
+        let diagnostics = check_for(Some("[package\nname =")).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].details.is_some());
+    }
+
+    #[test]
+    fn test_category_method() {
+        let check = MetadataCheck::new(Path::new("."));
+        assert_eq!(check.category(), "metadata");
+    }
+}
@@ -1,14 +1,28 @@
 //! Doctor checks implementations
 
+pub mod ci;
 pub mod dependencies;
+pub mod edition;
 pub mod files;
+pub mod format;
 pub mod lints;
+pub mod metadata;
+pub mod msrv;
 pub mod rust;
+pub mod security;
 pub mod templates;
+pub mod test_presence;
 
 // Re-export all check structs for convenience
+pub use ci::CiCheck;
 pub use dependencies::DependenciesCheck;
+pub use edition::EditionCheck;
 pub use files::FilesCheck;
+pub use format::FormatCheck;
 pub use lints::LintsCheck;
+pub use metadata::MetadataCheck;
+pub use msrv::MsrvCheck;
 pub use rust::RustToolchainCheck;
+pub use security::SecurityCheck;
 pub use templates::TemplatesCheck;
+pub use test_presence::TestsCheck;
@@ -0,0 +1,148 @@
+//! Check that the project declares a minimum supported Rust version
+
+use crate::commands::doctor::checks::metadata::{FileManifestReader, ManifestReader};
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::path::Path;
+
+/// Check for a valid `package.rust-version` in Cargo.toml
+pub struct MsrvCheck {
+    reader: Box<dyn ManifestReader>,
+}
+
+impl MsrvCheck {
+    /// Create a new MsrvCheck for the Cargo.toml in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            reader: Box::new(FileManifestReader::new(dir)),
+        }
+    }
+
+    /// Create a new MsrvCheck with a custom manifest reader (for testing)
+    #[cfg(test)]
+    pub fn with_reader(reader: Box<dyn ManifestReader>) -> Self {
+        Self { reader }
+    }
+}
+
+impl Check for MsrvCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let Some(package) = self
+            .reader
+            .read_manifest()
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+            .and_then(|manifest| manifest.get("package")?.as_table().cloned())
+        else {
+            // Missing or unparsable manifests are reported by other checks
+            return vec![Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "No package manifest found, skipping MSRV check.",
+                self.category(),
+            )];
+        };
+
+        let diagnostic = match package.get("rust-version") {
+            None => Diagnostic::new(
+                self.name(),
+                Severity::Warning,
+                "`rust-version` is not declared.",
+                self.category(),
+            )
+            .with_suggestion(
+                "Declare the minimum supported Rust version, e.g. rust-version = \"1.70\"",
+            ),
+            // The same rule `new --msrv` applies, so generated projects always pass
+            Some(toml::Value::String(version))
+                if quickstart_lib::validate_msrv(version).is_ok() =>
+            {
+                Diagnostic::new(
+                    self.name(),
+                    Severity::Info,
+                    format!("MSRV is declared as {version}."),
+                    self.category(),
+                )
+            }
+            Some(toml::Value::String(version)) => Diagnostic::new(
+                self.name(),
+                Severity::Warning,
+                format!("`rust-version` \"{version}\" is not a valid version."),
+                self.category(),
+            )
+            .with_suggestion("Use a bare version like \"1.70\" or \"1.70.0\" (no operators)"),
+            Some(_) => Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "MSRV is inherited from the workspace.",
+                self.category(),
+            ),
+        };
+
+        vec![diagnostic]
+    }
+
+    fn name(&self) -> &str {
+        "Minimum Rust Version"
+    }
+
+    fn description(&self) -> &str {
+        "Check that Cargo.toml declares a valid rust-version (MSRV)"
+    }
+
+    fn category(&self) -> &str {
+        "msrv"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    struct MockManifestReader {
+        content: &'static str,
+    }
+
+    impl ManifestReader for MockManifestReader {
+        fn read_manifest(&self) -> std::io::Result<String> {
+            Ok(self.content.to_string())
+        }
+    }
+
+    fn run_with(content: &'static str) -> Vec<Diagnostic> {
+        MsrvCheck::with_reader(Box::new(MockManifestReader { content })).run()
+    }
+
+    #[test]
+    fn test_missing_msrv() {
+        let diagnostics = run_with("[package]\nname = \"demo\"\n");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].suggestion.is_some());
+    }
+
+    #[test]
+    fn test_valid_msrv() {
+        let diagnostics = run_with("[package]\nname = \"demo\"\nrust-version = \"1.70.0\"\n");
+
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("1.70.0"));
+    }
+
+    #[test]
+    fn test_malformed_msrv() {
+        let diagnostics = run_with("[package]\nname = \"demo\"\nrust-version = \">=1.70\"\n");
+
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].message.contains("not a valid version"));
+    }
+
+    #[test]
+    fn test_inherited_msrv() {
+        let diagnostics = run_with("[package]\nname = \"demo\"\nrust-version.workspace = true\n");
+
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+}
@@ -0,0 +1,214 @@
+//! Check for known security vulnerabilities in dependencies
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+#[cfg(test)]
+use mockall;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Recommendation shown when cargo-audit is not available
+const CARGO_AUDIT_RECOMMENDATION: &str = "Install cargo-audit: cargo install cargo-audit";
+
+/// Trait for executing cargo-audit, allowing for easier mocking in tests
+#[cfg_attr(test, allow(clippy::disallowed_methods))]
+#[cfg_attr(test, mockall::automock)]
+pub trait CommandExecutor: Send + Sync {
+    fn execute_audit(&self) -> std::io::Result<Output>;
+    fn is_command_available(&self, command: &str) -> bool;
+}
+
+/// Default implementation that calls the actual cargo audit command
+pub struct RealCommandExecutor {
+    /// Project directory the command runs in
+    dir: PathBuf,
+}
+
+impl CommandExecutor for RealCommandExecutor {
+    fn execute_audit(&self) -> std::io::Result<Output> {
+        Command::new("cargo")
+            .current_dir(&self.dir)
+            .args(["audit", "--json"])
+            .output()
+    }
+
+    fn is_command_available(&self, command: &str) -> bool {
+        which::which(command).is_ok()
+    }
+}
+
+/// Check dependencies against the RustSec advisory database
+pub struct SecurityCheck {
+    executor: Box<dyn CommandExecutor>,
+}
+
+impl SecurityCheck {
+    /// Create a new SecurityCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            executor: Box::new(RealCommandExecutor { dir: dir.into() }),
+        }
+    }
+
+    /// Create a new SecurityCheck with a custom command executor (for testing)
+    #[cfg(test)]
+    pub fn with_executor(executor: Box<dyn CommandExecutor>) -> Self {
+        Self { executor }
+    }
+}
+
+/// Extract the vulnerability count from `cargo audit --json` output
+fn vulnerability_count(stdout: &[u8]) -> Option<u64> {
+    let report: serde_json::Value = serde_json::from_slice(stdout).ok()?;
+    report.get("vulnerabilities")?.get("count")?.as_u64()
+}
+
+impl Check for SecurityCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        // First check if cargo-audit is installed
+        if !self.executor.is_command_available("cargo-audit") {
+            return vec![Diagnostic::new(
+                self.name(),
+                Severity::Suggestion,
+                "cargo-audit is not installed",
+                self.category(),
+            )
+            .with_suggestion(CARGO_AUDIT_RECOMMENDATION)];
+        }
+
+        let diagnostic = match self.executor.execute_audit() {
+            // cargo audit exits non-zero when vulnerabilities are found, so parse either way
+            Ok(output) => match vulnerability_count(&output.stdout) {
+                Some(0) => Diagnostic::new(
+                    self.name(),
+                    Severity::Info,
+                    "No known vulnerabilities found",
+                    self.category(),
+                ),
+                Some(count) => Diagnostic::new(
+                    self.name(),
+                    Severity::Error,
+                    format!("{count} known vulnerabilities found in dependencies"),
+                    self.category(),
+                )
+                .with_suggestion("Run 'cargo audit' to see the advisories and affected crates"),
+                None => Diagnostic::new(
+                    self.name(),
+                    Severity::Warning,
+                    "Could not parse cargo audit output",
+                    self.category(),
+                )
+                .with_details(String::from_utf8_lossy(&output.stderr).to_string())
+                .with_suggestion("Run 'cargo audit' manually to inspect the results"),
+            },
+            Err(_) => Diagnostic::new(
+                self.name(),
+                Severity::Suggestion,
+                "Could not run cargo audit",
+                self.category(),
+            )
+            .with_suggestion(CARGO_AUDIT_RECOMMENDATION),
+        };
+
+        vec![diagnostic]
+    }
+
+    fn name(&self) -> &str {
+        "Security Audit"
+    }
+
+    fn description(&self) -> &str {
+        "Check dependencies for known vulnerabilities (cargo-audit)"
+    }
+
+    fn category(&self) -> &str {
+        "security"
+    }
+
+    fn requires_network(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::os::unix::process::ExitStatusExt;
+
+    fn audit_output(code: i32, stdout: &str) -> Output {
+        Output {
+            status: std::process::ExitStatus::from_raw(code),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_audit_not_installed() {
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available()
+            .with(mockall::predicate::eq("cargo-audit"))
+            .return_const(false);
+
+        let diagnostics = SecurityCheck::with_executor(Box::new(mock)).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Suggestion);
+        assert!(diagnostics[0].message.contains("not installed"));
+    }
+
+    #[test]
+    fn test_audit_installed_clean() {
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(true);
+        mock.expect_execute_audit().return_once(|| {
+            Ok(audit_output(
+                0,
+                r#"{"vulnerabilities":{"found":false,"count":0,"list":[]}}"#,
+            ))
+        });
+
+        let diagnostics = SecurityCheck::with_executor(Box::new(mock)).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_audit_vulnerable() {
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(true);
+        mock.expect_execute_audit().return_once(|| {
+            Ok(audit_output(
+                1,
+                r#"{"vulnerabilities":{"found":true,"count":2,"list":[]}}"#,
+            ))
+        });
+
+        let diagnostics = SecurityCheck::with_executor(Box::new(mock)).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0].message.contains("2 known vulnerabilities"));
+        assert!(diagnostics[0].suggestion.is_some());
+    }
+
+    #[test]
+    fn test_audit_unparseable_output() {
+        let mut mock = MockCommandExecutor::new();
+        mock.expect_is_command_available().return_const(true);
+        mock.expect_execute_audit()
+            .return_once(|| Ok(audit_output(1, "not json")));
+
+        let diagnostics = SecurityCheck::with_executor(Box::new(mock)).run();
+
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_category_method() {
+        let check = SecurityCheck::new(Path::new("."));
+        assert_eq!(check.category(), "security");
+    }
+}
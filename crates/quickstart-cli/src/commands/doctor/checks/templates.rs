@@ -2,66 +2,85 @@
 
 use crate::commands::doctor::diagnosis::Check;
 use crate::commands::doctor::types::{Diagnostic, Severity};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Directories every templates tree needs: shared files plus each project type and variant
+const EXPECTED_DIRS: [&str; 5] = [
+    "base",
+    "binary/minimal",
+    "binary/extended",
+    "library/minimal",
+    "library/extended",
+];
 
 /// Check for template consistency across the project
 pub struct TemplatesCheck {
-    #[allow(dead_code)]
-    template_dir: PathBuf,
+    /// Templates directory to probe, or `None` when the bundled templates are used
+    template_dir: Option<PathBuf>,
 }
 
 impl TemplatesCheck {
-    /// Create a new TemplatesCheck that examines the templates directory
+    /// Create a new TemplatesCheck for the templates directory that generation would use
     pub fn new() -> Self {
         Self {
-            template_dir: PathBuf::from("templates"),
+            template_dir: quickstart_lib::find_templates_dir().ok(),
         }
     }
 
-    /// Check that templates have consistent naming
-    fn check_template_naming(&self) -> Diagnostic {
-        // In a real implementation, we would scan template files
-        // For now, just a placeholder
-        Diagnostic::new(
-            self.name(),
-            Severity::Info,
-            "Templates follow consistent naming conventions",
-            self.category(),
-        )
+    /// Create a new TemplatesCheck that examines the given templates directory (for testing)
+    #[cfg(test)]
+    pub fn with_dir(dir: &Path) -> Self {
+        Self {
+            template_dir: Some(dir.into()),
+        }
     }
 
-    /// Check that templates have documentation
-    fn check_template_docs(&self) -> Diagnostic {
-        // In a real implementation, we would check for README files
-        // For now, just a placeholder
-        Diagnostic::new(
-            self.name(),
-            Severity::Info,
-            "All templates have documentation",
-            self.category(),
-        )
-    }
+    /// Check that the tree has the base directory and every type/variant directory
+    fn check_structure(&self, dir: &Path) -> Vec<Diagnostic> {
+        let missing: Vec<Diagnostic> = EXPECTED_DIRS
+            .iter()
+            .filter(|expected| !dir.join(expected).is_dir())
+            .map(|expected| {
+                Diagnostic::new(
+                    self.name(),
+                    Severity::Warning,
+                    format!("Templates directory is missing '{expected}/'"),
+                    self.category(),
+                )
+                .with_suggestion(format!(
+                    "Create '{}' so projects using it can be generated",
+                    dir.join(expected).display()
+                ))
+            })
+            .collect();
+
+        if !missing.is_empty() {
+            return missing;
+        }
 
-    /// Check that templates are valid
-    fn check_template_validity(&self) -> Diagnostic {
-        // In a real implementation, we would validate template syntax
-        // For now, just a placeholder
-        Diagnostic::new(
+        vec![Diagnostic::new(
             self.name(),
             Severity::Info,
-            "All templates have valid syntax",
+            format!(
+                "Templates in '{}' cover every project type and variant",
+                dir.display()
+            ),
             self.category(),
-        )
+        )]
     }
 }
 
 impl Check for TemplatesCheck {
     fn run(&self) -> Vec<Diagnostic> {
-        vec![
-            self.check_template_naming(),
-            self.check_template_docs(),
-            self.check_template_validity(),
-        ]
+        match &self.template_dir {
+            Some(dir) => self.check_structure(dir),
+            None => vec![Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "No templates directory found; the bundled templates are used",
+                self.category(),
+            )],
+        }
     }
 
     fn name(&self) -> &str {
@@ -80,6 +99,16 @@ impl Check for TemplatesCheck {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    /// Create a templates tree containing the given directories
+    fn tree_with(dirs: &[&str]) -> std::io::Result<TempDir> {
+        let temp_dir = TempDir::new()?;
+        for dir in dirs {
+            std::fs::create_dir_all(temp_dir.path().join(dir))?;
+        }
+        Ok(temp_dir)
+    }
 
     #[test]
     fn test_name_method() {
@@ -108,4 +137,52 @@ mod tests {
         let diagnostics = check.run();
         assert!(!diagnostics.is_empty());
     }
+
+    #[test]
+    fn test_complete_tree() -> std::io::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tree_with(&EXPECTED_DIRS)?;
+        let diagnostics = TemplatesCheck::with_dir(temp_dir.path()).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tree_missing_variant() -> std::io::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tree_with(&[
+            "base",
+            "binary/minimal",
+            "binary/extended",
+            "library/extended",
+        ])?;
+        let diagnostics = TemplatesCheck::with_dir(temp_dir.path()).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[0].message.contains("library/minimal"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_without_templates_dir() {
+        let check = TemplatesCheck { template_dir: None };
+        let diagnostics = check.run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert!(diagnostics[0].message.contains("bundled"));
+    }
 }
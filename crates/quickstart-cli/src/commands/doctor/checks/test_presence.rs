@@ -0,0 +1,200 @@
+//! Check that the project has at least some tests
+
+use crate::commands::doctor::diagnosis::Check;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use std::path::{Path, PathBuf};
+
+/// Markers that indicate the presence of unit tests in a source file
+const TEST_MARKERS: [&str; 2] = ["#[test]", "#[cfg(test)]"];
+
+/// Trait for inspecting project sources, allowing for easier mocking in tests
+pub trait SourceProvider: Send + Sync {
+    /// Whether an integration `tests/` directory exists
+    fn has_tests_dir(&self) -> bool;
+    /// Contents of the Rust source files under `src/`
+    fn source_files(&self) -> Vec<String>;
+}
+
+/// Default implementation that reads the project directory from disk
+pub struct FsSourceProvider {
+    dir: PathBuf,
+}
+
+impl FsSourceProvider {
+    /// Create a provider for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Recursively collect the contents of `.rs` files below `dir`
+    fn collect_rs_files(dir: &Path, contents: &mut Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for path in entries.filter_map(|e| e.ok().map(|e| e.path())) {
+            if path.is_dir() {
+                Self::collect_rs_files(&path, contents);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                if let Ok(content) = std::fs::read_to_string(&path) {
+                    contents.push(content);
+                }
+            }
+        }
+    }
+}
+
+impl SourceProvider for FsSourceProvider {
+    fn has_tests_dir(&self) -> bool {
+        self.dir.join("tests").is_dir()
+    }
+
+    fn source_files(&self) -> Vec<String> {
+        let mut contents = Vec::new();
+        Self::collect_rs_files(&self.dir.join("src"), &mut contents);
+        contents
+    }
+}
+
+/// Check for the presence of unit or integration tests
+pub struct TestsCheck {
+    provider: Box<dyn SourceProvider>,
+}
+
+impl TestsCheck {
+    /// Create a new TestsCheck for the project in the given directory
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            provider: Box::new(FsSourceProvider::new(dir)),
+        }
+    }
+
+    /// Create a new TestsCheck with a custom source provider (for testing)
+    #[cfg(test)]
+    pub fn with_provider(provider: Box<dyn SourceProvider>) -> Self {
+        Self { provider }
+    }
+}
+
+impl Check for TestsCheck {
+    fn run(&self) -> Vec<Diagnostic> {
+        let has_unit_tests = self
+            .provider
+            .source_files()
+            .iter()
+            .any(|content| TEST_MARKERS.iter().any(|marker| content.contains(marker)));
+
+        let diagnostic = if has_unit_tests || self.provider.has_tests_dir() {
+            Diagnostic::new(
+                self.name(),
+                Severity::Info,
+                "Tests found in the project",
+                self.category(),
+            )
+        } else {
+            Diagnostic::new(
+                self.name(),
+                Severity::Suggestion,
+                "No tests found in src/ or tests/",
+                self.category(),
+            )
+            .with_suggestion(
+                "Add unit tests in a #[cfg(test)] module or integration tests in tests/",
+            )
+        };
+
+        vec![diagnostic]
+    }
+
+    fn name(&self) -> &str {
+        "Tests"
+    }
+
+    fn description(&self) -> &str {
+        "Check that the project contains unit or integration tests"
+    }
+
+    fn category(&self) -> &str {
+        "tests"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    struct MockSourceProvider {
+        tests_dir: bool,
+        sources: Vec<&'static str>,
+    }
+
+    impl SourceProvider for MockSourceProvider {
+        fn has_tests_dir(&self) -> bool {
+            self.tests_dir
+        }
+
+        fn source_files(&self) -> Vec<String> {
+            self.sources.iter().map(|s| s.to_string()).collect()
+        }
+    }
+
+    fn check_for(tests_dir: bool, sources: Vec<&'static str>) -> TestsCheck {
+        TestsCheck::with_provider(Box::new(MockSourceProvider { tests_dir, sources }))
+    }
+
+    #[test]
+    fn test_project_with_unit_tests() {
+        let diagnostics = check_for(
+            false,
+            vec![
+                "fn main() {}",
+                "#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}",
+            ],
+        )
+        .run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_project_with_tests_dir() {
+        let diagnostics = check_for(true, vec!["fn main() {}"]).run();
+
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_project_without_tests() {
+        let diagnostics = check_for(false, vec!["fn main() {}"]).run();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Suggestion);
+        assert!(diagnostics[0].suggestion.is_some());
+    }
+
+    #[test]
+    fn test_fs_provider_scans_nested_sources() -> std::io::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir_all(dir.path().join("src/nested"))?;
+        std::fs::write(dir.path().join("src/main.rs"), "fn main() {}")?;
+        std::fs::write(dir.path().join("src/nested/mod.rs"), "#[test]\nfn t() {}")?;
+        std::fs::write(dir.path().join("src/notes.txt"), "#[test]")?;
+
+        let provider = FsSourceProvider::new(dir.path());
+        assert_eq!(provider.source_files().len(), 2);
+        assert!(!provider.has_tests_dir());
+        assert_eq!(
+            TestsCheck::new(dir.path()).run()[0].severity,
+            Severity::Info
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+//! User configuration for the doctor command
+
+use super::types::{HealthScoreThresholds, HEALTH_THRESHOLDS};
+use crate::errors::ErrorKind;
+use color_eyre::{eyre::Context, Result};
+use directories::ProjectDirs;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// File name of the doctor settings inside the user config directory
+const CONFIG_FILE: &str = "doctor.toml";
+
+/// Settings read from `doctor.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DoctorConfig {
+    /// Lowest score of each health rating
+    pub health: Option<HealthMinimums>,
+}
+
+/// Lowest score of each health rating, e.g. `excellent = 95`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HealthMinimums {
+    pub excellent: u8,
+    pub good: u8,
+    pub moderate: u8,
+    pub needs_attention: u8,
+}
+
+impl HealthMinimums {
+    fn thresholds(self) -> Result<HealthScoreThresholds> {
+        HealthScoreThresholds::from_minimums(
+            self.excellent,
+            self.good,
+            self.moderate,
+            self.needs_attention,
+        )
+        .map_err(|e| ErrorKind::Config.error(e))
+    }
+}
+
+/// Location of `doctor.toml` in the user config directory
+pub fn default_path() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "cargo-quickstart").map(|dirs| dirs.config_dir().join(CONFIG_FILE))
+}
+
+/// Load the doctor settings; a missing file means "use the defaults"
+///
+/// Unlike the wizard defaults, an invalid file is an error so a typo cannot silently
+/// loosen the health thresholds.
+pub fn load(path: &Path) -> Result<DoctorConfig> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(DoctorConfig::default()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+
+    toml::from_str(&content).map_err(|e| {
+        ErrorKind::Config.error(format!("Invalid doctor config {}: {e}", path.display()))
+    })
+}
+
+/// Health thresholds from `--health-thresholds`, else the config file, else the defaults
+pub fn health_thresholds(flag: &[u8], config_path: Option<&Path>) -> Result<HealthScoreThresholds> {
+    if let [excellent, good, moderate, needs_attention] = *flag {
+        return HealthMinimums {
+            excellent,
+            good,
+            moderate,
+            needs_attention,
+        }
+        .thresholds();
+    }
+    if !flag.is_empty() {
+        return Err(ErrorKind::Config.error(
+            "--health-thresholds takes four scores: EXCELLENT,GOOD,MODERATE,NEEDS_ATTENTION",
+        ));
+    }
+
+    let config = match config_path {
+        Some(path) => load(path)?,
+        None => DoctorConfig::default(),
+    };
+    match config.health {
+        Some(minimums) => minimums.thresholds(),
+        None => Ok(HEALTH_THRESHOLDS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::doctor::types::HealthRating;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_defaults_without_flag_or_config() -> Result<()> {
+        assert_eq!(health_thresholds(&[], None)?, HEALTH_THRESHOLDS);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_file_thresholds() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(CONFIG_FILE);
+        assert_eq!(health_thresholds(&[], Some(&path))?, HEALTH_THRESHOLDS);
+
+        std::fs::write(
+            &path,
+            "[health]\nexcellent = 98\ngood = 90\nmoderate = 75\nneeds_attention = 60\n",
+        )?;
+        let thresholds = health_thresholds(&[], Some(&path))?;
+        assert_eq!(thresholds.rating(92), HealthRating::Good);
+        assert_eq!(thresholds.rating(55), HealthRating::Critical);
+
+        // The flag wins over the config file
+        let thresholds = health_thresholds(&[90, 70, 50, 30], Some(&path))?;
+        assert_eq!(thresholds, HEALTH_THRESHOLDS);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_config_file() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(CONFIG_FILE);
+
+        std::fs::write(&path, "[health]\nexcelent = 98\n")?;
+        assert!(health_thresholds(&[], Some(&path)).is_err());
+
+        std::fs::write(
+            &path,
+            "[health]\nexcellent = 50\ngood = 90\nmoderate = 75\nneeds_attention = 60\n",
+        )?;
+        assert!(health_thresholds(&[], Some(&path)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flag_needs_four_scores() {
+        assert!(health_thresholds(&[90, 70], None).is_err());
+    }
+}
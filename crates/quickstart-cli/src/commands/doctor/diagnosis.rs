@@ -3,18 +3,17 @@
 //! This module provides the core trait for running diagnostics on Rust projects.
 
 use crate::commands::doctor::reporting::{CheckResult, DiagnosticReport};
-use crate::commands::doctor::types::Diagnostic;
+use crate::commands::doctor::types::{Diagnostic, Severity};
+use color_eyre::Result;
+use std::time::Duration;
 
-/// Weight applied to error diagnostics when calculating health score
-#[allow(dead_code)]
-pub const ERROR_WEIGHT: u8 = 20;
-
-/// Weight applied to warning diagnostics when calculating health score
-#[allow(dead_code)]
-pub const WARNING_WEIGHT: u8 = 5;
+/// Checks running longer than this get a slow-check suggestion unless overridden
+pub const DEFAULT_SLOW_CHECK_THRESHOLD: Duration = Duration::from_secs(5);
 
 /// A trait for implementing different types of checks
-pub trait Check {
+///
+/// Checks must be `Send + Sync` so that they can run concurrently.
+pub trait Check: Send + Sync {
     /// Run the check and return a list of diagnostics
     fn run(&self) -> Vec<Diagnostic>;
 
@@ -26,35 +25,105 @@ pub trait Check {
 
     /// Category this check belongs to
     fn category(&self) -> &str;
+
+    /// Whether the check needs network access (skipped with `--offline`)
+    fn requires_network(&self) -> bool {
+        false
+    }
+
+    /// Attempt to automatically remediate the issues this check reports
+    ///
+    /// Returns `Ok(true)` if anything was changed. Checks without fixes keep the no-op default.
+    fn fix(&self) -> Result<bool> {
+        Ok(false)
+    }
 }
 
-/// Run a collection of checks and generate a diagnostic report
-pub fn run_checks(checks: Vec<Box<dyn Check>>) -> DiagnosticReport {
-    let mut report = DiagnosticReport::new();
+/// Run a collection of checks concurrently and generate a diagnostic report
+///
+/// Results are sorted by check name so the report is stable regardless of
+/// which check finishes first. Checks taking longer than `slow_threshold` get an
+/// extra suggestion noting their duration.
+pub fn run_checks(checks: Vec<Box<dyn Check>>, slow_threshold: Duration) -> DiagnosticReport {
+    let mut results: Vec<CheckResult> = std::thread::scope(|scope| {
+        let handles: Vec<_> = checks
+            .iter()
+            .map(|check| scope.spawn(move || run_check(check.as_ref())))
+            .collect();
 
-    for check in checks {
-        let start = std::time::Instant::now();
-        let diagnostics = check.run();
-        let duration = start.elapsed();
+        handles
+            .into_iter()
+            .zip(&checks)
+            .map(|(handle, check)| {
+                handle.join().unwrap_or_else(|_| CheckResult {
+                    name: check.name().to_string(),
+                    description: check.description().to_string(),
+                    category: check.category().to_string(),
+                    duration: Duration::ZERO,
+                    diagnostics: vec![Diagnostic::new(
+                        check.name(),
+                        Severity::Error,
+                        "Check panicked while running",
+                        check.category(),
+                    )],
+                })
+            })
+            .collect()
+    });
 
-        let result = CheckResult {
-            name: check.name().to_string(),
-            description: check.description().to_string(),
-            category: check.category().to_string(),
-            duration,
-            diagnostics,
-        };
+    results.sort_by(|a, b| a.name.cmp(&b.name));
 
+    let mut report = DiagnosticReport::new();
+    for mut result in results {
+        flag_slow_check(&mut result, slow_threshold);
         report.add_result(result);
     }
 
     report
 }
 
+/// Run a single check, timing its execution
+fn run_check(check: &dyn Check) -> CheckResult {
+    let start = std::time::Instant::now();
+    let diagnostics = check.run();
+    let duration = start.elapsed();
+
+    CheckResult {
+        name: check.name().to_string(),
+        description: check.description().to_string(),
+        category: check.category().to_string(),
+        duration,
+        diagnostics,
+    }
+}
+
+/// Add a suggestion to `result` if it ran longer than `threshold`
+fn flag_slow_check(result: &mut CheckResult, threshold: Duration) {
+    if result.duration <= threshold {
+        return;
+    }
+
+    result.diagnostics.push(
+        Diagnostic::new(
+            &result.name,
+            Severity::Suggestion,
+            format!(
+                "Check took {:.1?}, over the {threshold:?} budget",
+                result.duration
+            ),
+            &result.category,
+        )
+        .with_suggestion(format!(
+            "Skip it with --skip {} while iterating, or raise --slow-threshold",
+            result.category
+        )),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commands::doctor::types::{Diagnostic, Severity};
+    use crate::commands::doctor::reporting::{ERROR_PENALTY, WARNING_PENALTY};
     use pretty_assertions::assert_eq;
 
     struct MockCheck {
@@ -98,6 +167,30 @@ mod tests {
         }
     }
 
+    /// A check that takes `delay` to report nothing
+    struct SlowCheck {
+        delay: Duration,
+    }
+
+    impl Check for SlowCheck {
+        fn run(&self) -> Vec<Diagnostic> {
+            std::thread::sleep(self.delay);
+            Vec::new()
+        }
+
+        fn name(&self) -> &str {
+            "slow-check"
+        }
+
+        fn description(&self) -> &str {
+            "Takes a while"
+        }
+
+        fn category(&self) -> &str {
+            "dependencies"
+        }
+    }
+
     #[test]
     fn test_diagnostic_creation() {
         let diagnostic = Diagnostic::new(
@@ -151,7 +244,7 @@ mod tests {
         ];
 
         // Run checks and get report
-        let report = run_checks(checks);
+        let report = run_checks(checks, DEFAULT_SLOW_CHECK_THRESHOLD);
 
         // Verify report
         assert_eq!(report.results.len(), 2);
@@ -164,6 +257,87 @@ mod tests {
         assert_eq!(stats.total_diagnostics, 2);
         assert_eq!(stats.errors, 1);
         assert_eq!(stats.warnings, 1);
-        assert_eq!(stats.health_score, 100 - (ERROR_WEIGHT + WARNING_WEIGHT));
+        assert_eq!(
+            stats.health_score,
+            100 - (ERROR_PENALTY + WARNING_PENALTY) as u8
+        );
+    }
+
+    #[test]
+    fn test_report_to_json() -> color_eyre::Result<()> {
+        let checks: Vec<Box<dyn Check>> = vec![Box::new(MockCheck::new(
+            "warning-check",
+            "Checks for warnings",
+            "warnings",
+            vec![
+                Diagnostic::new("warning-check", Severity::Warning, "A warning", "warnings")
+                    .with_suggestion("Fix it"),
+            ],
+        ))];
+
+        let report = run_checks(checks, DEFAULT_SLOW_CHECK_THRESHOLD);
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+
+        assert_eq!(json["statistics"]["health_score"], 100 - WARNING_PENALTY);
+        assert_eq!(json["statistics"]["warnings"], 1);
+        assert!(json["total_duration_ms"].is_number());
+
+        let result = &json["results"][0];
+        assert_eq!(result["name"], "warning-check");
+        assert_eq!(result["category"], "warnings");
+        assert!(result["duration_ms"].is_number());
+        assert_eq!(result["diagnostics"][0]["severity"], "warning");
+        assert_eq!(result["diagnostics"][0]["suggestion"], "Fix it");
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_checks_returns_every_check_in_stable_order() {
+        let names = ["zeta", "alpha", "mu", "beta", "omega"];
+        let checks: Vec<Box<dyn Check>> = names
+            .iter()
+            .map(|name| {
+                Box::new(MockCheck::new(*name, "desc", "cat", Vec::new())) as Box<dyn Check>
+            })
+            .collect();
+
+        let report = run_checks(checks, DEFAULT_SLOW_CHECK_THRESHOLD);
+        let result_names: Vec<&str> = report.results.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(result_names, vec!["alpha", "beta", "mu", "omega", "zeta"]);
+    }
+
+    #[test]
+    fn test_slow_check_gets_suggestion() {
+        let checks: Vec<Box<dyn Check>> = vec![
+            Box::new(SlowCheck {
+                delay: Duration::from_millis(50),
+            }),
+            Box::new(MockCheck::new("fast-check", "desc", "cat", Vec::new())),
+        ];
+
+        let report = run_checks(checks, Duration::from_millis(20));
+
+        let slow = &report.results[1];
+        assert_eq!(slow.name, "slow-check");
+        assert_eq!(slow.diagnostics.len(), 1);
+        assert_eq!(slow.diagnostics[0].severity, Severity::Suggestion);
+        assert!(slow.diagnostics[0].message.contains("over the 20ms budget"));
+        assert_eq!(
+            slow.diagnostics[0].suggestion.as_deref(),
+            Some("Skip it with --skip dependencies while iterating, or raise --slow-threshold")
+        );
+        assert!(report.results[0].diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_check_within_threshold_is_not_flagged() {
+        let checks: Vec<Box<dyn Check>> = vec![Box::new(SlowCheck {
+            delay: Duration::from_millis(1),
+        })];
+
+        let report = run_checks(checks, DEFAULT_SLOW_CHECK_THRESHOLD);
+
+        assert!(report.results[0].diagnostics.is_empty());
     }
 }
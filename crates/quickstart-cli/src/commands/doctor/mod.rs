@@ -1,26 +1,105 @@
 //! Doctor command: diagnose project issues and misconfigurations
 
-use crate::ui::output;
+use crate::{args::DoctorArgs, errors::ErrorKind, ui::output};
 use color_eyre::Result;
-use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
 
 // Define the module structure
 pub mod checks;
+pub mod config;
 pub mod diagnosis;
 pub mod reporting;
 pub mod types;
 
 // Re-export diagnostic types for use within individual check modules
 pub use diagnosis::Check;
-pub use types::{Severity, HEALTH_THRESHOLDS};
+pub use types::{HealthRating, HealthScoreThresholds, Severity};
+
+use types::Diagnostic;
 
 /// Execute the doctor command
-pub fn execute() -> Result<()> {
-    output::header("cargo-quickstart Doctor");
+pub fn execute(args: DoctorArgs, offline: bool) -> Result<()> {
+    let thresholds =
+        config::health_thresholds(&args.health_thresholds, config::default_path().as_deref())?;
+
+    let path = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    if !path.is_dir() {
+        return Err(ErrorKind::FileSystem.error(format!(
+            "Project directory '{}' does not exist",
+            path.display()
+        )));
+    }
 
     // Create and run checks
-    let checks = collect_checks();
-    let report = diagnosis::run_checks(checks);
+    let options = CheckOptions {
+        path,
+        offline,
+        use_cache: !args.no_cache,
+        tool_timeout: args.tool_timeout.map_or(
+            checks::dependencies::DEFAULT_TOOL_TIMEOUT,
+            Duration::from_secs,
+        ),
+        sarif: args.sarif.clone(),
+    };
+    let checks = filter_checks(collect_checks(&options), &args.only, &args.skip);
+    if checks.is_empty() {
+        return Err(ErrorKind::Config.error("No doctor checks match the selected categories"));
+    }
+
+    if args.fix {
+        apply_fixes(&checks, args.json)?;
+    }
+    let slow_threshold = args
+        .slow_threshold
+        .map_or(diagnosis::DEFAULT_SLOW_CHECK_THRESHOLD, Duration::from_secs);
+    let report = diagnosis::run_checks(checks, slow_threshold);
+
+    if args.json {
+        println!("{}", report.to_json()?);
+    } else {
+        display_report(&report, &thresholds);
+    }
+
+    check_outcome(&report.statistics(), args.strict)
+}
+
+/// Turn the report statistics into a failure when the project is unhealthy
+///
+/// Errors always fail; warnings only fail in strict mode.
+fn check_outcome(stats: &reporting::DiagnosticStatistics, strict: bool) -> Result<()> {
+    if stats.errors > 0 {
+        return Err(ErrorKind::Other.error(format!("Doctor found {} error(s)", stats.errors)));
+    }
+
+    if strict && stats.warnings > 0 {
+        return Err(ErrorKind::Other.error(format!(
+            "Doctor found {} warning(s) (treated as errors with --strict)",
+            stats.warnings
+        )));
+    }
+
+    Ok(())
+}
+
+/// Run the automatic fixes of every check, reporting which ones changed something
+fn apply_fixes(checks: &[Box<dyn Check>], quiet: bool) -> Result<()> {
+    if !quiet {
+        output::section("Applying fixes");
+    }
+
+    for check in checks {
+        if check.fix()? && !quiet {
+            output::success(&format!("Fixed issues found by: {}", check.name()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the decorated, human-readable report
+fn display_report(report: &reporting::DiagnosticReport, thresholds: &HealthScoreThresholds) {
+    output::header("cargo-quickstart Doctor");
 
     // Display diagnostics
     for result in &report.results {
@@ -65,20 +144,13 @@ pub fn execute() -> Result<()> {
 
     // Health assessment
     output::section("Project Health Assessment");
-    match stats.health_score {
-        score if HEALTH_THRESHOLDS.excellent.contains(&score) => {
-            output::success(&format!("Excellent ({}%)", stats.health_score))
-        }
-        score if HEALTH_THRESHOLDS.good.contains(&score) => {
-            output::success(&format!("Good ({}%)", stats.health_score))
-        }
-        score if HEALTH_THRESHOLDS.moderate.contains(&score) => {
-            output::warning(&format!("Moderate ({}%)", stats.health_score))
-        }
-        score if HEALTH_THRESHOLDS.needs_attention.contains(&score) => {
-            output::warning(&format!("Needs attention ({}%)", stats.health_score))
-        }
-        _ => output::error(&format!("Critical ({}%)", stats.health_score)),
+    let score = stats.health_score;
+    match thresholds.rating(score) {
+        HealthRating::Excellent => output::success(&format!("Excellent ({score}%)")),
+        HealthRating::Good => output::success(&format!("Good ({score}%)")),
+        HealthRating::Moderate => output::warning(&format!("Moderate ({score}%)")),
+        HealthRating::NeedsAttention => output::warning(&format!("Needs attention ({score}%)")),
+        HealthRating::Critical => output::error(&format!("Critical ({score}%)")),
     }
 
     // Provide recommendations if there are issues
@@ -108,23 +180,121 @@ pub fn execute() -> Result<()> {
             }
         }
     }
+}
 
-    Ok(())
+/// Settings that change how the checks are built
+#[derive(Debug, Clone)]
+struct CheckOptions {
+    /// Project directory the checks examine
+    path: PathBuf,
+    /// Stub out checks that need the network
+    offline: bool,
+    /// Reuse dependency tool results while `Cargo.lock` is unchanged
+    use_cache: bool,
+    /// How long external dependency tools may run before they are killed
+    tool_timeout: Duration,
+    /// Where the lints check writes its clippy findings as SARIF
+    sarif: Option<PathBuf>,
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("."),
+            offline: false,
+            use_cache: true,
+            tool_timeout: checks::dependencies::DEFAULT_TOOL_TIMEOUT,
+            sarif: None,
+        }
+    }
 }
 
-/// Collect all diagnostic checks to run
-fn collect_checks() -> Vec<Box<dyn Check>> {
+/// Collect all diagnostic checks to run, stubbing out network checks when offline
+fn collect_checks(options: &CheckOptions) -> Vec<Box<dyn Check>> {
     use checks::*;
 
+    let path = options.path.as_path();
+    let mut dependencies = DependenciesCheck::new(path).with_timeout(options.tool_timeout);
+    if options.use_cache {
+        dependencies = dependencies.with_cache(dependencies::DependencyCache::in_project(path));
+    }
+
+    let mut lints = LintsCheck::new(path);
+    if let Some(path) = &options.sarif {
+        lints = lints.with_sarif(path);
+    }
+
     let checks: Vec<Box<dyn Check>> = vec![
-        Box::new(FilesCheck::new(Path::new("."))),
+        Box::new(FilesCheck::new(path)),
+        Box::new(MetadataCheck::new(path)),
+        Box::new(MsrvCheck::new(path)),
+        Box::new(EditionCheck::new(path)),
+        Box::new(TestsCheck::new(path)),
+        Box::new(CiCheck::new(path)),
         Box::new(RustToolchainCheck::new()),
-        Box::new(LintsCheck::new()),
-        Box::new(DependenciesCheck::new()),
+        Box::new(lints),
+        Box::new(FormatCheck::new(path)),
+        Box::new(dependencies),
+        Box::new(SecurityCheck::new(path)),
         Box::new(TemplatesCheck::new()),
     ];
 
+    if !options.offline {
+        return checks;
+    }
+
+    checks
+        .into_iter()
+        .map(|check| -> Box<dyn Check> {
+            if check.requires_network() {
+                Box::new(SkippedOffline(check))
+            } else {
+                check
+            }
+        })
+        .collect()
+}
+
+/// Stand-in for a network check that reports it was skipped instead of running
+struct SkippedOffline(Box<dyn Check>);
+
+impl Check for SkippedOffline {
+    fn run(&self) -> Vec<Diagnostic> {
+        vec![Diagnostic::new(
+            self.name(),
+            Severity::Info,
+            "Skipped: requires network access (--offline).",
+            self.category(),
+        )]
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn description(&self) -> &str {
+        self.0.description()
+    }
+
+    fn category(&self) -> &str {
+        self.0.category()
+    }
+}
+
+/// Keep only the checks whose category is in `only` (if given) and not in `skip`
+fn filter_checks(
+    checks: Vec<Box<dyn Check>>,
+    only: &[String],
+    skip: &[String],
+) -> Vec<Box<dyn Check>> {
+    let matches =
+        |list: &[String], category: &str| list.iter().any(|c| c.eq_ignore_ascii_case(category));
+
     checks
+        .into_iter()
+        .filter(|check| only.is_empty() || matches(only, check.category()))
+        .filter(|check| !matches(skip, check.category()))
+        .collect()
 }
 
 #[cfg(test)]
@@ -133,13 +303,138 @@ mod tests {
 
     #[test]
     fn test_collect_checks_returns_checks() {
-        let checks = collect_checks();
+        let checks = collect_checks(&CheckOptions::default());
         assert!(
             !checks.is_empty(),
             "collect_checks should return at least one check"
         );
     }
 
+    #[test]
+    fn test_offline_skips_network_checks() {
+        let checks = collect_checks(&CheckOptions::default());
+        let network: Vec<&str> = checks
+            .iter()
+            .filter(|c| c.requires_network())
+            .map(|c| c.category())
+            .collect();
+        assert_eq!(network, vec!["dependencies", "security"]);
+
+        let checks = filter_checks(
+            collect_checks(&CheckOptions {
+                offline: true,
+                ..CheckOptions::default()
+            }),
+            &["dependencies".to_string(), "security".to_string()],
+            &[],
+        );
+        let report = diagnosis::run_checks(checks, diagnosis::DEFAULT_SLOW_CHECK_THRESHOLD);
+
+        assert_eq!(report.results.len(), 2);
+        for result in &report.results {
+            assert_eq!(result.diagnostics.len(), 1);
+            assert_eq!(result.diagnostics[0].severity, Severity::Info);
+            assert!(result.diagnostics[0].message.contains("--offline"));
+        }
+    }
+
+    #[test]
+    fn test_checks_examine_project_path() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::TempDir::new()?;
+        std::fs::write(temp_dir.path().join("README.md"), "# Elsewhere\n")?;
+
+        let checks = filter_checks(
+            collect_checks(&CheckOptions {
+                path: temp_dir.path().to_path_buf(),
+                ..CheckOptions::default()
+            }),
+            &["files".to_string()],
+            &[],
+        );
+        let report = diagnosis::run_checks(checks, diagnosis::DEFAULT_SLOW_CHECK_THRESHOLD);
+        let messages: Vec<&str> = report.results[0]
+            .diagnostics
+            .iter()
+            .map(|d| d.message.as_str())
+            .collect();
+
+        assert!(messages.contains(&"README.md found."));
+        assert!(messages.contains(&"Cargo.toml is missing."));
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_rejects_missing_path() {
+        let args = DoctorArgs {
+            path: Some(PathBuf::from("/definitely/not/a/project")),
+            ..DoctorArgs::default()
+        };
+        let err = execute(args, false).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_filter_checks_only() {
+        let checks = filter_checks(
+            collect_checks(&CheckOptions::default()),
+            &["rust".to_string()],
+            &[],
+        );
+        let names: Vec<&str> = checks.iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["Rust Toolchain"]);
+    }
+
+    #[test]
+    fn test_filter_checks_skip() {
+        let all = collect_checks(&CheckOptions::default()).len();
+        let checks = filter_checks(
+            collect_checks(&CheckOptions::default()),
+            &[],
+            &["dependencies".to_string(), "lints".to_string()],
+        );
+        assert_eq!(checks.len(), all - 2);
+        assert!(checks
+            .iter()
+            .all(|c| c.category() != "dependencies" && c.category() != "lints"));
+    }
+
+    #[test]
+    fn test_filter_checks_unknown_category() {
+        let checks = filter_checks(
+            collect_checks(&CheckOptions::default()),
+            &["nope".to_string()],
+            &[],
+        );
+        assert!(checks.is_empty());
+    }
+
+    fn stats(errors: usize, warnings: usize) -> reporting::DiagnosticStatistics {
+        reporting::DiagnosticStatistics {
+            total_checks: 1,
+            total_diagnostics: errors + warnings,
+            errors,
+            warnings,
+            suggestions: 0,
+            info: 0,
+            health_score: 100,
+        }
+    }
+
+    #[test]
+    fn test_check_outcome() {
+        assert!(check_outcome(&stats(0, 0), false).is_ok());
+        assert!(check_outcome(&stats(0, 2), false).is_ok());
+        assert!(check_outcome(&stats(0, 2), true).is_err());
+        assert!(check_outcome(&stats(1, 0), false).is_err());
+        assert!(check_outcome(&stats(0, 0), true).is_ok());
+    }
+
     #[test]
     fn test_execute_runs_without_error() {
         // Skip this test under Miri since it makes file system calls
@@ -148,7 +443,7 @@ mod tests {
             return;
         }
 
-        let result = execute();
+        let result = execute(DoctorArgs::default(), false);
         assert!(result.is_ok(), "execute() should complete without error");
     }
 }
@@ -1,17 +1,22 @@
 //! Diagnostic report generation and statistics
 
 use crate::commands::doctor::types::{Diagnostic, Severity};
+use color_eyre::{eyre::WrapErr, Result};
+use serde::{Serialize, Serializer};
 use std::collections::HashMap;
 use std::time::Duration;
 
-/// Constants for health score calculation
-pub const ERROR_PENALTY: usize = 20;
+/// Health score points deducted for each error
+pub const ERROR_PENALTY: usize = 15;
+/// Health score points deducted for each warning
 pub const WARNING_PENALTY: usize = 5;
+/// Health score points deducted for each suggestion
 pub const SUGGESTION_PENALTY: usize = 1;
+/// Largest total deduction, so the score never drops below 0
 pub const MAX_PENALTY: usize = 100;
 
 /// The result of running a diagnostic check
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CheckResult {
     /// Name of the check
     pub name: String,
@@ -21,6 +26,7 @@ pub struct CheckResult {
     #[allow(dead_code)]
     pub category: String,
     /// Duration of the check execution
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
     pub duration: Duration,
     /// Diagnostics produced by the check
     pub diagnostics: Vec<Diagnostic>,
@@ -75,6 +81,9 @@ impl DiagnosticReport {
     }
 
     /// Calculate health score (0-100) based on diagnostics
+    ///
+    /// Starts at 100 and deducts a penalty per finding weighted by its severity, so a
+    /// single error costs as much as fifteen suggestions. Info diagnostics are free.
     pub fn health_score(&self) -> u8 {
         let error_count = self.diagnostics_by_severity(Severity::Error).len();
         let warning_count = self.diagnostics_by_severity(Severity::Warning).len();
@@ -105,10 +114,34 @@ impl DiagnosticReport {
             health_score: self.health_score(),
         }
     }
+
+    /// Serialize the report, including statistics, as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        let json = JsonReport {
+            results: &self.results,
+            statistics: self.statistics(),
+            total_duration: self.total_duration,
+        };
+        serde_json::to_string_pretty(&json).wrap_err("Failed to serialize doctor report")
+    }
+}
+
+/// Machine-readable view of a diagnostic report
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    results: &'a [CheckResult],
+    statistics: DiagnosticStatistics,
+    #[serde(rename = "total_duration_ms", serialize_with = "serialize_millis")]
+    total_duration: Duration,
+}
+
+/// Serialize a duration as fractional milliseconds
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
 }
 
 /// Statistics about a diagnostic report
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct DiagnosticStatistics {
     /// Total number of checks run
     pub total_checks: usize,
@@ -126,3 +159,58 @@ pub struct DiagnosticStatistics {
     /// Overall health score (0-100)
     pub health_score: u8,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Report with a single check producing diagnostics of the given severities
+    fn report_with(severities: &[Severity]) -> DiagnosticReport {
+        let mut report = DiagnosticReport::new();
+        report.add_result(CheckResult {
+            name: "Mock".to_string(),
+            description: "Mock check".to_string(),
+            category: "mock".to_string(),
+            duration: Duration::from_millis(1),
+            diagnostics: severities
+                .iter()
+                .map(|severity| Diagnostic::new("Mock", *severity, "finding", "mock"))
+                .collect(),
+        });
+        report
+    }
+
+    #[test]
+    fn test_health_score_without_checks() {
+        assert_eq!(DiagnosticReport::new().health_score(), 100);
+    }
+
+    #[test]
+    fn test_health_score_weights_by_severity() {
+        let suggestions = report_with(&[Severity::Suggestion; 5]);
+        let errors = report_with(&[Severity::Error; 5]);
+        let info = report_with(&[Severity::Info; 5]);
+
+        assert_eq!(
+            suggestions.health_score(),
+            100 - 5 * SUGGESTION_PENALTY as u8
+        );
+        assert_eq!(errors.health_score(), 100 - 5 * ERROR_PENALTY as u8);
+        assert_eq!(info.health_score(), 100);
+        assert!(errors.health_score() < suggestions.health_score());
+
+        let mixed = report_with(&[Severity::Error, Severity::Warning, Severity::Suggestion]);
+        assert_eq!(
+            mixed.health_score(),
+            100 - (ERROR_PENALTY + WARNING_PENALTY + SUGGESTION_PENALTY) as u8
+        );
+    }
+
+    #[test]
+    fn test_health_score_clamped_at_zero() {
+        let report = report_with(&[Severity::Error; 10]);
+        assert_eq!(report.health_score(), 0);
+        assert_eq!(report.statistics().health_score, 0);
+    }
+}
@@ -1,10 +1,11 @@
 //! Core diagnostic types for the doctor command
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::RangeInclusive;
 
 /// Health score thresholds for project assessment
-#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HealthScoreThresholds {
     pub excellent: RangeInclusive<u8>,
     pub good: RangeInclusive<u8>,
@@ -13,7 +14,6 @@ pub struct HealthScoreThresholds {
 }
 
 /// Default health score thresholds
-#[allow(dead_code)]
 pub const HEALTH_THRESHOLDS: HealthScoreThresholds = HealthScoreThresholds {
     excellent: 90..=100,
     good: 70..=89,
@@ -21,8 +21,55 @@ pub const HEALTH_THRESHOLDS: HealthScoreThresholds = HealthScoreThresholds {
     needs_attention: 30..=49,
 };
 
+/// Assessment of a project's health score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthRating {
+    Excellent,
+    Good,
+    Moderate,
+    NeedsAttention,
+    Critical,
+}
+
+impl HealthScoreThresholds {
+    /// Build thresholds from the lowest score of each rating, e.g. `90, 70, 50, 30`
+    ///
+    /// Each rating runs up to just below the next one; lower scores are critical.
+    pub fn from_minimums(
+        excellent: u8,
+        good: u8,
+        moderate: u8,
+        needs_attention: u8,
+    ) -> Result<Self, String> {
+        if excellent > 100 || excellent <= good || good <= moderate || moderate <= needs_attention {
+            return Err(format!(
+                "Health thresholds must be decreasing and at most 100, got {excellent}, {good}, {moderate}, {needs_attention}"
+            ));
+        }
+
+        Ok(Self {
+            excellent: excellent..=100,
+            good: good..=excellent - 1,
+            moderate: moderate..=good - 1,
+            needs_attention: needs_attention..=moderate - 1,
+        })
+    }
+
+    /// Classify a health score
+    pub fn rating(&self, score: u8) -> HealthRating {
+        match score {
+            score if self.excellent.contains(&score) => HealthRating::Excellent,
+            score if self.good.contains(&score) => HealthRating::Good,
+            score if self.moderate.contains(&score) => HealthRating::Moderate,
+            score if self.needs_attention.contains(&score) => HealthRating::NeedsAttention,
+            _ => HealthRating::Critical,
+        }
+    }
+}
+
 /// Represents the severity level of a diagnostic
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     /// Informational message, no action needed
     Info,
@@ -46,7 +93,7 @@ impl fmt::Display for Severity {
 }
 
 /// A diagnostic finding from a check
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Diagnostic {
     /// Name of the check that produced this diagnostic
     #[allow(dead_code)]
@@ -116,6 +163,37 @@ mod tests {
         assert_eq!(*HEALTH_THRESHOLDS.needs_attention.end(), 49);
     }
 
+    #[test]
+    fn test_health_thresholds_from_minimums() {
+        assert_eq!(
+            HealthScoreThresholds::from_minimums(90, 70, 50, 30),
+            Ok(HEALTH_THRESHOLDS)
+        );
+        assert!(HealthScoreThresholds::from_minimums(101, 70, 50, 30).is_err());
+        assert!(HealthScoreThresholds::from_minimums(90, 90, 50, 30).is_err());
+        assert!(HealthScoreThresholds::from_minimums(90, 70, 30, 50).is_err());
+    }
+
+    #[test]
+    fn test_health_rating() {
+        assert_eq!(HEALTH_THRESHOLDS.rating(100), HealthRating::Excellent);
+        assert_eq!(HEALTH_THRESHOLDS.rating(89), HealthRating::Good);
+        assert_eq!(HEALTH_THRESHOLDS.rating(50), HealthRating::Moderate);
+        assert_eq!(HEALTH_THRESHOLDS.rating(30), HealthRating::NeedsAttention);
+        assert_eq!(HEALTH_THRESHOLDS.rating(29), HealthRating::Critical);
+    }
+
+    #[test]
+    fn test_stricter_thresholds_reclassify_score() -> Result<(), String> {
+        let strict = HealthScoreThresholds::from_minimums(98, 90, 75, 60)?;
+
+        assert_eq!(HEALTH_THRESHOLDS.rating(92), HealthRating::Excellent);
+        assert_eq!(strict.rating(92), HealthRating::Good);
+        assert_eq!(HEALTH_THRESHOLDS.rating(55), HealthRating::Moderate);
+        assert_eq!(strict.rating(55), HealthRating::Critical);
+        Ok(())
+    }
+
     #[test]
     fn test_severity_display() {
         assert_eq!(Severity::Info.to_string(), "Info");
@@ -0,0 +1,118 @@
+//! Implementation of the 'list-features' command
+
+use color_eyre::Result;
+use quickstart_lib::template::{TemplateLoader, FEATURES};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use super::templates::collect_template_entries;
+use crate::ui::output;
+
+/// An optional feature and the templates it enables
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureEntry {
+    /// Identifier accepted wherever features are selected (e.g. `ci`)
+    pub name: String,
+    /// Label shown in the interactive wizard
+    pub label: String,
+    /// Whether the feature is enabled when none are selected explicitly
+    pub default: bool,
+    /// Destination files or directories the feature owns
+    pub paths: Vec<String>,
+    /// Templates (relative to the templates directory) the feature enables
+    pub templates: Vec<String>,
+}
+
+/// Describe every optional feature, with the templates each one enables in `loader`
+pub fn collect_feature_entries(loader: &TemplateLoader) -> Result<Vec<FeatureEntry>> {
+    let templates = collect_template_entries(loader)?;
+
+    Ok(FEATURES
+        .iter()
+        .map(|feature| {
+            let enabled: BTreeSet<String> = templates
+                .iter()
+                .filter(|entry| {
+                    let destination = Path::new(&entry.destination);
+                    feature
+                        .paths
+                        .iter()
+                        .any(|path| destination.starts_with(path))
+                })
+                .map(|entry| entry.path.clone())
+                .collect();
+
+            FeatureEntry {
+                name: feature.name.to_string(),
+                label: feature.label.to_string(),
+                default: feature.default,
+                paths: feature.paths.iter().map(|path| path.to_string()).collect(),
+                templates: enabled.into_iter().collect(),
+            }
+        })
+        .collect())
+}
+
+/// Display features with their defaults and templates
+pub fn display_features(entries: &[FeatureEntry]) {
+    output::header("Optional features");
+    for entry in entries {
+        let default = if entry.default { "on" } else { "off" };
+        output::section(&format!(
+            "{} ({}, default: {default})",
+            entry.name, entry.label
+        ));
+        if entry.templates.is_empty() {
+            output::list_item(&format!("no templates for {}", entry.paths.join(", ")));
+        }
+        for template in &entry.templates {
+            output::list_item(template);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::interactive::feature_options;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_features_match_wizard_options() -> Result<()> {
+        let entries = collect_feature_entries(&TemplateLoader::bundled())?;
+        let labels: Vec<&str> = entries.iter().map(|entry| entry.label.as_str()).collect();
+        assert_eq!(labels, feature_options());
+        Ok(())
+    }
+
+    #[test]
+    fn test_feature_templates() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        let workflows = dir.path().join("base/.github/workflows");
+        std::fs::create_dir_all(&workflows)?;
+        std::fs::create_dir_all(dir.path().join("binary/minimal"))?;
+        std::fs::write(workflows.join("ci.yml.hbs"), "name: ci\n")?;
+        std::fs::write(dir.path().join("base/README.md.hbs"), "# x\n")?;
+
+        let entries = collect_feature_entries(&TemplateLoader::new(dir.path()))?;
+        let templates = |name: &str| -> Vec<String> {
+            entries
+                .iter()
+                .find(|entry| entry.name == name)
+                .map(|entry| entry.templates.clone())
+                .unwrap_or_default()
+        };
+
+        assert_eq!(templates("ci"), vec!["base/.github/workflows/ci.yml.hbs"]);
+        assert_eq!(templates("readme"), vec!["base/README.md.hbs"]);
+        assert!(templates("benchmarks").is_empty());
+        Ok(())
+    }
+}
@@ -2,9 +2,12 @@
 
 use color_eyre::Result;
 use quickstart_lib::ProjectType;
+use std::path::Path;
 
 use crate::{
     args::InitArgs,
+    commands::manifest,
+    errors::ErrorKind,
     ui::{output, prompts},
 };
 
@@ -13,35 +16,48 @@ pub fn get_project_name(args: &InitArgs) -> Result<String> {
     if let Some(name) = &args.name {
         Ok(name.clone())
     } else {
-        // Try to get the directory name from the path
-        let dir_name = args
-            .path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .unwrap_or("project");
-
-        if !args.yes {
-            prompts::input_with_default("Project name", dir_name)
+        // Prefer an existing manifest's package name, then the directory name
+        let default_name = match existing_manifest_name(&args.path)? {
+            Some(name) => name,
+            None => args
+                .path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("project")
+                .to_string(),
+        };
+
+        let name = if !args.yes {
+            prompts::input_with_default("Project name", &default_name)?
         } else {
-            Ok(dir_name.to_string())
-        }
+            default_name
+        };
+
+        quickstart_lib::validate_crate_name(&name).map_err(|e| ErrorKind::Config.error(e))?;
+        Ok(name)
     }
 }
 
+/// Read the package name from an existing `Cargo.toml` in `path`, if any
+pub fn existing_manifest_name(path: &Path) -> Result<Option<String>> {
+    Ok(manifest::read_package(path)?.map(|package| package.name))
+}
+
 /// Determine project type based on args or user input
 pub fn determine_project_type(args: &InitArgs) -> Result<ProjectType> {
-    Ok(if args.lib {
-        ProjectType::Library
-    } else if args.bin || args.yes {
-        ProjectType::Binary // Default to binary in non-interactive/bin mode
+    Ok(if let Some(project_type) = args.kind.selected() {
+        project_type
+    } else if args.yes {
+        ProjectType::Binary // Default to binary in non-interactive mode
     } else {
         // Prompt for project type
-        let options = &["Binary application", "Library crate"];
+        let options = &["Binary application", "Library crate", "Binary and library"];
         let selection = prompts::select("Project type", options)?;
 
         match selection {
             0 => ProjectType::Binary,
             1 => ProjectType::Library,
+            2 => ProjectType::Both,
             _ => {
                 // Fallback to Binary if selection is somehow out of range
                 output::warning("Unexpected project type selection, defaulting to Binary");
@@ -59,5 +75,5 @@ pub fn display_project_info(project_name: &str, project_type: &ProjectType, args
     output::key_value("Edition", &args.edition);
     output::key_value("License", &args.license);
     output::key_value("Path", &args.path.display().to_string());
-    output::key_value("Git", &args.git.to_string());
+    output::key_value("Git", &args.use_git().to_string());
 }
@@ -1,19 +1,23 @@
 //! Main execution logic for the init command
 
-use color_eyre::{eyre::Report, Result};
-use quickstart_lib::{generate_project, ProjectConfig};
+use color_eyre::Result;
+use quickstart_lib::{generate_project_with_progress, ProjectConfig};
 
 use crate::{
     args::InitArgs,
-    errors::CommandErrorExt,
-    ui::{output, progress::with_spinner, prompts},
+    commands::cargo_add,
+    errors::{CommandErrorExt, ErrorKind},
+    ui::{output, progress::with_progress_bar, prompts},
 };
 
 use super::config::{determine_project_type, display_project_info, get_project_name};
 
-/// Execute the 'init' command
-pub fn execute(args: InitArgs) -> Result<()> {
+/// Execute the 'init' command, running `cargo add` for seeded dependencies when asked
+/// and not `offline`
+pub fn execute(args: InitArgs, offline: bool) -> Result<()> {
     output::header("Generating project");
+    quickstart_lib::validate_keywords(&args.options.keywords)
+        .map_err(|e| ErrorKind::Config.error(e))?;
 
     // Get project name and type
     let project_name = get_project_name(&args)?;
@@ -23,28 +27,64 @@ pub fn execute(args: InitArgs) -> Result<()> {
     display_project_info(&project_name, &project_type, &args);
 
     // Prompt for confirmation if not in yes mode
-    if !args.yes {
+    if !args.yes && !args.dry_run && !args.diff {
         let confirmed = prompts::confirm("Initialize project with these settings?", true)?;
         if !confirmed {
-            return Err(Report::msg("Project initialization cancelled by user"));
+            return Err(ErrorKind::Other.error("Project initialization cancelled by user"));
         }
     }
 
+    let git = args.use_git();
+    let use_cargo_add = cargo_add::should_run(args.options.cargo_add, offline, false);
+
     // Build configuration
-    let config = ProjectConfig {
+    let mut config = ProjectConfig {
         name: project_name,
         project_type,
         edition: args.edition,
         license: args.license,
         path: args.path,
-        git: args.git,
+        git,
         yes: args.yes,
+        dry_run: args.dry_run,
+        description: args.options.description,
+        author_name: args.options.author_name,
+        author_email: args.options.author_email,
+        features: args.options.features,
+        toolchain: args.options.toolchain,
+        msrv: args.options.msrv,
+        keywords: args.options.keywords,
+        categories: args.options.categories,
+        dependencies: args.options.dependencies,
+        dev_dependencies: args.options.dev_dependencies,
+        backup: args.backup,
+        ..Default::default()
+    };
+
+    // Leave seeded dependencies out of the templates when cargo add resolves them
+    let (dependencies, dev_dependencies) = if use_cargo_add {
+        (
+            std::mem::take(&mut config.dependencies),
+            std::mem::take(&mut config.dev_dependencies),
+        )
+    } else {
+        Default::default()
     };
 
+    if config.dry_run {
+        return crate::commands::preview_project(&config);
+    }
+    if args.diff {
+        return crate::commands::diff::preview_diff(&config);
+    }
+
     // Generate project with a progress spinner
-    with_spinner(
+    let report = with_progress_bar(
         "Initializing project...",
         "Project initialized successfully!",
-        || generate_project(config).command_context("init"),
-    )
+        |progress| generate_project_with_progress(config, progress).command_context("init"),
+    )?;
+    super::add_dependencies(&report.path, &dependencies, &dev_dependencies)?;
+    crate::commands::print_generated(&report);
+    Ok(())
 }
@@ -12,6 +12,7 @@ use std::fmt::Display;
 /// This enables dependency injection for testing
 pub trait InquireApi {
     /// Prompts the user for text input
+    #[allow(dead_code)]
     fn text(&self, message: &str, help: Option<&str>) -> Result<String>;
 
     /// Prompts the user for text input with validation
@@ -23,6 +24,16 @@ pub trait InquireApi {
         error_message: &str,
     ) -> Result<String>;
 
+    /// Prompts the user for validated text input, pre-filled with `default`
+    fn text_with_default(
+        &self,
+        message: &str,
+        help: Option<&str>,
+        default: Option<&str>,
+        validator: impl Fn(&str) -> Result<bool, String> + Clone + 'static,
+        error_message: &str,
+    ) -> Result<String>;
+
     /// Prompts the user to select one option from a list
     fn select<T: ToString + Display + Clone>(
         &self,
@@ -31,6 +42,15 @@ pub trait InquireApi {
         help: Option<&str>,
     ) -> Result<usize>;
 
+    /// Prompts the user to select one option, starting the cursor on `default`
+    fn select_with_default<T: ToString + Display + Clone>(
+        &self,
+        message: &str,
+        options: &[T],
+        default: Option<usize>,
+        help: Option<&str>,
+    ) -> Result<usize>;
+
     /// Prompts the user for a yes/no confirmation
     fn confirm(&self, message: &str, default: bool) -> Result<bool>;
 
@@ -66,16 +86,32 @@ impl InquireApi for RealInquire {
         help: Option<&str>,
         validator: impl Fn(&str) -> Result<bool, String> + Clone + 'static,
         error_message: &str,
+    ) -> Result<String> {
+        self.text_with_default(message, help, None, validator, error_message)
+    }
+
+    fn text_with_default(
+        &self,
+        message: &str,
+        help: Option<&str>,
+        default: Option<&str>,
+        validator: impl Fn(&str) -> Result<bool, String> + Clone + 'static,
+        error_message: &str,
     ) -> Result<String> {
         let error_msg = error_message.to_string();
         let validation_fn = move |input: &str| match validator(input) {
             Ok(true) => Ok(Validation::Valid),
-            Ok(false) | Err(_) => Ok(Validation::Invalid(error_msg.clone().into())),
+            Ok(false) => Ok(Validation::Invalid(error_msg.clone().into())),
+            Err(reason) => Ok(Validation::Invalid(reason.into())),
         };
 
         let mut prompt = Text::new(message);
         prompt = prompt.with_validator(validation_fn);
 
+        if let Some(d) = default {
+            prompt = prompt.with_initial_value(d);
+        }
+
         if let Some(h) = help {
             prompt = prompt.with_help_message(h);
         }
@@ -90,9 +126,23 @@ impl InquireApi for RealInquire {
         message: &str,
         options: &[T],
         help: Option<&str>,
+    ) -> Result<usize> {
+        self.select_with_default(message, options, None, help)
+    }
+
+    fn select_with_default<T: ToString + Display + Clone>(
+        &self,
+        message: &str,
+        options: &[T],
+        default: Option<usize>,
+        help: Option<&str>,
     ) -> Result<usize> {
         let mut prompt = Select::new(message, options.to_vec());
 
+        if let Some(index) = default.filter(|&i| i < options.len()) {
+            prompt = prompt.with_starting_cursor(index);
+        }
+
         if let Some(h) = help {
             prompt = prompt.with_help_message(h);
         }
@@ -157,6 +207,11 @@ pub struct TestInquire {
     select_responses: RefCell<std::collections::VecDeque<Result<usize>>>,
     confirm_responses: RefCell<std::collections::VecDeque<Result<bool>>>,
     multiselect_responses: RefCell<std::collections::VecDeque<Result<Vec<usize>>>>,
+    select_defaults: RefCell<Vec<Option<usize>>>,
+    confirm_defaults: RefCell<Vec<bool>>,
+    multiselect_defaults: RefCell<Vec<Vec<usize>>>,
+    text_messages: RefCell<Vec<String>>,
+    text_defaults: RefCell<Vec<Option<String>>>,
 }
 
 #[cfg(test)]
@@ -168,9 +223,39 @@ impl TestInquire {
             select_responses: RefCell::new(std::collections::VecDeque::new()),
             confirm_responses: RefCell::new(std::collections::VecDeque::new()),
             multiselect_responses: RefCell::new(std::collections::VecDeque::new()),
+            select_defaults: RefCell::new(Vec::new()),
+            confirm_defaults: RefCell::new(Vec::new()),
+            multiselect_defaults: RefCell::new(Vec::new()),
+            text_messages: RefCell::new(Vec::new()),
+            text_defaults: RefCell::new(Vec::new()),
         }
     }
 
+    /// Defaults passed to each `select_with_default` call so far, in order
+    pub fn select_defaults(&self) -> Vec<Option<usize>> {
+        self.select_defaults.borrow().clone()
+    }
+
+    /// Defaults passed to each `confirm` call so far, in order
+    pub fn confirm_defaults(&self) -> Vec<bool> {
+        self.confirm_defaults.borrow().clone()
+    }
+
+    /// Defaults passed to each `multiselect` call so far, in order
+    pub fn multiselect_defaults(&self) -> Vec<Vec<usize>> {
+        self.multiselect_defaults.borrow().clone()
+    }
+
+    /// Messages of each text prompt shown so far, in order
+    pub fn text_messages(&self) -> Vec<String> {
+        self.text_messages.borrow().clone()
+    }
+
+    /// Defaults passed to each `text_with_default` call so far, in order
+    pub fn text_defaults(&self) -> Vec<Option<String>> {
+        self.text_defaults.borrow().clone()
+    }
+
     /// Queue a text response to be returned on next text call
     pub fn queue_text_response(&self, response: Result<String>) {
         self.text_responses.borrow_mut().push_back(response);
@@ -243,17 +328,33 @@ impl TestInquire {
 
 #[cfg(test)]
 impl InquireApi for TestInquire {
-    fn text(&self, _message: &str, _help: Option<&str>) -> Result<String> {
+    fn text(&self, message: &str, _help: Option<&str>) -> Result<String> {
+        self.text_messages.borrow_mut().push(message.to_string());
         self.next_text_response()
     }
 
     fn text_with_validation(
         &self,
-        _message: &str,
+        message: &str,
+        help: Option<&str>,
+        validator: impl Fn(&str) -> Result<bool, String> + Clone + 'static,
+        error_message: &str,
+    ) -> Result<String> {
+        self.text_with_default(message, help, None, validator, error_message)
+    }
+
+    fn text_with_default(
+        &self,
+        message: &str,
         _help: Option<&str>,
+        default: Option<&str>,
         validator: impl Fn(&str) -> Result<bool, String> + Clone + 'static,
         error_message: &str,
     ) -> Result<String> {
+        self.text_messages.borrow_mut().push(message.to_string());
+        self.text_defaults
+            .borrow_mut()
+            .push(default.map(str::to_string));
         let response = self.next_text_response()?;
 
         // Apply validation
@@ -277,7 +378,19 @@ impl InquireApi for TestInquire {
         self.next_select_response()
     }
 
-    fn confirm(&self, _message: &str, _default: bool) -> Result<bool> {
+    fn select_with_default<T: ToString + Display + Clone>(
+        &self,
+        _message: &str,
+        _options: &[T],
+        default: Option<usize>,
+        _help: Option<&str>,
+    ) -> Result<usize> {
+        self.select_defaults.borrow_mut().push(default);
+        self.next_select_response()
+    }
+
+    fn confirm(&self, _message: &str, default: bool) -> Result<bool> {
+        self.confirm_defaults.borrow_mut().push(default);
         self.next_confirm_response()
     }
 
@@ -285,9 +398,12 @@ impl InquireApi for TestInquire {
         &self,
         _message: &str,
         _options: &[T],
-        _defaults: &[usize],
+        defaults: &[usize],
         _help: Option<&str>,
     ) -> Result<Vec<usize>> {
+        self.multiselect_defaults
+            .borrow_mut()
+            .push(defaults.to_vec());
         self.next_multiselect_response()
     }
 }
@@ -1,24 +1,43 @@
 //! Enhanced interactive wizard for project setup
 
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::Report;
 use color_eyre::Result;
-use quickstart_lib::{ProjectConfig, ProjectType};
+use quickstart_lib::{
+    template::{find_feature, license_files, FEATURES},
+    ExistingFileAction, ProjectConfig, ProjectType,
+};
 
 use crate::commands::init::inquire_api::{InquireApi, RealInquire};
+use crate::commands::init::saved_defaults::{self, SavedDefaults};
+use crate::errors::ErrorKind;
 
-/// Run the interactive setup wizard
-pub fn run_wizard(path: PathBuf) -> Result<ProjectConfig> {
+/// Run the interactive setup wizard, optionally seeded from the saved defaults
+pub fn run_wizard(base: ProjectConfig, use_saved_defaults: bool) -> Result<ProjectConfig> {
     let inquire_api = RealInquire;
-    run_wizard_with_api(&inquire_api, path)
+    let defaults_path = use_saved_defaults
+        .then(saved_defaults::default_path)
+        .flatten();
+    run_wizard_with_api(&inquire_api, base, defaults_path.as_deref())
 }
 
 /// Run the interactive setup wizard with dependency injection
-/// This function allows for testing with a mock implementation
-pub fn run_wizard_with_api<T: InquireApi>(inquire_api: &T, path: PathBuf) -> Result<ProjectConfig> {
+/// This function allows for testing with a mock implementation.
+/// Answers are loaded from and saved to `defaults_path` when given.
+/// Settings the wizard does not ask about, such as the path, come from `base`, whose
+/// features and author preselect those answers; for a dry run nothing is created and
+/// existing files are not asked about.
+pub fn run_wizard_with_api<T: InquireApi>(
+    inquire_api: &T,
+    base: ProjectConfig,
+    defaults_path: Option<&Path>,
+) -> Result<ProjectConfig> {
+    let path = base.path.clone();
+
     // Check if directory exists and create it if needed
-    if !path.exists() {
+    if !path.exists() && !base.dry_run {
         let dir_str = path.display().to_string();
         let create_dir = inquire_api.confirm(
             &format!("Directory {dir_str} does not exist. Create it?"),
@@ -26,45 +45,85 @@ pub fn run_wizard_with_api<T: InquireApi>(inquire_api: &T, path: PathBuf) -> Res
         )?;
 
         if !create_dir {
-            return Err(Report::msg("Project creation cancelled"));
+            return Err(ErrorKind::Other.error("Project creation cancelled"));
         }
 
         std::fs::create_dir_all(&path)
             .map_err(|e| Report::msg(format!("Failed to create directory: {e}")))?;
     }
 
-    // Step 1: Project name
-    let name = get_project_name_with_api(inquire_api)?;
+    // Walk the steps, letting "Back" in any select revisit the previous one
+    let mut answers = defaults_path
+        .map(|p| WizardAnswers::from(saved_defaults::load(p)))
+        .unwrap_or_default();
+    answers.seed_from(&base);
+    let mut index = 0;
+    while let Some(step) = WizardStep::ALL.get(index) {
+        if step.ask(inquire_api, &mut answers)? {
+            index += 1;
+        } else {
+            index = index.saturating_sub(1);
+        }
+    }
 
-    // Step 2: Project type (binary/library)
-    let project_type = get_project_type_with_api(inquire_api)?;
+    let features = answers.features.unwrap_or_default();
 
-    // Step 3: Rust edition
-    let edition = get_rust_edition_with_api(inquire_api)?;
+    // Create project config
+    let mut config = ProjectConfig {
+        name: answers.name.unwrap_or_default(),
+        project_type: answers.project_type.unwrap_or(ProjectType::Binary),
+        edition: answers.edition.unwrap_or_default(),
+        license: answers.license.unwrap_or_default(),
+        git: answers.git.unwrap_or(true),
+        author_name: answers.author_name,
+        author_email: answers.author_email,
+        features: Some(
+            features
+                .iter()
+                .filter_map(|label| find_feature(label))
+                .map(|feature| feature.name.to_string())
+                .collect(),
+        ),
+        license_files: answers.license_files.unwrap_or(true),
+        ..base
+    };
 
-    // Step 4: License
-    let license = get_license_with_api(inquire_api)?;
+    // Show summary and confirmation
+    println!("\nProject Summary:\n{}", render_summary(&config, &features));
 
-    // Step 5: Git initialization
-    let git = get_git_init_with_api(inquire_api)?;
+    let confirm = inquire_api.confirm("Create project with these settings?", true)?;
 
-    // Step 6: Optional features
-    let features = get_optional_features_with_api(inquire_api)?;
+    if !confirm {
+        return Err(ErrorKind::Other.error("Project creation cancelled by user"));
+    }
 
-    // Create project config
-    let config = ProjectConfig {
-        name,
-        project_type,
-        edition,
-        license,
-        git,
-        path: path.clone(),
-        yes: false,
-    };
+    if !config.dry_run {
+        config.existing_files = get_existing_file_actions_with_api(inquire_api, &config)?;
+    }
 
-    // Show summary and confirmation
+    if let Some(defaults_path) = defaults_path {
+        let saved = SavedDefaults {
+            edition: Some(config.edition.clone()),
+            license: Some(config.license.clone()),
+            git: Some(config.git),
+            features,
+        };
+        saved_defaults::save(defaults_path, &saved)?;
+    }
+
+    Ok(config)
+}
+
+/// Render the assembled configuration for the final confirmation step
+fn render_summary(config: &ProjectConfig, features: &[String]) -> String {
     let mut summary = String::new();
     summary.push_str(&format!("Project name: {}\n", config.name));
+    if let Some(author) = &config.author_name {
+        summary.push_str(&format!("Author: {author}\n"));
+    }
+    if let Some(email) = &config.author_email {
+        summary.push_str(&format!("Email: {email}\n"));
+    }
     summary.push_str(&format!("Type: {}\n", config.project_type));
     summary.push_str(&format!("Edition: {}\n", config.edition));
     summary.push_str(&format!("License: {}\n", config.license));
@@ -73,49 +132,362 @@ pub fn run_wizard_with_api<T: InquireApi>(inquire_api: &T, path: PathBuf) -> Res
         if config.git { "Yes" } else { "No" }
     ));
 
+    if let Some(description) = &config.description {
+        summary.push_str(&format!("Description: {description}\n"));
+    }
+    if let Some(toolchain) = &config.toolchain {
+        summary.push_str(&format!("Toolchain: {toolchain}\n"));
+    }
+    if let Some(msrv) = &config.msrv {
+        summary.push_str(&format!("MSRV: {msrv}\n"));
+    }
+    for (label, values) in [
+        ("Keywords", &config.keywords),
+        ("Categories", &config.categories),
+    ] {
+        if !values.is_empty() {
+            summary.push_str(&format!("{label}: {}\n", values.join(", ")));
+        }
+    }
+    for (label, dependencies) in [
+        ("Dependencies", &config.dependencies),
+        ("Dev-dependencies", &config.dev_dependencies),
+    ] {
+        if !dependencies.is_empty() {
+            let specs: Vec<String> = dependencies.iter().map(ToString::to_string).collect();
+            summary.push_str(&format!("{label}: {}\n", specs.join(", ")));
+        }
+    }
+
     if !features.is_empty() {
         summary.push_str("Additional features:\n");
-        for feature in &features {
+        for feature in features {
             summary.push_str(&format!("  - {feature}\n"));
         }
     }
 
-    println!("\nProject Summary:\n{summary}");
+    summary
+}
+
+/// Sentinel option appended to wizard selects to return to the previous step
+const BACK_OPTION: &str = "← Back";
+
+/// Project type choices, in the order they are offered
+const PROJECT_TYPE_OPTIONS: [(&str, ProjectType); 3] = [
+    ("Binary (application)", ProjectType::Binary),
+    ("Library", ProjectType::Library),
+    ("Binary and library", ProjectType::Both),
+];
+
+/// Choices for a file the project would overwrite, in the order they are offered
+const EXISTING_FILE_OPTIONS: [(&str, ExistingFileAction); 3] = [
+    ("Skip (keep the existing file)", ExistingFileAction::Skip),
+    ("Overwrite", ExistingFileAction::Overwrite),
+    (
+        "Back up to .bak, then overwrite",
+        ExistingFileAction::Backup,
+    ),
+];
+
+/// Final license choice that prompts for an arbitrary SPDX expression
+const CUSTOM_LICENSE: &str = "Custom";
+
+/// License choices, in the order they are offered
+fn license_options() -> Vec<&'static str> {
+    quickstart_lib::LICENSES
+        .into_iter()
+        .chain([CUSTOM_LICENSE])
+        .collect()
+}
 
-    let confirm = inquire_api.confirm("Create project with these settings?", true)?;
+/// Answers collected so far, reused as defaults when a step is revisited
+#[derive(Debug, Default)]
+struct WizardAnswers {
+    name: Option<String>,
+    author_name: Option<String>,
+    author_email: Option<String>,
+    project_type: Option<ProjectType>,
+    edition: Option<String>,
+    license: Option<String>,
+    license_files: Option<bool>,
+    git: Option<bool>,
+    features: Option<Vec<String>>,
+}
 
-    if !confirm {
-        return Err(Report::msg("Project creation cancelled by user"));
+impl From<SavedDefaults> for WizardAnswers {
+    fn from(saved: SavedDefaults) -> Self {
+        Self {
+            edition: saved.edition,
+            license: saved.license,
+            git: saved.git,
+            features: Some(saved.features),
+            ..Self::default()
+        }
     }
+}
 
-    Ok(config)
+impl WizardAnswers {
+    /// Prefer the author and features given on the command line over saved answers
+    fn seed_from(&mut self, base: &ProjectConfig) {
+        if base.author_name.is_some() {
+            self.author_name.clone_from(&base.author_name);
+        }
+        if base.author_email.is_some() {
+            self.author_email.clone_from(&base.author_email);
+        }
+        if let Some(features) = &base.features {
+            self.features = Some(
+                features
+                    .iter()
+                    .filter_map(|name| find_feature(name))
+                    .map(|feature| feature.label.to_string())
+                    .collect(),
+            );
+        }
+    }
+}
+
+/// The wizard's steps, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardStep {
+    Name,
+    Author,
+    ProjectType,
+    Edition,
+    License,
+    Git,
+    Features,
+}
+
+impl WizardStep {
+    const ALL: [WizardStep; 7] = [
+        WizardStep::Name,
+        WizardStep::Author,
+        WizardStep::ProjectType,
+        WizardStep::Edition,
+        WizardStep::License,
+        WizardStep::Git,
+        WizardStep::Features,
+    ];
+
+    /// Ask this step's question, returning `false` if the user chose to go back
+    fn ask<T: InquireApi>(self, inquire_api: &T, answers: &mut WizardAnswers) -> Result<bool> {
+        match self {
+            WizardStep::Name => {
+                answers.name = Some(get_project_name_with_api(
+                    inquire_api,
+                    answers.name.as_deref(),
+                )?);
+            }
+            WizardStep::Author => {
+                answers.author_name =
+                    get_author_name_with_api(inquire_api, answers.author_name.as_deref())?;
+                answers.author_email =
+                    get_author_email_with_api(inquire_api, answers.author_email.as_deref())?;
+            }
+            WizardStep::ProjectType => {
+                let labels = PROJECT_TYPE_OPTIONS.map(|(label, _)| label);
+                let default = answers
+                    .project_type
+                    .and_then(|t| PROJECT_TYPE_OPTIONS.iter().position(|(_, o)| *o == t));
+                let Some(idx) =
+                    select_or_back(inquire_api, "Project type:", &labels, default, None)?
+                else {
+                    return Ok(false);
+                };
+                answers.project_type = Some(PROJECT_TYPE_OPTIONS[idx].1);
+            }
+            WizardStep::Edition => {
+                let options = quickstart_lib::RUST_EDITIONS;
+                let default = answers
+                    .edition
+                    .as_deref()
+                    .and_then(|e| options.iter().position(|o| *o == e));
+                let Some(idx) = select_or_back(
+                    inquire_api,
+                    "Rust edition:",
+                    &options,
+                    default,
+                    Some("The Rust edition to use for your project"),
+                )?
+                else {
+                    return Ok(false);
+                };
+                answers.edition = Some(options[idx].to_string());
+            }
+            WizardStep::License => {
+                let options = license_options();
+                let default = answers.license.as_deref().map(|l| {
+                    options
+                        .iter()
+                        .position(|o| *o == l)
+                        .unwrap_or(options.len() - 1)
+                });
+                let Some(idx) = select_or_back(
+                    inquire_api,
+                    "License:",
+                    &options,
+                    default,
+                    Some("The license to use for your project"),
+                )?
+                else {
+                    return Ok(false);
+                };
+                let license = license_from_selection(inquire_api, idx)?;
+                answers.license_files = Some(confirm_license_files(
+                    inquire_api,
+                    &license,
+                    answers.license_files.unwrap_or(true),
+                )?);
+                answers.license = Some(license);
+            }
+            WizardStep::Git => {
+                let default = answers.git.unwrap_or(true);
+                answers.git = Some(inquire_api.confirm("Initialize Git repository?", default)?);
+            }
+            WizardStep::Features => {
+                answers.features = Some(select_features(inquire_api, answers.features.as_deref())?);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Select from `options` plus a trailing "Back" entry; `None` means the user went back
+fn select_or_back<T: InquireApi>(
+    inquire_api: &T,
+    message: &str,
+    options: &[&str],
+    default: Option<usize>,
+    help: Option<&str>,
+) -> Result<Option<usize>> {
+    let mut choices = options.to_vec();
+    choices.push(BACK_OPTION);
+
+    let idx = inquire_api.select_with_default(message, &choices, default, help)?;
+    Ok((idx < options.len()).then_some(idx))
+}
+
+/// Turn a license selection into an identifier, prompting for custom ones
+fn license_from_selection<T: InquireApi>(inquire_api: &T, idx: usize) -> Result<String> {
+    let selection = license_options()[idx];
+
+    if selection == CUSTOM_LICENSE {
+        let validator = |input: &str| quickstart_lib::validate_license(input.trim()).map(|()| true);
+
+        inquire_api.text_with_validation(
+            "Enter custom license identifier:",
+            Some("Enter a valid SPDX license identifier"),
+            validator,
+            "License must be a valid SPDX expression",
+        )
+    } else {
+        Ok(selection.to_string())
+    }
+}
+
+/// Ask whether to write the license texts for `license`, if it has any
+fn confirm_license_files<T: InquireApi>(
+    inquire_api: &T,
+    license: &str,
+    default: bool,
+) -> Result<bool> {
+    let destinations: Vec<&str> = license_files(license)
+        .iter()
+        .map(|file| file.destination)
+        .collect();
+
+    let message = match destinations.as_slice() {
+        [] => return Ok(default),
+        [single] => format!("Write the license text to {single}?"),
+        [first, second] => format!("Write both {first} and {second}?"),
+        all => format!("Write the license texts to {}?", all.join(", ")),
+    };
+    inquire_api.confirm(&message, default)
 }
 
 /// Gets the project name from the user
 #[allow(dead_code)]
 pub fn get_project_name() -> Result<String> {
     let inquire_api = RealInquire;
-    get_project_name_with_api(&inquire_api)
+    get_project_name_with_api(&inquire_api, None)
 }
 
-/// Gets the project name from the user with dependency injection
-pub fn get_project_name_with_api<T: InquireApi>(inquire_api: &T) -> Result<String> {
-    let validator = |input: &str| -> Result<bool, String> {
-        if !input.trim().is_empty() && !input.trim().starts_with(|c: char| c.is_ascii_digit()) {
-            Ok(true)
-        } else {
-            Ok(false)
-        }
-    };
+/// Gets the project name from the user with dependency injection, pre-filling `previous`
+pub fn get_project_name_with_api<T: InquireApi>(
+    inquire_api: &T,
+    previous: Option<&str>,
+) -> Result<String> {
+    let validator = |input: &str| quickstart_lib::validate_crate_name(input.trim()).map(|()| true);
 
-    inquire_api.text_with_validation(
+    inquire_api.text_with_default(
         "Project name:",
         Some("The name of your Rust project (valid crate name)"),
+        previous,
         validator,
-        "Project name cannot be empty or start with a digit",
+        "Project name must be a valid crate name",
     )
 }
 
+/// Gets the optional author name with dependency injection, pre-filling `previous`;
+/// empty input means "none"
+pub fn get_author_name_with_api<T: InquireApi>(
+    inquire_api: &T,
+    previous: Option<&str>,
+) -> Result<Option<String>> {
+    let name = inquire_api.text_with_default(
+        "Author name:",
+        Some("Leave empty to use the default author"),
+        previous,
+        |_: &str| Ok(true),
+        "",
+    )?;
+
+    Ok(Some(name.trim().to_string()).filter(|n| !n.is_empty()))
+}
+
+/// Gets the optional author email with dependency injection, pre-filling `previous`;
+/// empty input means "none"
+pub fn get_author_email_with_api<T: InquireApi>(
+    inquire_api: &T,
+    previous: Option<&str>,
+) -> Result<Option<String>> {
+    let validator = |input: &str| validate_email(input.trim()).map(|()| true);
+
+    let email = inquire_api.text_with_default(
+        "Author email:",
+        Some("Leave empty to use the default email"),
+        previous,
+        validator,
+        "Author email must look like name@example.com",
+    )?;
+
+    Ok(Some(email.trim().to_string()).filter(|e| !e.is_empty()))
+}
+
+/// Loosely check that `email` looks like an address: one `@` and a dotted domain
+///
+/// Empty input is accepted since the email is optional.
+fn validate_email(email: &str) -> Result<(), String> {
+    if email.is_empty() {
+        return Ok(());
+    }
+
+    let invalid = || format!("`{email}` is not a valid email address");
+    let (local, domain) = email.split_once('@').ok_or_else(invalid)?;
+
+    let domain_ok = domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains('@');
+    if local.is_empty() || !domain_ok || email.contains(char::is_whitespace) {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
+
 /// Gets the project type from the user
 #[allow(dead_code)]
 pub fn get_project_type() -> Result<ProjectType> {
@@ -125,13 +497,12 @@ pub fn get_project_type() -> Result<ProjectType> {
 
 /// Gets the project type from the user with dependency injection
 pub fn get_project_type_with_api<T: InquireApi>(inquire_api: &T) -> Result<ProjectType> {
-    let options = ["Binary (application)", "Library"];
+    let labels = PROJECT_TYPE_OPTIONS.map(|(label, _)| label);
 
-    match inquire_api.select("Project type:", &options, None)? {
-        0 => Ok(ProjectType::Binary),
-        1 => Ok(ProjectType::Library),
-        _ => Ok(ProjectType::Binary), // Default to binary if somehow an invalid option is selected
-    }
+    // Default to binary if somehow an invalid option is selected
+    Ok(PROJECT_TYPE_OPTIONS
+        .get(inquire_api.select("Project type:", &labels, None)?)
+        .map_or(ProjectType::Binary, |(_, project_type)| *project_type))
 }
 
 /// Gets the Rust edition from the user
@@ -143,7 +514,7 @@ pub fn get_rust_edition() -> Result<String> {
 
 /// Gets the Rust edition from the user with dependency injection
 pub fn get_rust_edition_with_api<T: InquireApi>(inquire_api: &T) -> Result<String> {
-    let options = ["2021", "2018", "2015"];
+    let options = quickstart_lib::RUST_EDITIONS;
 
     let idx = inquire_api.select(
         "Rust edition:",
@@ -163,32 +534,13 @@ pub fn get_license() -> Result<String> {
 
 /// Gets the license from the user with dependency injection
 pub fn get_license_with_api<T: InquireApi>(inquire_api: &T) -> Result<String> {
-    let options = [
-        "MIT OR Apache-2.0",
-        "MIT",
-        "Apache-2.0",
-        "GPL-3.0",
-        "BSD-3-Clause",
-        "Unlicense",
-        "Custom",
-    ];
-
     let idx = inquire_api.select(
         "License:",
-        &options,
+        &license_options(),
         Some("The license to use for your project"),
     )?;
 
-    let selection = options[idx];
-
-    if selection == "Custom" {
-        inquire_api.text(
-            "Enter custom license identifier:",
-            Some("Enter a valid SPDX license identifier"),
-        )
-    } else {
-        Ok(selection.to_string())
-    }
+    license_from_selection(inquire_api, idx)
 }
 
 /// Gets git initialization preference from the user
@@ -212,32 +564,78 @@ pub fn get_optional_features() -> Result<Vec<String>> {
 
 /// Gets optional features to include with dependency injection
 pub fn get_optional_features_with_api<T: InquireApi>(inquire_api: &T) -> Result<Vec<String>> {
-    let features = [
-        "README.md",
-        ".gitignore",
-        "CONTRIBUTING.md",
-        "CI configuration",
-        "VS Code configuration",
-        "benchmarks",
-        "examples",
-    ];
+    select_features(inquire_api, None)
+}
+
+/// Labels offered by the wizard's feature selection, in order
+pub(crate) fn feature_options() -> Vec<&'static str> {
+    FEATURES.iter().map(|feature| feature.label).collect()
+}
+
+/// Ask for optional features, pre-selecting `previous` (or the features enabled by default)
+fn select_features<T: InquireApi>(
+    inquire_api: &T,
+    previous: Option<&[String]>,
+) -> Result<Vec<String>> {
+    let options = feature_options();
+    let defaults: Vec<usize> = FEATURES
+        .iter()
+        .enumerate()
+        .filter(|(_, feature)| match previous {
+            Some(previous) => previous.iter().any(|p| p == feature.label),
+            None => feature.default,
+        })
+        .map(|(i, _)| i)
+        .collect();
 
     let indices = inquire_api.multiselect(
         "Select optional features:",
-        &features,
-        &[0, 1], // Default to README and .gitignore
+        &options,
+        &defaults,
         Some("Select additional files and features to include"),
     )?;
 
     Ok(indices
         .into_iter()
-        .map(|i| features[i].to_string())
+        .map(|i| options[i].to_string())
         .collect())
 }
 
+/// Asks what to do with each file of the project that already exists in its directory
+pub fn get_existing_file_actions_with_api<T: InquireApi>(
+    inquire_api: &T,
+    config: &ProjectConfig,
+) -> Result<BTreeMap<PathBuf, ExistingFileAction>> {
+    let labels = EXISTING_FILE_OPTIONS.map(|(label, _)| label);
+
+    quickstart_lib::existing_files(config)?
+        .into_iter()
+        .map(|file| {
+            let selection = inquire_api.select(
+                &format!("{} already exists:", file.display()),
+                &labels,
+                None,
+            )?;
+            // Keep the file if somehow an invalid option is selected
+            let action = EXISTING_FILE_OPTIONS
+                .get(selection)
+                .map_or(ExistingFileAction::Skip, |(_, action)| *action);
+            Ok((file, action))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    /// Wizard base config for a project in `path`, with nothing else given
+    fn wizard_base(path: PathBuf) -> ProjectConfig {
+        ProjectConfig {
+            path,
+            ..Default::default()
+        }
+    }
     use crate::commands::init::inquire_api::TestInquire;
     use pretty_assertions::assert_eq;
     use tempfile::TempDir;
@@ -247,7 +645,7 @@ mod tests {
         let test_inquire = TestInquire::new();
         test_inquire.add_text("valid-project");
 
-        let result = get_project_name_with_api(&test_inquire);
+        let result = get_project_name_with_api(&test_inquire, None);
 
         assert!(result.is_ok());
         if let Ok(name) = result {
@@ -262,7 +660,7 @@ mod tests {
         let test_inquire = TestInquire::new();
         test_inquire.add_text("");
 
-        let result = get_project_name_with_api(&test_inquire);
+        let result = get_project_name_with_api(&test_inquire, None);
 
         assert!(result.is_err());
         if let Err(err) = result {
@@ -272,6 +670,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_project_name_reserved() {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("std");
+
+        let result = get_project_name_with_api(&test_inquire, None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_author_email_valid() -> Result<()> {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text(" jane.doe@example.co.uk ");
+
+        assert_eq!(
+            get_author_email_with_api(&test_inquire, None)?,
+            Some("jane.doe@example.co.uk".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_author_email_invalid() {
+        for email in [
+            "not-an-email",
+            "a@b@example.com",
+            "@example.com",
+            "jane@localhost",
+        ] {
+            let test_inquire = TestInquire::new();
+            test_inquire.add_text(email);
+
+            let err = get_author_email_with_api(&test_inquire, None).expect_err(email);
+            assert!(
+                err.to_string().contains("Validation failed"),
+                "{email}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_author_email_empty() -> Result<()> {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("");
+
+        assert_eq!(get_author_email_with_api(&test_inquire, None)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_author_name_empty() -> Result<()> {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("  ");
+
+        assert_eq!(get_author_name_with_api(&test_inquire, None)?, None);
+        Ok(())
+    }
+
     #[test]
     fn test_get_project_type_binary() {
         let test_inquire = TestInquire::new();
@@ -317,6 +774,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_rust_edition_2024() -> color_eyre::Result<()> {
+        let test_inquire = TestInquire::new();
+        let index = quickstart_lib::RUST_EDITIONS
+            .iter()
+            .position(|e| *e == "2024")
+            .ok_or_else(|| Report::msg("2024 should be offered"))?;
+        test_inquire.add_select(index);
+
+        assert_eq!(get_rust_edition_with_api(&test_inquire)?, "2024");
+        Ok(())
+    }
+
     #[test]
     fn test_get_license_standard() {
         let test_inquire = TestInquire::new();
@@ -348,6 +818,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_license_custom_invalid() {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_select(6); // "Custom" option
+        test_inquire.add_text("MIT OR");
+
+        assert!(get_license_with_api(&test_inquire).is_err());
+    }
+
     #[test]
     fn test_get_optional_features() {
         let test_inquire = TestInquire::new();
@@ -379,12 +858,16 @@ mod tests {
         // Set up mock responses for all prompts in order
         // 1. Project name
         test_inquire.add_text("test-project");
+        // 2. Author name and email
+        test_inquire.add_text("Jane Doe");
+        test_inquire.add_text("jane@example.com");
         // 2. Project type (Binary)
         test_inquire.add_select(0);
         // 3. Rust edition (2021)
         test_inquire.add_select(0);
-        // 4. License (MIT)
+        // 4. License (MIT), writing LICENSE
         test_inquire.add_select(1);
+        test_inquire.add_confirm(true);
         // 5. Git init (yes)
         test_inquire.add_confirm(true);
         // 6. Optional features (README + CI)
@@ -393,7 +876,11 @@ mod tests {
         test_inquire.add_confirm(true);
 
         let temp_dir = TempDir::new()?;
-        let result = run_wizard_with_api(&test_inquire, temp_dir.path().to_path_buf());
+        let result = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        );
 
         assert!(result.is_ok());
         if let Ok(config) = result {
@@ -402,12 +889,281 @@ mod tests {
             assert_eq!(config.edition, "2021");
             assert_eq!(config.license, "MIT");
             assert!(config.git);
+            assert_eq!(config.author_name.as_deref(), Some("Jane Doe"));
+            assert_eq!(config.author_email.as_deref(), Some("jane@example.com"));
+            assert_eq!(
+                config.features,
+                Some(vec!["readme".to_string(), "ci".to_string()])
+            );
         } else {
             panic!("Result should be Ok but was Err");
         }
         Ok(())
     }
 
+    #[test]
+    fn test_run_wizard_dual_license_files() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("dual-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
+        test_inquire.add_select(0);
+        test_inquire.add_select(0);
+        // MIT OR Apache-2.0, declining both license files
+        test_inquire.add_select(0);
+        test_inquire.add_confirm(false);
+        test_inquire.add_confirm(true);
+        test_inquire.add_multiselect(vec![]);
+        test_inquire.add_confirm(true);
+
+        let temp_dir = TempDir::new()?;
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+
+        assert_eq!(config.license, "MIT OR Apache-2.0");
+        assert!(!config.license_files);
+        // The license file prompt defaults to writing them
+        assert_eq!(test_inquire.confirm_defaults(), vec![true, true, true]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_single_license_file() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("single-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
+        test_inquire.add_select(1);
+        test_inquire.add_select(0);
+        // Apache-2.0, writing LICENSE
+        test_inquire.add_select(2);
+        test_inquire.add_confirm(true);
+        test_inquire.add_confirm(false);
+        test_inquire.add_multiselect(vec![]);
+        test_inquire.add_confirm(true);
+
+        let temp_dir = TempDir::new()?;
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+
+        assert_eq!(config.license, "Apache-2.0");
+        assert!(config.license_files);
+        assert!(!config.git);
+        Ok(())
+    }
+
+    #[test]
+    fn test_confirm_license_files() -> Result<()> {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_confirm(false);
+
+        assert!(!confirm_license_files(&test_inquire, "MIT", true)?);
+        // Licenses without bundled texts are not asked about
+        assert!(confirm_license_files(
+            &test_inquire,
+            "LicenseRef-Proprietary",
+            true
+        )?);
+        assert_eq!(test_inquire.confirm_defaults(), vec![true]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_back_navigation() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let test_inquire = TestInquire::new();
+        let back_from_edition = quickstart_lib::RUST_EDITIONS.len();
+
+        test_inquire.add_text("test-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
+        // Binary, then "Back" from the edition step
+        test_inquire.add_select(0);
+        test_inquire.add_select(back_from_edition);
+        // Revisited project type: switch to Library
+        test_inquire.add_select(1);
+        test_inquire.add_select(0);
+        test_inquire.add_select(1);
+        test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
+        test_inquire.add_multiselect(vec![]);
+        test_inquire.add_confirm(true);
+
+        let temp_dir = TempDir::new()?;
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+
+        assert_eq!(config.project_type, ProjectType::Library);
+        assert_eq!(config.edition, "2021");
+        assert_eq!(config.license, "MIT");
+        // The revisited project type prompt starts on the previous answer
+        assert_eq!(
+            test_inquire.select_defaults(),
+            vec![None, None, Some(0), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_reuses_saved_defaults() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let defaults_path = temp_dir.path().join("config").join("wizard-defaults.toml");
+
+        // First run: 2024 edition, Apache-2.0, no git, only CI
+        let first = TestInquire::new();
+        first.add_text("first-project");
+        first.add_text("");
+        first.add_text("");
+        first.add_select(0);
+        first.add_select(1);
+        first.add_select(2);
+        first.add_confirm(true);
+        first.add_confirm(false);
+        first.add_multiselect(vec![3]);
+        first.add_confirm(true);
+        run_wizard_with_api(
+            &first,
+            wizard_base(temp_dir.path().to_path_buf()),
+            Some(&defaults_path),
+        )?;
+
+        // Second run: accept whatever is offered
+        let second = TestInquire::new();
+        second.add_text("second-project");
+        second.add_text("");
+        second.add_text("");
+        second.add_select(0);
+        second.add_select(1);
+        second.add_select(2);
+        second.add_confirm(true);
+        second.add_confirm(false);
+        second.add_multiselect(vec![3]);
+        second.add_confirm(true);
+        run_wizard_with_api(
+            &second,
+            wizard_base(temp_dir.path().to_path_buf()),
+            Some(&defaults_path),
+        )?;
+
+        assert_eq!(first.select_defaults(), vec![None, None, None]);
+        assert_eq!(second.select_defaults(), vec![None, Some(1), Some(2)]);
+        assert_eq!(second.confirm_defaults(), vec![true, false, true]);
+        assert_eq!(second.multiselect_defaults(), vec![vec![3]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_or_back() -> Result<()> {
+        let test_inquire = TestInquire::new();
+        test_inquire.add_select(1);
+        test_inquire.add_select(2);
+
+        assert_eq!(
+            select_or_back(&test_inquire, "Pick:", &["a", "b"], None, None)?,
+            Some(1)
+        );
+        assert_eq!(
+            select_or_back(&test_inquire, "Pick:", &["a", "b"], None, None)?,
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_summary() {
+        let config = ProjectConfig {
+            name: "summary-project".to_string(),
+            project_type: ProjectType::Library,
+            edition: "2024".to_string(),
+            license: "MIT".to_string(),
+            path: PathBuf::from("summary-project"),
+            author_name: Some("Jane Doe".to_string()),
+            ..Default::default()
+        };
+
+        let summary = render_summary(&config, &["CI configuration".to_string()]);
+
+        assert_eq!(
+            summary,
+            "Project name: summary-project\n\
+             Author: Jane Doe\n\
+             Type: Library crate\n\
+             Edition: 2024\n\
+             License: MIT\n\
+             Initialize Git: No\n\
+             Additional features:\n  - CI configuration\n"
+        );
+    }
+
+    #[test]
+    fn test_run_wizard_prompts_for_name_once() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("named-once");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
+        test_inquire.add_select(0);
+        test_inquire.add_select(0);
+        test_inquire.add_select(1);
+        test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
+        test_inquire.add_multiselect(vec![]);
+        test_inquire.add_confirm(true);
+
+        let temp_dir = TempDir::new()?;
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+
+        let name_prompts = test_inquire
+            .text_messages()
+            .iter()
+            .filter(|m| *m == "Project name:")
+            .count();
+        assert_eq!(name_prompts, 1);
+        assert!(crate::commands::init::success_message(&config).contains("named-once"));
+        Ok(())
+    }
+
     #[test]
     fn test_run_wizard_cancel_at_end() -> Result<()> {
         // Skip under Miri
@@ -420,15 +1176,22 @@ mod tests {
 
         // Set up all responses but cancel at the end
         test_inquire.add_text("test-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
         test_inquire.add_select(0);
         test_inquire.add_select(0);
         test_inquire.add_select(1);
         test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
         test_inquire.add_multiselect(vec![0, 3]);
         test_inquire.add_confirm(false); // Cancel at final confirmation
 
         let temp_dir = TempDir::new()?;
-        let result = run_wizard_with_api(&test_inquire, temp_dir.path().to_path_buf());
+        let result = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        );
 
         assert!(result.is_err());
         if let Err(err) = result {
@@ -439,6 +1202,208 @@ mod tests {
         Ok(())
     }
 
+    /// Queue the answers for a binary MIT project with README and CI, confirmed
+    fn queue_wizard_answers(test_inquire: &TestInquire) {
+        test_inquire.add_text("existing-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
+        test_inquire.add_select(0);
+        test_inquire.add_select(0);
+        test_inquire.add_select(1);
+        test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
+        test_inquire.add_multiselect(vec![0, 3]);
+        test_inquire.add_confirm(true);
+    }
+
+    #[test]
+    fn test_run_wizard_asks_about_existing_files() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        std::fs::create_dir_all(temp_dir.path().join("src"))?;
+        std::fs::write(temp_dir.path().join("README.md"), "# Mine\n")?;
+        std::fs::write(temp_dir.path().join("src/main.rs"), "fn main() {}\n")?;
+        // Not part of the project, so never asked about
+        std::fs::write(temp_dir.path().join("notes.txt"), "")?;
+
+        let test_inquire = TestInquire::new();
+        queue_wizard_answers(&test_inquire);
+        // Planned files are asked about in path order
+        test_inquire.add_select(0); // README.md: skip
+        test_inquire.add_select(2); // src/main.rs: back up
+
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+        assert_eq!(
+            config.existing_files,
+            BTreeMap::from([
+                (PathBuf::from("README.md"), ExistingFileAction::Skip),
+                (PathBuf::from("src/main.rs"), ExistingFileAction::Backup),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_existing_file_actions_overwrite() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let test_inquire = TestInquire::new();
+        queue_wizard_answers(&test_inquire);
+        let mut config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+        // Nothing existed yet, so nothing was asked
+        assert!(config.existing_files.is_empty());
+
+        std::fs::write(temp_dir.path().join("Cargo.toml"), "[package]\n")?;
+        test_inquire.add_select(1);
+        config.existing_files = get_existing_file_actions_with_api(&test_inquire, &config)?;
+        assert_eq!(
+            config.existing_files,
+            BTreeMap::from([(PathBuf::from("Cargo.toml"), ExistingFileAction::Overwrite)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_back_prefills_previous_text_answers() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let test_inquire = TestInquire::new();
+        test_inquire.add_text("prefilled");
+        test_inquire.add_text("Jane Doe");
+        test_inquire.add_text("jane@example.com");
+        // Back from the project type re-asks the author step
+        test_inquire.add_select(PROJECT_TYPE_OPTIONS.len());
+        test_inquire.add_text("Jane Doe");
+        test_inquire.add_text("jane@example.com");
+        test_inquire.add_select(0);
+        test_inquire.add_select(0);
+        test_inquire.add_select(1);
+        test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
+        test_inquire.add_multiselect(vec![]);
+        test_inquire.add_confirm(true);
+
+        let temp_dir = TempDir::new()?;
+        let config = run_wizard_with_api(
+            &test_inquire,
+            wizard_base(temp_dir.path().to_path_buf()),
+            None,
+        )?;
+
+        assert_eq!(
+            test_inquire.text_defaults(),
+            vec![
+                None,
+                None,
+                None,
+                Some("Jane Doe".to_string()),
+                Some("jane@example.com".to_string()),
+            ]
+        );
+        assert_eq!(config.author_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(config.author_email.as_deref(), Some("jane@example.com"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_keeps_command_line_options() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let base = ProjectConfig {
+            description: Some("From the command line".to_string()),
+            author_name: Some("Jane Doe".to_string()),
+            features: Some(vec!["ci".to_string(), "changelog".to_string()]),
+            msrv: Some("1.74".to_string()),
+            keywords: vec!["cli".to_string()],
+            dependencies: vec!["serde@1".parse().map_err(Report::msg)?],
+            ..wizard_base(temp_dir.path().to_path_buf())
+        };
+        let test_inquire = TestInquire::new();
+        queue_wizard_answers(&test_inquire);
+        let config = run_wizard_with_api(&test_inquire, base, None)?;
+
+        // --features preselects the features instead of the defaults
+        assert_eq!(test_inquire.multiselect_defaults(), vec![vec![3, 8]]);
+        // --author-name pre-fills the author prompt
+        assert_eq!(test_inquire.text_defaults()[1].as_deref(), Some("Jane Doe"));
+        assert_eq!(config.description.as_deref(), Some("From the command line"));
+        assert_eq!(config.msrv.as_deref(), Some("1.74"));
+        assert_eq!(config.keywords, vec!["cli"]);
+        assert_eq!(config.dependencies[0].to_string(), "serde@1");
+
+        let summary = render_summary(&config, &[]);
+        assert!(
+            summary.contains("Description: From the command line\n"),
+            "{summary}"
+        );
+        assert!(summary.contains("MSRV: 1.74\nKeywords: cli\n"), "{summary}");
+        assert!(summary.contains("Dependencies: serde@1\n"), "{summary}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_wizard_dry_run_writes_nothing() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let base = |path: PathBuf| ProjectConfig {
+            dry_run: true,
+            ..wizard_base(path)
+        };
+
+        // A missing directory is neither offered nor created
+        let missing = temp_dir.path().join("missing");
+        let test_inquire = TestInquire::new();
+        queue_wizard_answers(&test_inquire);
+        let config = run_wizard_with_api(&test_inquire, base(missing.clone()), None)?;
+        assert!(config.dry_run);
+        assert!(!missing.exists());
+        assert!(!quickstart_lib::plan_project(&config)?.is_empty());
+
+        // Existing files are not asked about, since none would be touched
+        std::fs::write(temp_dir.path().join("Cargo.toml"), "[package]\n")?;
+        let test_inquire = TestInquire::new();
+        queue_wizard_answers(&test_inquire);
+        let config = run_wizard_with_api(&test_inquire, base(temp_dir.path().to_path_buf()), None)?;
+        assert!(config.existing_files.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("Cargo.toml"))?,
+            "[package]\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_run_wizard_nonexistent_dir_create() -> Result<()> {
         // Skip under Miri
@@ -453,16 +1418,19 @@ mod tests {
         test_inquire.add_confirm(true);
         // Rest of the wizard prompts
         test_inquire.add_text("test-project");
+        test_inquire.add_text("");
+        test_inquire.add_text("");
         test_inquire.add_select(0);
         test_inquire.add_select(0);
         test_inquire.add_select(1);
         test_inquire.add_confirm(true);
+        test_inquire.add_confirm(true);
         test_inquire.add_multiselect(vec![0]);
         test_inquire.add_confirm(true);
 
         let temp_dir = TempDir::new()?;
         let nonexistent_dir = temp_dir.path().join("nonexistent");
-        let result = run_wizard_with_api(&test_inquire, nonexistent_dir.clone());
+        let result = run_wizard_with_api(&test_inquire, wizard_base(nonexistent_dir.clone()), None);
 
         assert!(result.is_ok());
         assert!(nonexistent_dir.exists());
@@ -484,7 +1452,7 @@ mod tests {
 
         let temp_dir = TempDir::new()?;
         let nonexistent_dir = temp_dir.path().join("nonexistent");
-        let result = run_wizard_with_api(&test_inquire, nonexistent_dir.clone());
+        let result = run_wizard_with_api(&test_inquire, wizard_base(nonexistent_dir.clone()), None);
 
         assert!(result.is_err());
         if let Err(err) = result {
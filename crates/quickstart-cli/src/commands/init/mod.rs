@@ -3,27 +3,83 @@
 mod config;
 mod executor;
 mod inquire_api;
-mod interactive;
+pub(super) mod interactive;
+mod saved_defaults;
 mod tests;
 
+use super::cargo_add;
 use crate::args::InitArgs;
-use crate::errors::CommandErrorExt;
-use crate::ui::progress::with_spinner;
+use crate::errors::{CommandErrorExt, ErrorKind};
+use crate::ui::progress::with_progress_bar;
 use color_eyre::Result;
-use quickstart_lib::generate_project;
+use quickstart_lib::{generate_project_with_progress, DependencySpec, ProjectConfig};
+use std::path::Path;
 
 /// Execute the init command
-pub fn execute(args: InitArgs) -> Result<()> {
+///
+/// With `--cargo-add`, seeded dependencies are added by `cargo add` once the files are
+/// written, unless `offline` is set and they are written into `Cargo.toml` as given.
+pub fn execute(args: InitArgs, offline: bool) -> Result<()> {
     if args.interactive {
-        // Use the enhanced interactive mode
-        let config = interactive::run_wizard(args.path.clone())?;
-        with_spinner(
-            "Initializing project...",
-            "Project initialized successfully!",
-            || generate_project(config).command_context("init"),
-        )
+        // Use the enhanced interactive mode, keeping the options it does not ask about
+        quickstart_lib::validate_keywords(&args.options.keywords)
+            .map_err(|e| ErrorKind::Config.error(e))?;
+        let options = args.options;
+        let use_cargo_add = cargo_add::should_run(options.cargo_add, offline, false);
+        let base = ProjectConfig {
+            path: args.path.clone(),
+            dry_run: args.dry_run,
+            description: options.description,
+            author_name: options.author_name,
+            author_email: options.author_email,
+            features: options.features,
+            toolchain: options.toolchain,
+            msrv: options.msrv,
+            keywords: options.keywords,
+            categories: options.categories,
+            dependencies: options.dependencies,
+            dev_dependencies: options.dev_dependencies,
+            ..Default::default()
+        };
+        let mut config = interactive::run_wizard(base, !args.no_saved_defaults)?;
+
+        // Leave seeded dependencies out of the templates when cargo add resolves them
+        let (dependencies, dev_dependencies) = if use_cargo_add {
+            (
+                std::mem::take(&mut config.dependencies),
+                std::mem::take(&mut config.dev_dependencies),
+            )
+        } else {
+            Default::default()
+        };
+        if config.dry_run {
+            return super::preview_project(&config);
+        }
+        let message = success_message(&config);
+        let report = with_progress_bar("Initializing project...", &message, |progress| {
+            generate_project_with_progress(config, progress).command_context("init")
+        })?;
+        add_dependencies(&report.path, &dependencies, &dev_dependencies)?;
+        super::print_generated(&report);
+        Ok(())
     } else {
         // Use the standard execution path
-        executor::execute(args)
+        executor::execute(args, offline)
     }
 }
+
+/// Add the seeded dependencies held back for `cargo add` to the project in `dir`
+fn add_dependencies(
+    dir: &Path,
+    dependencies: &[DependencySpec],
+    dev_dependencies: &[DependencySpec],
+) -> Result<()> {
+    let executor = cargo_add::RealCommandExecutor { quiet: false };
+    cargo_add::add_dependencies(&executor, dir, dependencies, dev_dependencies)
+        .command_context("init")
+}
+
+/// Message shown once the wizard's project has been generated
+fn success_message(config: &ProjectConfig) -> String {
+    format!("Project '{}' initialized successfully!", config.name)
+}
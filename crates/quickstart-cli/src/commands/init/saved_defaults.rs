@@ -0,0 +1,89 @@
+//! Wizard answers persisted between runs and offered as defaults
+
+use color_eyre::{eyre::Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File name of the saved defaults inside the user config directory
+const DEFAULTS_FILE: &str = "wizard-defaults.toml";
+
+/// Answers from the last successful wizard run
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedDefaults {
+    pub edition: Option<String>,
+    pub license: Option<String>,
+    pub git: Option<bool>,
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+/// Location of the saved defaults in the user config directory
+pub fn default_path() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "cargo-quickstart").map(|dirs| dirs.config_dir().join(DEFAULTS_FILE))
+}
+
+/// Load saved defaults, treating a missing or unreadable file as "no defaults"
+pub fn load(path: &Path) -> SavedDefaults {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Save `defaults`, creating the config directory if needed
+pub fn save(path: &Path, defaults: &SavedDefaults) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    let content = toml::to_string(defaults).context("Failed to serialize wizard defaults")?;
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_roundtrip() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("nested").join(DEFAULTS_FILE);
+        let defaults = SavedDefaults {
+            edition: Some("2024".to_string()),
+            license: Some("MIT".to_string()),
+            git: Some(false),
+            features: vec!["README.md".to_string()],
+        };
+
+        save(&path, &defaults)?;
+        assert_eq!(load(&path), defaults);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_or_invalid() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(DEFAULTS_FILE);
+        assert_eq!(load(&path), SavedDefaults::default());
+
+        std::fs::write(&path, "edition = [")?;
+        assert_eq!(load(&path), SavedDefaults::default());
+        Ok(())
+    }
+}
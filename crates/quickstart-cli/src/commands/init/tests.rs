@@ -3,7 +3,10 @@
 #[cfg(test)]
 mod test {
     use crate::commands::init::{config, executor::execute};
-    use crate::{args::InitArgs, ui::prompts};
+    use crate::{
+        args::{InitArgs, ProjectTypeArgs},
+        ui::prompts,
+    };
     use color_eyre::eyre::Context;
     use color_eyre::Result;
     use pretty_assertions::assert_eq;
@@ -139,21 +142,26 @@ mod tests {
 
         let args = InitArgs {
             name: Some("test-project".to_string()),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: temp_dir.path().to_path_buf(),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         // Enable mocking for prompts
         prompts::enable_mocking();
         prompts::set_mock_confirm(Some(true));
 
-        let result = execute(args);
+        let result = execute(args, false);
 
         // Cleanup test environment
         cleanup_test_environment(current_dir)?;
@@ -177,21 +185,26 @@ mod tests {
 
         let args = InitArgs {
             name: Some("test-project".to_string()),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: temp_dir.path().to_path_buf(),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         // Enable mocking and set confirmation to false
         prompts::enable_mocking();
         prompts::set_mock_confirm(Some(false));
 
-        let result = execute(args);
+        let result = execute(args, false);
 
         // Cleanup test environment
         cleanup_test_environment(current_dir)?;
@@ -225,17 +238,22 @@ mod tests {
 
         let args = InitArgs {
             name: Some("test-project".to_string()),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: nonexistent_dir.clone(),
             git: false,
+            no_git: false,
             yes: true,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
-        let result = execute(args);
+        let result = execute(args, false);
 
         // Cleanup test environment
         cleanup_test_environment(current_dir)?;
@@ -254,14 +272,19 @@ mod tests {
     fn test_explicit_project_name() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: Some("explicit-name".to_string()),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         let project_name =
@@ -274,14 +297,19 @@ mod tests {
     fn test_prompted_name() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: None,
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         prompts::enable_mocking();
@@ -295,18 +323,124 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_name_from_existing_manifest() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("some-dir");
+        std::fs::create_dir_all(&project_dir)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"manifest-name\"\nversion = \"0.1.0\"\n",
+        )?;
+
+        let args = InitArgs {
+            name: None,
+            kind: ProjectType::Binary.into(),
+            edition: "2021".to_string(),
+            license: "MIT".to_string(),
+            path: project_dir,
+            git: false,
+            no_git: false,
+            yes: true,
+            dry_run: false,
+            options: Default::default(),
+            interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
+        };
+
+        let project_name = config::get_project_name(&args)?;
+        assert_eq!(project_name, "manifest-name");
+        Ok(())
+    }
+
+    #[test]
+    fn test_prompted_keyword_name_rejected() {
+        let args = InitArgs {
+            name: None,
+            kind: ProjectType::Binary.into(),
+            edition: "2021".to_string(),
+            license: "MIT".to_string(),
+            path: PathBuf::from("."),
+            git: false,
+            no_git: false,
+            yes: false,
+            dry_run: false,
+            options: Default::default(),
+            interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
+        };
+
+        prompts::enable_mocking();
+        prompts::set_mock_input(Some("crate".to_string()));
+
+        let result = config::get_project_name(&args);
+        prompts::disable_mocking();
+
+        let err = result.expect_err("keyword names should be rejected");
+        assert!(err.to_string().contains("Rust keyword"));
+    }
+
+    #[test]
+    fn test_name_from_directory_without_manifest() -> color_eyre::Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("empty-dir");
+        std::fs::create_dir_all(&project_dir)?;
+
+        let args = InitArgs {
+            name: None,
+            kind: ProjectType::Binary.into(),
+            edition: "2021".to_string(),
+            license: "MIT".to_string(),
+            path: project_dir,
+            git: false,
+            no_git: false,
+            yes: true,
+            dry_run: false,
+            options: Default::default(),
+            interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
+        };
+
+        let project_name = config::get_project_name(&args)?;
+        assert_eq!(project_name, "empty-dir");
+        Ok(())
+    }
+
     #[test]
     fn test_lib_project_type() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: None,
-            bin: false,
-            lib: true,
+            kind: ProjectType::Library.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         let project_type =
@@ -319,14 +453,19 @@ mod tests {
     fn test_bin_project_type() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: None,
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         let project_type =
@@ -339,14 +478,19 @@ mod tests {
     fn test_prompted_project_type() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: None,
-            bin: false,
-            lib: false,
+            kind: ProjectTypeArgs::default(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         prompts::enable_mocking();
@@ -364,14 +508,19 @@ mod tests {
     fn test_fallback_project_type() -> color_eyre::Result<()> {
         let args = InitArgs {
             name: None,
-            bin: false,
-            lib: false,
+            kind: ProjectTypeArgs::default(),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: PathBuf::from("."),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         prompts::enable_mocking();
@@ -0,0 +1,133 @@
+//! Helpers for reading an existing project's Cargo.toml
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::{Deserialize, Deserializer};
+use std::path::Path;
+
+/// The subset of `[package]` metadata the CLI cares about
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackageManifest {
+    /// Package name
+    pub name: String,
+    /// Rust edition, if declared
+    #[serde(default, deserialize_with = "literal_string")]
+    pub edition: Option<String>,
+    /// SPDX license expression, if declared
+    #[serde(default, deserialize_with = "literal_string")]
+    pub license: Option<String>,
+}
+
+/// Accept a plain string value, ignoring workspace-inherited tables like `{ workspace = true }`
+fn literal_string<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match toml::Value::deserialize(deserializer)? {
+        toml::Value::String(value) => Some(value),
+        _ => None,
+    })
+}
+
+/// Top-level manifest layout used for deserialization
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    package: Option<PackageManifest>,
+}
+
+/// Read the `[package]` section of the `Cargo.toml` in `dir`
+///
+/// Returns `Ok(None)` when there is no manifest or it has no `[package]` table.
+pub fn read_package(dir: &Path) -> Result<Option<PackageManifest>> {
+    let manifest_path = dir.join("Cargo.toml");
+    if !manifest_path.is_file() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&manifest_path)
+        .wrap_err_with(|| format!("Failed to read {}", manifest_path.display()))?;
+    let manifest: Manifest = toml::from_str(&content)
+        .wrap_err_with(|| format!("Failed to parse {}", manifest_path.display()))?;
+
+    Ok(manifest.package)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_package() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        std::fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"my-crate\"\nedition = \"2021\"\n",
+        )?;
+
+        let package = read_package(temp_dir.path())?
+            .ok_or_else(|| color_eyre::eyre::eyre!("Expected a package section"))?;
+        assert_eq!(package.name, "my-crate");
+        assert_eq!(package.edition.as_deref(), Some("2021"));
+        assert_eq!(package.license, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_package_missing_manifest() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        assert!(read_package(temp_dir.path())?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_package_workspace_manifest() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        std::fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        assert!(read_package(temp_dir.path())?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_package_inherited_fields() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        std::fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"member\"\nedition.workspace = true\nlicense = { workspace = true }\n",
+        )?;
+
+        let package = read_package(temp_dir.path())?
+            .ok_or_else(|| color_eyre::eyre::eyre!("Expected a package section"))?;
+        assert_eq!(package.name, "member");
+        assert_eq!(package.edition, None);
+        assert_eq!(package.license, None);
+        Ok(())
+    }
+}
@@ -1,41 +1,171 @@
 //! Command implementations for cargo-quickstart
 
+mod add;
+mod cache;
+mod cargo_add;
 #[cfg(feature = "completions")]
 mod completions;
+mod diff;
 #[cfg(feature = "doctor")]
 mod doctor;
+mod features;
 mod init;
+mod manifest;
 mod new;
 mod templates;
+mod validate;
+
+#[cfg(feature = "completions")]
+pub use completions::complete_template_name;
 
 #[cfg(feature = "completions")]
 use crate::args::CompletionsArgs;
-use crate::args::{InitArgs, NewArgs};
-use color_eyre::Result;
+#[cfg(feature = "doctor")]
+use crate::args::DoctorArgs;
+use crate::args::{
+    AddArgs, CacheArgs, InitArgs, ListFeaturesArgs, ListTemplatesArgs, NewArgs, OutputFormat,
+    ValidateTemplatesArgs,
+};
+use crate::errors::SuggestionsExt;
+use crate::ui::output;
+use color_eyre::{eyre::Report, Result};
+use directories::ProjectDirs;
+use quickstart_lib::{
+    template::{RemoteTemplates, TemplateError, TemplateLoader},
+    GenerationReport, ProjectConfig,
+};
+use std::path::{Path, PathBuf};
+
+/// Suggestions attached when the templates cannot be located
+const TEMPLATE_LOOKUP_SUGGESTIONS: [&str; 3] = [
+    "Set CARGO_QUICKSTART_TEMPLATES to a directory containing the templates",
+    "Run the command from a project root that has a templates/ directory",
+    "Reinstall cargo-quickstart to restore its bundled templates",
+];
 
 /// Execute the 'new' command
-pub fn execute_new(args: NewArgs) -> Result<()> {
-    new::execute(args)
+pub fn execute_new(args: NewArgs, quiet: bool, offline: bool) -> Result<()> {
+    new::execute(args, quiet, offline)
 }
 
 /// Execute the 'init' command
-pub fn execute_init(args: InitArgs) -> Result<()> {
-    init::execute(args)
+pub fn execute_init(args: InitArgs, offline: bool) -> Result<()> {
+    init::execute(args, offline)
+}
+
+/// Execute the 'add' command
+pub fn execute_add(args: AddArgs) -> Result<()> {
+    add::execute(args)
+}
+
+/// Print the files `config` would generate instead of writing them
+fn preview_project(config: &ProjectConfig) -> Result<()> {
+    let files = quickstart_lib::plan_project(config)?;
+
+    output::section("Files that would be created");
+    output::file_tree(&config.path.display().to_string(), &files);
+    output::info("Dry run: no files were written");
+    Ok(())
+}
+
+/// Confirm on stdout that the project in `report` was generated
+fn print_generated(report: &GenerationReport) {
+    for file in &report.skipped {
+        output::info(&format!("Kept existing {}", file.display()));
+    }
+    output::success(&format!(
+        "Successfully generated project: {}",
+        report.config.name
+    ));
+}
+
+/// Directory fetched template repositories are cached in
+fn template_cache_root() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "cargo-quickstart").map(|dirs| dirs.cache_dir().join("templates"))
+}
+
+/// Fetch `remote` into `cache_root`, returning its templates directory
+///
+/// A cached copy is reused unless `refresh` is set.
+fn fetch_remote_templates(
+    remote: &RemoteTemplates,
+    cache_root: &Path,
+    refresh: bool,
+) -> Result<PathBuf> {
+    let fetched = if refresh {
+        remote.refetch(cache_root)
+    } else {
+        remote.fetch(cache_root)
+    };
+    fetched.map_err(Report::from).suggest(&[
+        "Check that the repository URL and --template-ref are correct",
+        "Fetching needs network access; once fetched, templates are reused offline",
+    ])
+}
+
+/// Find the templates to use, suggesting fixes when the lookup fails
+fn find_template_loader() -> Result<TemplateLoader> {
+    with_template_suggestions(quickstart_lib::find_template_loader())
+}
+
+/// Turn a template lookup error into a report with [`TEMPLATE_LOOKUP_SUGGESTIONS`]
+fn with_template_suggestions<T>(result: std::io::Result<T>) -> Result<T> {
+    result
+        .map_err(|source| Report::from(TemplateError::LookupError { source }))
+        .suggest(&TEMPLATE_LOOKUP_SUGGESTIONS)
 }
 
 /// Execute the 'list-templates' command
-pub fn execute_list_templates() -> Result<()> {
-    // Find the templates directory
-    let template_dir = quickstart_lib::find_templates_dir()?;
-    let loader = quickstart_lib::template::TemplateLoader::new(template_dir);
+pub fn execute_list_templates(args: ListTemplatesArgs) -> Result<()> {
+    // Find the templates, falling back to the bundled set
+    let loader = find_template_loader()?;
 
-    // Collect and display templates
-    let all_templates = templates::collect_templates(&loader)?;
-    templates::display_templates(all_templates);
+    match args.format {
+        OutputFormat::Human if args.long => {
+            let entries = templates::collect_template_entries(&loader)?;
+            templates::display_template_entries(&entries);
+        }
+        OutputFormat::Human => {
+            // Collect and display templates
+            let all_templates = templates::collect_templates(&loader)?;
+            templates::display_templates(all_templates);
+        }
+        OutputFormat::Json => {
+            let entries = templates::collect_template_entries(&loader)?;
+            println!("{}", templates::entries_to_json(&entries)?);
+        }
+    }
 
     Ok(())
 }
 
+/// Execute the 'list-features' command
+pub fn execute_list_features(args: ListFeaturesArgs) -> Result<()> {
+    let loader = find_template_loader()?;
+    let entries = features::collect_feature_entries(&loader)?;
+
+    match args.format {
+        OutputFormat::Human => features::display_features(&entries),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&entries)?),
+    }
+
+    Ok(())
+}
+
+/// Execute the 'cache' command
+pub fn execute_cache(args: CacheArgs) -> Result<()> {
+    cache::execute(args)
+}
+
+/// Execute the 'validate-templates' command
+pub fn execute_validate_templates(args: ValidateTemplatesArgs) -> Result<()> {
+    let loader = match args.path {
+        Some(path) => TemplateLoader::new(path),
+        None => find_template_loader()?,
+    };
+    validate::execute(&loader)
+}
+
 /// Execute the 'completions' command
 #[cfg(feature = "completions")]
 pub fn execute_completions(args: CompletionsArgs) -> color_eyre::Result<()> {
@@ -44,8 +174,8 @@ pub fn execute_completions(args: CompletionsArgs) -> color_eyre::Result<()> {
 
 /// Execute the 'doctor' command
 #[cfg(feature = "doctor")]
-pub fn execute_doctor() -> color_eyre::Result<()> {
-    doctor::execute()
+pub fn execute_doctor(args: DoctorArgs, offline: bool) -> color_eyre::Result<()> {
+    doctor::execute(args, offline)
 }
 
 #[cfg(test)]
@@ -55,6 +185,7 @@ mod tests {
     use color_eyre::{eyre::WrapErr, Result};
     use mockall::predicate::*;
     use mockall::*;
+    use quickstart_lib::ProjectType;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
@@ -76,6 +207,44 @@ mod tests {
     #[allow(unused_imports)]
     use mock_template_loader::MockTemplateLoader;
 
+    #[test]
+    fn test_template_lookup_error_suggestions() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping process test under Miri");
+            return Ok(());
+        }
+
+        // Sections are only kept by the color-eyre handler, which has to be installed
+        // before any other report is created, so check them in a fresh test process
+        if std::env::var_os("QUICKSTART_SUGGESTIONS_TEST").is_none() {
+            let status = std::process::Command::new(std::env::current_exe()?)
+                .args([
+                    "--exact",
+                    "commands::tests::test_template_lookup_error_suggestions",
+                ])
+                .env("QUICKSTART_SUGGESTIONS_TEST", "1")
+                .status()?;
+            assert!(status.success());
+            return Ok(());
+        }
+        color_eyre::install()?;
+
+        let err = with_template_suggestions::<()>(Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Could not find a 'templates/' directory in this or any parent directory.",
+        )))
+        .expect_err("lookup error should be returned");
+
+        let report = format!("{err:?}");
+        assert!(report.contains("Could not find a 'templates/' directory"));
+        for suggestion in TEMPLATE_LOOKUP_SUGGESTIONS {
+            assert!(report.contains(suggestion), "{report}");
+        }
+        assert!(report.contains("CARGO_QUICKSTART_TEMPLATES"));
+        Ok(())
+    }
+
     #[test]
     fn test_execute_list_templates_with_mocks() {
         // Skip under Miri
@@ -87,7 +256,10 @@ mod tests {
         // This is a simple test - we can't easily mock the entire quickstart_lib,
         // but we can verify the function doesn't panic when run
         // We're in a test environment, so there should be templates available
-        let result = execute_list_templates();
+        let result = execute_list_templates(ListTemplatesArgs {
+            format: OutputFormat::Human,
+            long: false,
+        });
         assert!(
             result.is_ok(),
             "execute_list_templates should complete successfully"
@@ -103,7 +275,7 @@ mod tests {
             return;
         }
 
-        let result = execute_doctor();
+        let result = execute_doctor(DoctorArgs::default(), false);
         assert!(
             result.is_ok(),
             "execute_doctor should complete successfully"
@@ -128,6 +300,8 @@ mod tests {
         let args = CompletionsArgs {
             shell: Shell::Bash,
             output: None,
+            install: false,
+            install_dir: None,
         };
 
         let result = execute_completions(args);
@@ -155,17 +329,27 @@ mod tests {
 
         let args = NewArgs {
             name: "test-project".to_string(),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
+            bin_name: None,
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             path: Some(PathBuf::from("/non-existent-path/that-does-not-exist")),
             yes: true,
+            dry_run: false,
+            options: Default::default(),
+            template: None,
+            template_repo: None,
+            template_ref: None,
+            refresh_templates: false,
+            define: Vec::new(),
+            emit_manifest: None,
+            format: OutputFormat::Human,
         };
 
         // We expect this to fail because we're using a non-existent path
-        let result = execute_new(args);
+        let result = execute_new(args, false, false);
         assert!(result.is_err(), "Should fail with non-existent path");
     }
 
@@ -183,20 +367,25 @@ mod tests {
         let nonexistent_subdir = temp_dir.path().join("nonexistent_subdir");
 
         let args = InitArgs {
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
             name: Some("test-project".to_string()),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             path: nonexistent_subdir,
             yes: true,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         // This test is only verifying that the function correctly passes arguments to the init module.
         // The implementation creates directories that don't exist, so this should succeed.
-        let result = execute_init(args);
+        let result = execute_init(args, false);
         assert!(
             result.is_ok(),
             "Should succeed because implementation creates directories"
@@ -1,24 +1,89 @@
 //! Implementation of the 'new' command for creating a new project
 
-use color_eyre::Result;
-use quickstart_lib::{generate_project, ProjectConfig, ProjectType};
-use std::path::PathBuf;
+use color_eyre::{
+    eyre::{Context, Report},
+    Result,
+};
+use quickstart_lib::{
+    generate_project, generate_project_with_progress,
+    template::{
+        CargoGenerateManifest, Placeholder, PlaceholderKind, PlaceholderValue, RemoteTemplates,
+        TemplateVariant,
+    },
+    GenerationReport, ProjectConfig, ProjectType,
+};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
+use super::cargo_add;
 use crate::{
-    args::NewArgs,
-    errors::CommandErrorExt,
-    ui::{output, progress::with_spinner},
+    args::{NewArgs, OutputFormat},
+    errors::{CommandErrorExt, ErrorKind, SuggestionsExt},
+    ui::{output, progress::with_progress_bar, prompts},
 };
 
 /// Execute the 'new' command
-pub fn execute(args: NewArgs) -> Result<()> {
-    output::header("Generating project");
+///
+/// Only errors are printed when `quiet` is set, and only the JSON report with
+/// `--format json`, so scripts can rely on the exit code and stdout.
+///
+/// With `--cargo-add`, seeded dependencies are added by `cargo add` once the files are
+/// written, unless `offline` is set and they are written into `Cargo.toml` as given.
+pub fn execute(args: NewArgs, quiet: bool, offline: bool) -> Result<()> {
+    let decorated = !quiet && args.format == OutputFormat::Human;
+    if decorated {
+        output::header("Generating project");
+    }
 
     // Determine project type
-    let project_type = if args.lib {
-        ProjectType::Library
+    let project_type = args.kind.selected().unwrap_or(ProjectType::Binary);
+    quickstart_lib::validate_keywords(&args.options.keywords)
+        .map_err(|e| ErrorKind::Config.error(e))?;
+    if args.bin_name.is_some() && !project_type.has_binary() {
+        return Err(ErrorKind::Config
+            .error("--bin-name needs a binary target; use --bin, or --bin --lib for both"));
+    }
+
+    // Resolve the requested template, defaulting to the extended variant
+    let variant = match &args.template {
+        Some(name) => name
+            .parse::<TemplateVariant>()
+            .map_err(|e| ErrorKind::Config.error(e))?,
+        None => TemplateVariant::default(),
+    };
+
+    let git = args.use_git();
+
+    // Fetch remote templates up front so a bad URL fails before anything is written
+    let templates_dir = match args.template_repo {
+        Some(url) => {
+            let cache_root = super::template_cache_root().ok_or_else(|| {
+                ErrorKind::FileSystem.error("Could not determine the user cache directory")
+            })?;
+            let remote = RemoteTemplates::new(url, args.template_ref).with_offline(offline);
+            if decorated {
+                output::info(&format!("Using templates from {}", remote.url));
+            }
+            Some(super::fetch_remote_templates(
+                &remote,
+                &cache_root,
+                args.refresh_templates,
+            )?)
+        }
+        None => None,
+    };
+
+    let placeholders = placeholder_values(templates_dir.as_deref(), args.define, args.yes)?;
+
+    // Leave seeded dependencies out of the templates when cargo add resolves them
+    let use_cargo_add = cargo_add::should_run(args.options.cargo_add, offline, quiet);
+    let (dependencies, dev_dependencies) = if use_cargo_add {
+        (Vec::new(), Vec::new())
     } else {
-        ProjectType::Binary // Default to binary if not specified
+        (
+            args.options.dependencies.clone(),
+            args.options.dev_dependencies.clone(),
+        )
     };
 
     // Determine project path
@@ -30,20 +95,26 @@ pub fn execute(args: NewArgs) -> Result<()> {
 
     // Check if the target directory already exists
     if project_path.exists() {
-        return Err(color_eyre::eyre::eyre!(
+        return Err(ErrorKind::FileSystem.error(format!(
             "Target directory '{}' already exists. Refusing to overwrite.",
             project_path.display()
-        ));
+        )));
     }
 
     // Display project information
-    output::section("Project configuration");
-    output::key_value("Name", &args.name);
-    output::key_value("Type", &project_type.to_string());
-    output::key_value("Edition", &args.edition);
-    output::key_value("License", &args.license);
-    output::key_value("Path", &project_path.display().to_string());
-    output::key_value("Git", &args.git.to_string());
+    if decorated {
+        output::section("Project configuration");
+        output::key_value("Name", &args.name);
+        output::key_value("Type", &project_type.to_string());
+        if let Some(bin_name) = &args.bin_name {
+            output::key_value("Binary", bin_name);
+        }
+        output::key_value("Edition", &args.edition);
+        output::key_value("License", &args.license);
+        output::key_value("Path", &project_path.display().to_string());
+        output::key_value("Git", &git.to_string());
+        output::key_value("Template", variant.name());
+    }
 
     // Build configuration
     let config = ProjectConfig {
@@ -52,16 +123,132 @@ pub fn execute(args: NewArgs) -> Result<()> {
         edition: args.edition,
         license: args.license,
         path: project_path,
-        git: args.git,
+        git,
         yes: args.yes,
+        variant,
+        dry_run: args.dry_run,
+        description: args.options.description,
+        author_name: args.options.author_name,
+        author_email: args.options.author_email,
+        features: args.options.features,
+        toolchain: args.options.toolchain,
+        msrv: args.options.msrv,
+        keywords: args.options.keywords,
+        categories: args.options.categories,
+        dependencies,
+        dev_dependencies,
+        templates_dir,
+        placeholders,
+        bin_name: args.bin_name,
+        ..Default::default()
     };
 
-    // Generate project with a progress spinner
-    with_spinner(
-        "Generating project...",
-        "Project created successfully!",
-        || generate_project(config).command_context("new"),
-    )
+    if config.dry_run {
+        return super::preview_project(&config);
+    }
+
+    // Generate project, with a per-file progress bar unless the output is for scripts
+    let report = if decorated {
+        with_progress_bar(
+            "Generating project...",
+            "Project created successfully!",
+            |progress| generate_project_with_progress(config, progress).command_context("new"),
+        )?
+    } else {
+        generate_project(config).command_context("new")?
+    };
+
+    if use_cargo_add {
+        let executor = cargo_add::RealCommandExecutor { quiet: !decorated };
+        cargo_add::add_dependencies(
+            &executor,
+            &report.path,
+            &args.options.dependencies,
+            &args.options.dev_dependencies,
+        )
+        .command_context("new")?;
+    }
+
+    if let Some(manifest) = &args.emit_manifest {
+        write_manifest(&report, manifest)?;
+        if decorated {
+            output::info(&format!("Wrote manifest to {}", manifest.display()));
+        }
+    }
+
+    match args.format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Human if !quiet => super::print_generated(&report),
+        OutputFormat::Human => {}
+    }
+    Ok(())
+}
+
+/// Write `report` to `path` as pretty-printed JSON
+fn write_manifest(report: &GenerationReport, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(report)?;
+    std::fs::write(path, json + "\n")
+        .with_context(|| format!("Failed to write manifest {}", path.display()))
+}
+
+/// Values for the placeholders of a cargo-generate template
+///
+/// Placeholders not set with `--define` are asked for, unless `yes` is set and their
+/// defaults are used. Templates in any other format get the definitions unchanged.
+fn placeholder_values(
+    templates_dir: Option<&Path>,
+    defined: Vec<(String, String)>,
+    yes: bool,
+) -> Result<BTreeMap<String, String>> {
+    let mut values: BTreeMap<String, String> = defined.into_iter().collect();
+
+    let templates_dir = match templates_dir {
+        Some(dir) => Some(dir.to_path_buf()),
+        None => quickstart_lib::find_templates_dir().ok(),
+    };
+    let Some(manifest) = templates_dir
+        .map(|dir| CargoGenerateManifest::load(&dir))
+        .transpose()?
+        .flatten()
+    else {
+        return Ok(values);
+    };
+
+    if !yes {
+        for (name, placeholder) in &manifest.placeholders {
+            if !values.contains_key(name) {
+                values.insert(name.clone(), prompt_placeholder(placeholder)?);
+            }
+        }
+    }
+
+    // Check every value now, before anything is written
+    manifest
+        .placeholder_values(&values)
+        .map_err(Report::from)
+        .suggest(&["Set placeholders with --define NAME=VALUE"])?;
+    Ok(values)
+}
+
+/// Ask for the value of a cargo-generate placeholder
+fn prompt_placeholder(placeholder: &Placeholder) -> Result<String> {
+    let default = placeholder.default.as_ref();
+
+    match (placeholder.kind, &placeholder.choices) {
+        (PlaceholderKind::Bool, _) => {
+            let default = matches!(default, Some(PlaceholderValue::Bool(true)));
+            Ok(prompts::confirm(&placeholder.prompt, default)?.to_string())
+        }
+        (PlaceholderKind::String, Some(choices)) => {
+            let options: Vec<&str> = choices.iter().map(String::as_str).collect();
+            let index = prompts::select(&placeholder.prompt, &options)?;
+            Ok(choices[index].clone())
+        }
+        (PlaceholderKind::String, None) => match default {
+            Some(default) => prompts::input_with_default(&placeholder.prompt, &default.to_string()),
+            None => prompts::input_required(&placeholder.prompt),
+        },
+    }
 }
 
 #[cfg(test)]
@@ -202,20 +389,30 @@ mod tests {
 
         let args = NewArgs {
             name: "test-project".to_string(),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
+            bin_name: None,
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             path: Some(project_dir.clone()),
             git: false,
+            no_git: false,
             yes: true,
+            dry_run: false,
+            options: Default::default(),
+            template: None,
+            template_repo: None,
+            template_ref: None,
+            refresh_templates: false,
+            define: Vec::new(),
+            emit_manifest: None,
+            format: OutputFormat::Human,
         };
 
         // Enable mocking for prompts
         crate::ui::prompts::enable_mocking();
         crate::ui::prompts::set_mock_confirm(Some(true));
 
-        let result = execute(args);
+        let result = execute(args, false, false);
 
         // Cleanup test environment
         cleanup_test_environment(current_dir)?;
@@ -247,15 +444,25 @@ mod tests {
         let args = NewArgs {
             name: "test-lib".to_string(),
             path: Some(project_dir.clone()),
-            lib: true,
-            bin: false,
+            kind: ProjectType::Library.into(),
+            bin_name: None,
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             yes: true,
+            dry_run: false,
+            options: Default::default(),
+            template: None,
+            template_repo: None,
+            template_ref: None,
+            refresh_templates: false,
+            define: Vec::new(),
+            emit_manifest: None,
+            format: OutputFormat::Human,
         };
 
-        let result = execute(args);
+        let result = execute(args, false, false);
 
         // Cleanup test environment
         cleanup_test_environment(current_dir)?;
@@ -289,15 +496,25 @@ mod tests {
         let args = NewArgs {
             name: "existing-project".to_string(),
             path: Some(project_path),
-            lib: false,
-            bin: true,
+            kind: ProjectType::Binary.into(),
+            bin_name: None,
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
+            template: None,
+            template_repo: None,
+            template_ref: None,
+            refresh_templates: false,
+            define: Vec::new(),
+            emit_manifest: None,
+            format: OutputFormat::Human,
         };
 
-        let result = execute(args);
+        let result = execute(args, false, false);
         assert!(
             result.is_err(),
             "execute() should fail on existing directory"
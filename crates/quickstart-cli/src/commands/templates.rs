@@ -2,10 +2,37 @@
 
 use color_eyre::Result;
 use quickstart_lib::{template::TemplateVariant, ProjectType};
+use serde::Serialize;
 use std::collections::BTreeMap;
+use std::path::Path;
 
 use crate::ui::output;
 
+/// A single template entry in machine-readable listings
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateEntry {
+    /// Project type directory (binary or library)
+    #[serde(rename = "type")]
+    pub project_type: String,
+    /// Template variant (minimal or extended)
+    pub variant: String,
+    /// Template path relative to the templates directory
+    pub path: String,
+    /// Directory the template comes from: `base` (shared) or `type` (project type/variant)
+    pub source: String,
+    /// Destination path relative to the generated project root
+    pub destination: String,
+}
+
+/// Directory name used for a project type in the templates tree
+const fn type_name(project_type: ProjectType) -> &'static str {
+    match project_type {
+        ProjectType::Binary => "binary",
+        ProjectType::Library => "library",
+        ProjectType::Both => "both",
+    }
+}
+
 /// Collect templates from all project types and variants
 pub fn collect_templates(
     loader: &quickstart_lib::template::TemplateLoader,
@@ -15,14 +42,7 @@ pub fn collect_templates(
 
     for &project_type in &[ProjectType::Binary, ProjectType::Library] {
         for &variant in &[TemplateVariant::Minimal, TemplateVariant::Extended] {
-            let label = format!(
-                "{:?}/{}",
-                project_type,
-                match variant {
-                    TemplateVariant::Minimal => "minimal",
-                    TemplateVariant::Extended => "extended",
-                }
-            );
+            let label = format!("{:?}/{}", project_type, variant.name());
 
             match loader.list_templates(project_type, variant) {
                 Ok(templates) => {
@@ -47,6 +67,59 @@ pub fn collect_templates(
     Ok(all_templates)
 }
 
+/// Collect templates from all project types and variants as flat entries
+pub fn collect_template_entries(
+    loader: &quickstart_lib::template::TemplateLoader,
+) -> Result<Vec<TemplateEntry>> {
+    let mut entries = Vec::new();
+
+    for &project_type in &[ProjectType::Binary, ProjectType::Library] {
+        for &variant in &[TemplateVariant::Minimal, TemplateVariant::Extended] {
+            let Ok(templates) = loader.list_templates(project_type, variant) else {
+                // No templates for this type/variant, skip
+                continue;
+            };
+
+            let mut type_entries: Vec<TemplateEntry> = templates
+                .into_iter()
+                .map(|p| {
+                    let path = p
+                        .strip_prefix(loader.base_path())
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_else(|_| p.display().to_string());
+                    let destination = loader
+                        .get_destination_path(&p, Path::new(""))
+                        .display()
+                        .to_string();
+
+                    let source = if path.starts_with("base/") {
+                        "base"
+                    } else {
+                        "type"
+                    };
+
+                    TemplateEntry {
+                        project_type: type_name(project_type).to_string(),
+                        variant: variant.name().to_string(),
+                        path,
+                        source: source.to_string(),
+                        destination,
+                    }
+                })
+                .collect();
+            type_entries.sort_by(|a, b| a.path.cmp(&b.path));
+            entries.extend(type_entries);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Serialize template entries as a pretty-printed JSON array
+pub fn entries_to_json(entries: &[TemplateEntry]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(entries)?)
+}
+
 /// Display collected templates in a structured format
 pub fn display_templates(all_templates: BTreeMap<String, Vec<String>>) {
     if all_templates.is_empty() {
@@ -62,6 +135,53 @@ pub fn display_templates(all_templates: BTreeMap<String, Vec<String>>) {
     }
 }
 
+/// Format template entries as an aligned table, one row per template
+pub fn format_template_table(entries: &[TemplateEntry]) -> String {
+    let header = ["TYPE", "VARIANT", "SOURCE", "TEMPLATE", "DESTINATION"];
+    let rows: Vec<[&str; 5]> = entries
+        .iter()
+        .map(|e| {
+            [
+                e.project_type.as_str(),
+                e.variant.as_str(),
+                e.source.as_str(),
+                e.path.as_str(),
+                e.destination.as_str(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    std::iter::once(header)
+        .chain(rows)
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Display template entries as a detailed table
+pub fn display_template_entries(entries: &[TemplateEntry]) {
+    if entries.is_empty() {
+        output::warning("No templates found.");
+    } else {
+        output::header("Available templates");
+        println!("{}", format_template_table(entries));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +219,66 @@ mod tests {
         assert!(!templates.is_empty());
         Ok(())
     }
+
+    #[test]
+    fn test_template_entries_json() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let binary_dir = temp_dir.path().join("binary").join("minimal").join("src");
+        std::fs::create_dir_all(&binary_dir)?;
+        std::fs::write(binary_dir.join("main.rs.hbs"), "fn main() {}")?;
+
+        let loader = quickstart_lib::template::TemplateLoader::new(temp_dir.path());
+        let entries = collect_template_entries(&loader)?;
+        let json = entries_to_json(&entries)?;
+
+        let parsed: serde_json::Value = serde_json::from_str(&json)?;
+        let items = parsed
+            .as_array()
+            .ok_or_else(|| color_eyre::eyre::eyre!("Expected a JSON array"))?;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["type"], "binary");
+        assert_eq!(items[0]["variant"], "minimal");
+        assert_eq!(items[0]["path"], "binary/minimal/src/main.rs.hbs");
+        assert_eq!(items[0]["source"], "type");
+        assert_eq!(items[0]["destination"], "src/main.rs");
+        Ok(())
+    }
+
+    #[test]
+    fn test_template_table_long_output() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let binary_dir = temp_dir.path().join("binary").join("minimal").join("src");
+        std::fs::create_dir_all(&binary_dir)?;
+        std::fs::create_dir_all(temp_dir.path().join("base"))?;
+        std::fs::write(binary_dir.join("main.rs.hbs"), "fn main() {}")?;
+        std::fs::write(temp_dir.path().join("base").join("README.md.hbs"), "# x")?;
+
+        let loader = quickstart_lib::template::TemplateLoader::new(temp_dir.path());
+        let table = format_template_table(&collect_template_entries(&loader)?);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("TYPE"));
+        assert!(lines[0].ends_with("DESTINATION"));
+        assert!(lines[1].contains("base") && lines[1].ends_with("README.md"));
+        assert!(lines[2].contains("binary/minimal/src/main.rs.hbs"));
+        assert!(lines[2].ends_with("src/main.rs"));
+
+        // Destinations line up under their header
+        let column = lines[0].find("DESTINATION");
+        assert_eq!(lines[2].rfind("src/main.rs"), column);
+        Ok(())
+    }
 }
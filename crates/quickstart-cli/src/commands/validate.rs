@@ -0,0 +1,298 @@
+//! Implementation of the 'validate-templates' command
+
+use color_eyre::Result;
+use quickstart_lib::{
+    template::{license_files, TemplateEngine, TemplateLoader, TemplateVariables, TemplateVariant},
+    ProjectConfig, ProjectType,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::errors::ErrorKind;
+use crate::ui::output;
+
+/// Editions rendered for every template, so both sides of edition branches are checked
+const EDITIONS: [&str; 2] = ["2021", "2024"];
+
+/// A problem found in a template
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TemplateIssue {
+    /// Template path relative to the templates directory
+    pub template: String,
+    /// What is wrong with it
+    pub message: String,
+}
+
+/// Validate every template and report the problems found
+pub fn execute(loader: &TemplateLoader) -> Result<()> {
+    output::header("Validating templates");
+    if !loader.is_bundled() {
+        output::key_value("Templates", &loader.base_path().display().to_string());
+    }
+
+    let (checked, issues) = validate_templates(loader)?;
+    if issues.is_empty() {
+        output::success(&format!("Checked {checked} templates, no problems found"));
+        return Ok(());
+    }
+
+    for issue in &issues {
+        output::error(&format!("{}: {}", issue.template, issue.message));
+    }
+    Err(ErrorKind::Template.error(format!(
+        "Found {} problem(s) in {checked} templates",
+        issues.len()
+    )))
+}
+
+/// Render every project type and variant with placeholder variables
+///
+/// Returns the number of templates checked and the issues found, sorted and deduplicated.
+pub fn validate_templates(loader: &TemplateLoader) -> Result<(usize, Vec<TemplateIssue>)> {
+    let mut checked = BTreeSet::new();
+    let mut issues = BTreeSet::new();
+
+    for project_type in [ProjectType::Binary, ProjectType::Library, ProjectType::Both] {
+        for variant in [TemplateVariant::Minimal, TemplateVariant::Extended] {
+            let Ok(templates) = loader.list_templates(project_type, variant) else {
+                // No templates for this type/variant, skip
+                continue;
+            };
+
+            let mut rel_paths: Vec<String> = templates
+                .iter()
+                .map(|template| loader.relative_path(template))
+                .collect();
+            rel_paths.extend(
+                license_files("MIT OR Apache-2.0")
+                    .iter()
+                    .map(|license| license.template.to_string())
+                    .filter(|template| loader.template_exists(template)),
+            );
+
+            issues.extend(destination_collisions(loader, &templates));
+            for edition in EDITIONS {
+                let config = placeholder_config(project_type, variant, edition);
+                issues.extend(render_issues(loader, &config, &rel_paths));
+            }
+            checked.extend(rel_paths);
+        }
+    }
+
+    if checked.is_empty() {
+        return Err(ErrorKind::Template.error(format!(
+            "No templates found in '{}'",
+            loader.base_path().display()
+        )));
+    }
+
+    Ok((checked.len(), issues.into_iter().collect()))
+}
+
+/// Project configuration with every optional value filled in
+fn placeholder_config(
+    project_type: ProjectType,
+    variant: TemplateVariant,
+    edition: &str,
+) -> ProjectConfig {
+    ProjectConfig {
+        name: "example-project".to_string(),
+        project_type,
+        edition: edition.to_string(),
+        path: PathBuf::from("example-project"),
+        yes: true,
+        variant,
+        dry_run: true,
+        description: Some("An example project".to_string()),
+        author_name: Some("Example Author".to_string()),
+        author_email: Some("author@example.com".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Compile and render `rel_paths` and their destination names for `config`, collecting
+/// syntax and render errors
+fn render_issues(
+    loader: &TemplateLoader,
+    config: &ProjectConfig,
+    rel_paths: &[String],
+) -> Vec<TemplateIssue> {
+    let mut engine = TemplateEngine::new(TemplateVariables::from_config(config));
+    let mut issues = Vec::new();
+    let mut registered = Vec::new();
+
+    for rel_path in rel_paths {
+        let result = loader
+            .load_template(rel_path)
+            .and_then(|content| engine.register_template_file(rel_path, &content));
+        match result {
+            Ok(()) => registered.push(rel_path),
+            Err(err) => issues.push(TemplateIssue {
+                template: rel_path.clone(),
+                message: err.to_string(),
+            }),
+        }
+    }
+
+    for rel_path in registered {
+        if TemplateLoader::is_partial(Path::new(rel_path)) {
+            continue;
+        }
+        let destination =
+            loader.get_destination_path(&loader.base_path().join(rel_path), Path::new(""));
+        let result = engine.render_path(&destination).and_then(|_| {
+            engine.render_named_to_writer(TemplateLoader::partial_name(rel_path), std::io::sink())
+        });
+        if let Err(err) = result {
+            issues.push(TemplateIssue {
+                template: rel_path.clone(),
+                message: err.to_string(),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Report templates of one type/variant that render to the same destination
+fn destination_collisions(loader: &TemplateLoader, templates: &[PathBuf]) -> Vec<TemplateIssue> {
+    let mut by_destination: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+    for template in templates {
+        if TemplateLoader::is_partial(template) {
+            continue;
+        }
+        by_destination
+            .entry(loader.get_destination_path(template, Path::new("")))
+            .or_default()
+            .push(loader.relative_path(template));
+    }
+
+    by_destination
+        .into_iter()
+        .filter(|(_, sources)| sources.len() > 1)
+        .flat_map(|(destination, mut sources)| {
+            sources.sort();
+            let message = format!(
+                "renders to '{}', as do {}",
+                destination.display(),
+                sources.join(", ")
+            );
+            sources.into_iter().map(move |template| TemplateIssue {
+                template,
+                message: message.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+
+    /// Write a minimal valid templates tree
+    fn write_valid_tree(root: &Path) -> Result<()> {
+        fs::create_dir_all(root.join("base"))?;
+        fs::create_dir_all(root.join("binary/minimal/src"))?;
+        fs::write(root.join("base/README.md.hbs"), "# {{name}}\n")?;
+        fs::write(root.join("base/_footer.md.hbs"), "by {{author.name}}\n")?;
+        fs::write(
+            root.join("binary/minimal/src/main.rs.hbs"),
+            "// {{> base/_footer.md}}fn main() {}\n",
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_valid_tree() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        write_valid_tree(dir.path())?;
+
+        let (checked, issues) = validate_templates(&TemplateLoader::new(dir.path()))?;
+        assert_eq!(checked, 3);
+        assert_eq!(issues, Vec::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_broken_templates() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        write_valid_tree(dir.path())?;
+        fs::write(dir.path().join("base/CHANGELOG.md.hbs"), "{{#if name}\n")?;
+        fs::write(
+            dir.path().join("base/NOTES.md.hbs"),
+            "{{no_such_variable}}\n",
+        )?;
+
+        fs::write(dir.path().join("base/{{no_such_name}}.md.hbs"), "\n")?;
+
+        let (_, issues) = validate_templates(&TemplateLoader::new(dir.path()))?;
+        let templates: Vec<&str> = issues.iter().map(|i| i.template.as_str()).collect();
+        assert_eq!(
+            templates,
+            vec![
+                "base/CHANGELOG.md.hbs",
+                "base/NOTES.md.hbs",
+                "base/{{no_such_name}}.md.hbs"
+            ]
+        );
+        assert!(issues[1].message.contains("no_such_variable"));
+        assert!(issues[2].message.contains("invalid name"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_destination_collisions() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        write_valid_tree(dir.path())?;
+        fs::create_dir_all(dir.path().join("base/src"))?;
+        fs::write(dir.path().join("base/src/main.rs.hbs"), "fn main() {}\n")?;
+
+        let (_, issues) = validate_templates(&TemplateLoader::new(dir.path()))?;
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .all(|issue| issue.message.contains("renders to 'src/main.rs'")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_bundled_templates() -> Result<()> {
+        let (checked, issues) = validate_templates(&TemplateLoader::bundled())?;
+        assert!(checked > 0);
+        assert_eq!(issues, Vec::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_empty_tree() -> Result<()> {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return Ok(());
+        }
+
+        let dir = tempfile::tempdir()?;
+        assert!(validate_templates(&TemplateLoader::new(dir.path())).is_err());
+        Ok(())
+    }
+}
@@ -1,36 +1,127 @@
 //! Error handling for the CLI interface
 
 use color_eyre::{eyre::Report, Section};
+use quickstart_lib::template::TemplateError;
 use std::path::Path;
 use thiserror::Error;
 
 /// CLI-specific error types
+///
+/// Command errors keep their [`Report`], with its sections and suggestions, and are
+/// sorted into a variant by [`From<Report>`] so each kind gets its own exit code.
 #[derive(Error, Debug)]
 pub enum CliError {
-    /// Error occurred during command execution
-    #[error("Command failed: {0}")]
-    CommandError(String),
-
     /// Error occurred with file system operations
-    #[allow(dead_code)]
-    #[error("File system error: {0}")]
-    FileSystemError(String),
+    #[error("{0}")]
+    FileSystemError(Report),
 
     /// Error occurred with project configuration
-    #[allow(dead_code)]
-    #[error("Configuration error: {0}")]
-    ConfigError(String),
+    #[error("{0}")]
+    ConfigError(Report),
+
+    /// Error occurred with template lookup or processing
+    #[error("{0}")]
+    TemplateError(Report),
 
-    /// Error occurred with template processing
-    #[allow(dead_code)]
-    #[error("Template error: {0}")]
-    TemplateError(String),
+    /// Any other error reported by a command or quickstart-lib
+    #[error("{0}")]
+    LibraryError(Report),
 
     /// Other errors that don't fit specific categories
     #[error("Error: {0}")]
     Other(String),
 }
 
+/// Kind of failure the CLI raises itself, tagged where the error is created
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Invalid arguments or settings
+    Config,
+    /// Missing or broken templates
+    Template,
+    /// Files or directories in the way, or missing
+    FileSystem,
+    /// An outcome the user should see fail without the tool being at fault, such as
+    /// an unhealthy project or a cancelled prompt
+    Other,
+}
+
+impl ErrorKind {
+    /// Create a report for `message` that exits with this kind's code
+    #[track_caller]
+    pub fn error(self, message: impl Into<String>) -> Report {
+        Report::new(KindError {
+            kind: self,
+            message: message.into(),
+        })
+    }
+}
+
+/// Error message tagged with the [`ErrorKind`] that decides its exit code
+#[derive(Debug, Error)]
+#[error("{message}")]
+struct KindError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl From<Report> for CliError {
+    fn from(report: Report) -> Self {
+        let tagged = report
+            .chain()
+            .find_map(|e| e.downcast_ref::<KindError>())
+            .map(|e| e.kind);
+        let caused_by = |is: fn(&(dyn std::error::Error + 'static)) -> bool| report.chain().any(is);
+
+        // Template errors often wrap an io::Error, so they are checked first
+        if tagged == Some(ErrorKind::Other) {
+            Self::Other(format!("{report:?}"))
+        } else if tagged == Some(ErrorKind::Config) {
+            Self::ConfigError(report)
+        } else if tagged == Some(ErrorKind::Template) || caused_by(|e| e.is::<TemplateError>()) {
+            Self::TemplateError(report)
+        } else if tagged == Some(ErrorKind::FileSystem) || caused_by(|e| e.is::<std::io::Error>()) {
+            Self::FileSystemError(report)
+        } else {
+            Self::LibraryError(report)
+        }
+    }
+}
+
+impl CliError {
+    /// Process exit code for this kind of failure, so scripts can tell them apart
+    ///
+    /// | Variant           | Code |
+    /// |-------------------|------|
+    /// | `Other`           | 1    |
+    /// | `ConfigError`     | 2    |
+    /// | `TemplateError`   | 3    |
+    /// | `LibraryError`    | 4    |
+    /// | `FileSystemError` | 5    |
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::Other(_) => 1,
+            Self::ConfigError(_) => 2,
+            Self::TemplateError(_) => 3,
+            Self::LibraryError(_) => 4,
+            Self::FileSystemError(_) => 5,
+        }
+    }
+
+    /// Text printed for this error when the CLI exits
+    ///
+    /// Reports are rendered by color-eyre, with their sections and suggestions.
+    pub fn render(&self) -> String {
+        match self {
+            Self::FileSystemError(report)
+            | Self::ConfigError(report)
+            | Self::TemplateError(report)
+            | Self::LibraryError(report) => format!("{report:?}"),
+            Self::Other(message) => message.clone(),
+        }
+    }
+}
+
 /// Set up the error handling system for the CLI
 pub fn setup() -> color_eyre::Result<()> {
     color_eyre::install()
@@ -67,7 +158,6 @@ where
 
 /// Add actionable suggestions to an error
 pub trait SuggestionsExt<T> {
-    #[allow(dead_code)]
     fn suggest(self, suggestions: &[&str]) -> Result<T, Report>;
 }
 
@@ -100,6 +190,71 @@ mod tests {
         assert!(true, "Test passing by design");
     }
 
+    #[test]
+    fn test_exit_code_per_variant() {
+        let cases = [
+            (CliError::Other("other".to_string()), 1),
+            (CliError::ConfigError(eyre!("bad config")), 2),
+            (CliError::TemplateError(eyre!("bad template")), 3),
+            (CliError::LibraryError(eyre!("lib failed")), 4),
+            (CliError::FileSystemError(eyre!("no access")), 5),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.exit_code(), code, "{err}");
+        }
+    }
+
+    #[test]
+    fn test_report_converts_to_library_error() {
+        let err = CliError::from(eyre!("Something went wrong"));
+        assert!(matches!(err, CliError::LibraryError(_)));
+        assert_eq!(err.to_string(), "Something went wrong");
+        assert!(err.render().contains("Something went wrong"));
+    }
+
+    #[test]
+    fn test_report_classified_by_cause() {
+        let template = Report::from(TemplateError::TemplateNotFound {
+            path: "base/README.md.hbs".to_string(),
+        });
+        assert_eq!(CliError::from(template).exit_code(), 3);
+
+        // A lookup failure is a template error even though it wraps an io::Error
+        let lookup = Report::from(TemplateError::LookupError {
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "no templates"),
+        });
+        assert_eq!(CliError::from(lookup).exit_code(), 3);
+
+        let io = Report::from(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "denied",
+        ))
+        .wrap_err("Could not write Cargo.toml");
+        let err = CliError::from(io);
+        assert!(matches!(err, CliError::FileSystemError(_)));
+        assert_eq!(err.to_string(), "Could not write Cargo.toml");
+    }
+
+    #[test]
+    fn test_report_classified_by_tagged_kind() {
+        for (kind, code) in [
+            (ErrorKind::Config, 2),
+            (ErrorKind::Template, 3),
+            (ErrorKind::FileSystem, 5),
+        ] {
+            let report = Err::<(), _>(kind.error("tagged"))
+                .suggest(&["Try again"])
+                .unwrap_err();
+            let err = CliError::from(report);
+            assert_eq!(err.exit_code(), code, "{kind:?}");
+            assert_eq!(err.to_string(), "tagged");
+        }
+
+        let err = CliError::from(ErrorKind::Other.error("Doctor found 1 error(s)"));
+        assert_eq!(err.exit_code(), 1);
+        assert!(err.render().starts_with("Doctor found 1 error(s)"));
+    }
+
     #[test]
     fn test_with_path_context() {
         let path = PathBuf::from("/test/path");
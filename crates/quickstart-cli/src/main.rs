@@ -9,31 +9,46 @@ use args::{Cli, Commands};
 use clap::Parser;
 use errors::CliError;
 
-fn main() -> Result<(), CliError> {
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("Error: {}", err.render());
+        std::process::exit(err.exit_code());
+    }
+}
+
+/// Run the CLI, returning the error that decides the exit code
+fn run() -> Result<(), CliError> {
     // Setup error handling
     errors::setup().map_err(|e| CliError::Other(e.to_string()))?;
 
+    // Answer dynamic shell completion requests (COMPLETE=<shell>) before parsing
+    #[cfg(feature = "completions")]
+    clap_complete::CompleteEnv::with_factory(<Cli as clap::CommandFactory>::command).complete();
+
     // Parse command-line arguments
     let cli = Cli::parse();
 
+    // Route library diagnostics through the selected verbosity (RUST_LOG still overrides)
+    env_logger::Builder::new()
+        .filter_level(cli.log_level())
+        .format_timestamp(None)
+        .format_target(false)
+        .parse_default_env()
+        .init();
+
     // Route to the appropriate command handler
     match cli.command {
-        Commands::New(args) => {
-            commands::execute_new(args).map_err(|e| CliError::CommandError(e.to_string()))?
-        }
-        Commands::Init(args) => {
-            commands::execute_init(args).map_err(|e| CliError::CommandError(e.to_string()))?
-        }
-        Commands::ListTemplates => {
-            commands::execute_list_templates().map_err(|e| CliError::CommandError(e.to_string()))?
-        }
+        Commands::New(args) => commands::execute_new(args, cli.quiet, cli.offline)?,
+        Commands::Init(args) => commands::execute_init(args, cli.offline)?,
+        Commands::Add(args) => commands::execute_add(args)?,
+        Commands::ListTemplates(args) => commands::execute_list_templates(args)?,
+        Commands::ListFeatures(args) => commands::execute_list_features(args)?,
+        Commands::ValidateTemplates(args) => commands::execute_validate_templates(args)?,
+        Commands::Cache(args) => commands::execute_cache(args)?,
         #[cfg(feature = "completions")]
-        Commands::Completions(args) => commands::execute_completions(args)
-            .map_err(|e| CliError::CommandError(e.to_string()))?,
+        Commands::Completions(args) => commands::execute_completions(args)?,
         #[cfg(feature = "doctor")]
-        Commands::Doctor => {
-            commands::execute_doctor().map_err(|e| CliError::CommandError(e.to_string()))?
-        }
+        Commands::Doctor(args) => commands::execute_doctor(args, cli.offline)?,
     }
 
     Ok(())
@@ -42,11 +57,12 @@ fn main() -> Result<(), CliError> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        args::{Cli, Commands, InitArgs, NewArgs},
+        args::{Cli, Commands, InitArgs, ListTemplatesArgs, NewArgs, OutputFormat},
         errors::CliError,
     };
     use clap::Parser;
     use pretty_assertions::assert_eq;
+    use quickstart_lib::ProjectType;
     use std::path::PathBuf;
 
     #[test]
@@ -63,7 +79,7 @@ mod tests {
         let cli = Cli::parse_from(["cargo-quickstart", "list-templates"]);
 
         match cli.command {
-            Commands::ListTemplates => {
+            Commands::ListTemplates(_) => {
                 // Expected command
             }
             _ => panic!("Unexpected command parsed"),
@@ -76,8 +92,8 @@ mod tests {
         let err = CliError::Other("Test error".to_string());
         assert_eq!(err.to_string(), "Error: Test error");
 
-        let err = CliError::CommandError("Test command error".to_string());
-        assert_eq!(err.to_string(), "Command failed: Test command error");
+        let err = CliError::from(color_eyre::eyre::eyre!("Test command error"));
+        assert_eq!(err.to_string(), "Test command error");
     }
 
     #[test]
@@ -96,8 +112,8 @@ mod tests {
         match cli.command {
             Commands::New(args) => {
                 assert_eq!(args.name, "test-project");
-                assert!(args.bin);
-                assert!(!args.lib);
+                assert!(args.kind.bin);
+                assert!(!args.kind.lib);
                 assert_eq!(args.edition, "2021");
                 assert!(args.git);
             }
@@ -123,8 +139,8 @@ mod tests {
         match cli.command {
             Commands::Init(args) => {
                 assert_eq!(args.name, Some("test-lib".to_string()));
-                assert!(!args.bin);
-                assert!(args.lib);
+                assert!(!args.kind.bin);
+                assert!(args.kind.lib);
                 assert_eq!(args.license, "MIT");
                 assert_eq!(args.path.to_string_lossy(), "/tmp/project");
             }
@@ -163,7 +179,7 @@ mod tests {
         let cli = Cli::parse_from(["cargo-quickstart", "doctor"]);
 
         match cli.command {
-            Commands::Doctor => {
+            Commands::Doctor(_) => {
                 // Successfully parsed Doctor command
             }
             _ => panic!("Expected Doctor command"),
@@ -179,13 +195,23 @@ mod tests {
         // Mock Commands::New case
         let new_args = NewArgs {
             name: "test-project".to_string(),
-            bin: true,
-            lib: false,
+            kind: ProjectType::Binary.into(),
+            bin_name: None,
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             path: None,
             yes: false,
+            dry_run: false,
+            options: Default::default(),
+            template: None,
+            template_repo: None,
+            template_ref: None,
+            refresh_templates: false,
+            define: Vec::new(),
+            emit_manifest: None,
+            format: OutputFormat::Human,
         };
 
         let result = match Commands::New(new_args) {
@@ -196,15 +222,20 @@ mod tests {
 
         // Mock Commands::Init case
         let init_args = InitArgs {
-            bin: false,
-            lib: true,
+            kind: ProjectType::Library.into(),
             name: Some("test-lib".to_string()),
             edition: "2021".to_string(),
             license: "MIT".to_string(),
             git: false,
+            no_git: false,
             path: PathBuf::from("."),
             yes: false,
+            dry_run: false,
+            options: Default::default(),
             interactive: false,
+            no_saved_defaults: false,
+            backup: false,
+            diff: false,
         };
 
         let result = match Commands::Init(init_args) {
@@ -214,8 +245,12 @@ mod tests {
         assert!(result.is_ok());
 
         // Mock Commands::ListTemplates case
-        let result = match Commands::ListTemplates {
-            Commands::ListTemplates => Ok(()),
+        let list_args = ListTemplatesArgs {
+            format: OutputFormat::Human,
+            long: false,
+        };
+        let result = match Commands::ListTemplates(list_args) {
+            Commands::ListTemplates(_) => Ok(()),
             _ => Err(CliError::Other("Wrong command matched".to_string())),
         };
         assert!(result.is_ok());
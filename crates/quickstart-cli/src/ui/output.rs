@@ -1,7 +1,9 @@
 //! Formatted output utilities for the CLI
 
 use console::Style;
+use std::collections::BTreeSet;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Print a success message to stdout
 #[allow(dead_code)]
@@ -49,12 +51,68 @@ pub fn key_value(key: &str, value: &str) {
     println!("{}: {}", style.apply_to(key), value);
 }
 
+/// Print a unified diff, colouring removed and added lines
+pub fn diff(unified: &str) {
+    let removed = Style::new().red();
+    let added = Style::new().green();
+    let hunk = Style::new().cyan();
+    for line in unified.lines() {
+        if line.starts_with("---") || line.starts_with("+++") {
+            println!("{}", Style::new().bold().apply_to(line));
+        } else if line.starts_with("@@") {
+            println!("{}", hunk.apply_to(line));
+        } else if line.starts_with('-') {
+            println!("{}", removed.apply_to(line));
+        } else if line.starts_with('+') {
+            println!("{}", added.apply_to(line));
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
 /// Print a list item with a bullet point
 #[allow(dead_code)]
 pub fn list_item(message: &str) {
     println!("  • {message}");
 }
 
+/// Print relative file paths as an indented tree under `root`
+pub fn file_tree(root: &str, paths: &[PathBuf]) {
+    for line in format_file_tree(root, paths) {
+        println!("{line}");
+    }
+}
+
+/// Render relative file paths as indented tree lines, directories suffixed with `/`
+fn format_file_tree(root: &str, paths: &[PathBuf]) -> Vec<String> {
+    let mut lines = vec![format!("{root}/")];
+    let mut seen = BTreeSet::new();
+    let sorted: BTreeSet<&Path> = paths.iter().map(PathBuf::as_path).collect();
+
+    for path in sorted {
+        let components: Vec<_> = path.components().collect();
+        let mut prefix = PathBuf::new();
+        for (depth, component) in components.iter().enumerate() {
+            prefix.push(component);
+            if seen.insert(prefix.clone()) {
+                let suffix = if depth + 1 < components.len() {
+                    "/"
+                } else {
+                    ""
+                };
+                lines.push(format!(
+                    "{}{}{suffix}",
+                    "  ".repeat(depth + 1),
+                    component.as_os_str().to_string_lossy()
+                ));
+            }
+        }
+    }
+
+    lines
+}
+
 /// Print a confirmation message and get user input
 #[allow(dead_code)]
 pub fn confirm(message: &str) -> io::Result<bool> {
@@ -85,7 +143,29 @@ mod tests {
         error("Error message");
         list_item("List item");
 
+        file_tree("demo", &[PathBuf::from("src/main.rs")]);
+
         // We cannot easily test the confirm function in an automated test
         // as it requires stdin interaction. Skip this for coverage purposes.
     }
+
+    #[test]
+    fn test_format_file_tree() {
+        let paths = [
+            PathBuf::from("src/main.rs"),
+            PathBuf::from("Cargo.toml"),
+            PathBuf::from("src/lib.rs"),
+        ];
+
+        assert_eq!(
+            format_file_tree("demo", &paths),
+            vec![
+                "demo/",
+                "  Cargo.toml",
+                "  src/",
+                "    lib.rs",
+                "    main.rs"
+            ]
+        );
+    }
 }
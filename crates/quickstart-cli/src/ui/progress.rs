@@ -1,6 +1,7 @@
 //! Progress indicators for long-running operations
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::path::Path;
 use std::time::Duration;
 
 /// Create a spinner for tasks that have an unknown duration
@@ -20,7 +21,6 @@ pub fn spinner(message: &str) -> ProgressBar {
 }
 
 /// Create a progress bar for tasks with a known number of steps
-#[allow(dead_code)]
 pub fn progress_bar(total: u64, message: &str) -> ProgressBar {
     let pb = ProgressBar::new(total);
 
@@ -42,6 +42,7 @@ pub fn multi_progress() -> MultiProgress {
 }
 
 /// Wrap a function with a spinner that completes on success
+#[allow(dead_code)]
 pub fn with_spinner<F, T, E>(message: &str, complete_message: &str, func: F) -> Result<T, E>
 where
     F: FnOnce() -> Result<T, E>,
@@ -60,3 +61,27 @@ where
 
     result
 }
+
+/// Wrap a function with a progress bar it advances through a `(path, current, total)` callback
+pub fn with_progress_bar<F, T, E>(message: &str, complete_message: &str, func: F) -> Result<T, E>
+where
+    F: FnOnce(&dyn Fn(&Path, usize, usize)) -> Result<T, E>,
+{
+    let bar = progress_bar(0, message);
+    let result = func(&|path, current, total| {
+        bar.set_length(total as u64);
+        bar.set_position(current as u64);
+        bar.set_message(path.display().to_string());
+    });
+
+    match &result {
+        Ok(_) => {
+            bar.finish_with_message(format!("✓ {complete_message}"));
+        }
+        Err(_) => {
+            bar.abandon_with_message(format!("✗ {message}"));
+        }
+    }
+
+    result
+}
@@ -76,7 +76,6 @@ pub fn input_with_default(prompt: &str, default: &str) -> color_eyre::Result<Str
 }
 
 /// Get required user input (no default)
-#[allow(dead_code)]
 pub fn input_required(prompt: &str) -> color_eyre::Result<String> {
     #[cfg(test)]
     {
@@ -164,19 +163,7 @@ pub fn project_name(prompt: &str) -> Result<String> {
             if let Ok(mut lock) = MOCK_INPUT.lock() {
                 if let Some(mock_value) = lock.take() {
                     // Validate the mock input
-                    if mock_value.is_empty() {
-                        return Err(Report::msg("Project name cannot be empty"));
-                    }
-
-                    if mock_value.contains(|c: char| !c.is_alphanumeric() && c != '_' && c != '-') {
-                        return Err(Report::msg(
-                            "Project name must contain only alphanumeric characters, '-', or '_'",
-                        ));
-                    }
-
-                    if mock_value.chars().next().is_none_or(|c| !c.is_alphabetic()) {
-                        return Err(Report::msg("Project name must start with a letter"));
-                    }
+                    quickstart_lib::validate_crate_name(&mock_value).map_err(Report::msg)?;
 
                     return Ok(mock_value);
                 }
@@ -185,24 +172,10 @@ pub fn project_name(prompt: &str) -> Result<String> {
     }
 
     let validator = |input: &str| -> Result<Validation, CustomUserError> {
-        // Validate according to Cargo's rules
-        if input.is_empty() {
-            return Ok(Validation::Invalid("Project name cannot be empty".into()));
-        }
-
-        if input.contains(|c: char| !c.is_alphanumeric() && c != '_' && c != '-') {
-            return Ok(Validation::Invalid(
-                "Project name must contain only alphanumeric characters, '-', or '_'".into(),
-            ));
-        }
-
-        if input.chars().next().is_none_or(|c| !c.is_alphabetic()) {
-            return Ok(Validation::Invalid(
-                "Project name must start with a letter".into(),
-            ));
-        }
-
-        Ok(Validation::Valid)
+        Ok(match quickstart_lib::validate_crate_name(input) {
+            Ok(()) => Validation::Valid,
+            Err(message) => Validation::Invalid(message.into()),
+        })
     };
 
     let result = Text::new(prompt)
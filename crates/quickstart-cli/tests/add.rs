@@ -0,0 +1,80 @@
+//! Integration tests for the add command
+
+mod utils;
+use anyhow::Result;
+use utils::*;
+
+/// Scaffold a binary project into `dir` and return its path
+fn scaffold_project(dir: &std::path::Path) -> Result<std::path::PathBuf> {
+    let project = dir.join("my-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "my-app", "--bin", "--yes", "--path"])
+        .arg(&project);
+    cmd.assert().success();
+    Ok(project)
+}
+
+#[test]
+fn test_add_gitignore_to_scaffolded_project() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = scaffold_project(temp.path())?;
+    std::fs::remove_file(project.join(".gitignore"))?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["add", ".gitignore", "--path"]).arg(&project);
+    assert_success_with_output(&mut cmd, "Templates added successfully!");
+    assert_file_exists(&project, ".gitignore");
+    Ok(())
+}
+
+#[test]
+fn test_add_refuses_to_overwrite_without_force() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = scaffold_project(temp.path())?;
+    std::fs::write(project.join(".gitignore"), "custom\n")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["add", ".gitignore", "--path"]).arg(&project);
+    assert_failure_with_error(&mut cmd, "Refusing to overwrite");
+    assert_eq!(
+        std::fs::read_to_string(project.join(".gitignore"))?,
+        "custom\n"
+    );
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["add", ".gitignore", "--force", "--path"])
+        .arg(&project);
+    cmd.assert().success();
+    assert_ne!(
+        std::fs::read_to_string(project.join(".gitignore"))?,
+        "custom\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_add_requires_manifest() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let mut cmd = create_test_command()?;
+    cmd.args(["add", ".gitignore", "--path"]).arg(temp.path());
+    assert_failure_with_error(&mut cmd, "No Cargo.toml");
+    Ok(())
+}
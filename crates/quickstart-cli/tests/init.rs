@@ -0,0 +1,119 @@
+//! Integration tests for the init command
+
+mod utils;
+use anyhow::Result;
+use predicates::prelude::*;
+use utils::*;
+
+#[test]
+fn test_init_backup_keeps_overwritten_files() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    std::fs::write(temp.path().join("README.md"), "# My notes\n")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "init",
+        "--name",
+        "reinit-app",
+        "--yes",
+        "--backup",
+        "--path",
+    ])
+    .arg(temp.path());
+    cmd.assert().success();
+
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("README.md.bak"))?,
+        "# My notes\n"
+    );
+    let readme = std::fs::read_to_string(temp.path().join("README.md"))?;
+    assert!(readme.contains("# reinit-app"), "{readme}");
+
+    // A second run keeps the first backup
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "init",
+        "--name",
+        "reinit-app",
+        "--yes",
+        "--backup",
+        "--path",
+    ])
+    .arg(temp.path());
+    cmd.assert().success();
+
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("README.md.bak"))?,
+        "# My notes\n"
+    );
+    assert_file_exists(temp.path(), "README.md.bak.1");
+    Ok(())
+}
+
+#[test]
+fn test_init_diff_previews_changes_without_writing() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    std::fs::write(temp.path().join("README.md"), "# My notes\n")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["init", "--name", "diff-app", "--yes", "--diff", "--path"])
+        .arg(temp.path());
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stdout = String::from_utf8(output)?;
+
+    assert!(stdout.contains("--- a/README.md"), "{stdout}");
+    assert!(stdout.contains("-# My notes"), "{stdout}");
+    assert!(stdout.contains("+# diff-app"), "{stdout}");
+    assert!(stdout.contains("Cargo.toml"), "{stdout}");
+
+    // Nothing was written
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("README.md"))?,
+        "# My notes\n"
+    );
+    assert!(!temp.path().join("Cargo.toml").exists());
+    Ok(())
+}
+
+#[test]
+fn test_init_cargo_add_falls_back_offline() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "--offline",
+        "init",
+        "--name",
+        "offline-init",
+        "--dep",
+        "serde",
+        "--cargo-add",
+        "--yes",
+        "--path",
+    ])
+    .arg(temp.path());
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("instead of running cargo add"));
+
+    let manifest = std::fs::read_to_string(temp.path().join("Cargo.toml"))?;
+    assert!(manifest.contains("serde = \"*\""), "{manifest}");
+    Ok(())
+}
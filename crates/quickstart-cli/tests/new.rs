@@ -0,0 +1,812 @@
+//! Integration tests for the new command
+
+mod utils;
+use anyhow::Result;
+use predicates::prelude::*;
+use utils::*;
+
+#[test]
+fn test_new_rejects_keyword_name() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "self", "--bin", "--yes", "--path"])
+        .arg(temp.path().join("self"));
+    assert_failure_with_error(&mut cmd, "`self` is a Rust keyword");
+    assert!(!temp.path().join("self").exists());
+    Ok(())
+}
+
+#[test]
+fn test_new_rejects_reserved_name() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "test", "--lib", "--yes"]);
+    assert_failure_with_error(&mut cmd, "`test` is a reserved crate name");
+    Ok(())
+}
+
+#[test]
+fn test_init_rejects_keyword_name() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["init", "--name", "crate", "--yes"]);
+    assert_failure_with_error(&mut cmd, "`crate` is a Rust keyword");
+    Ok(())
+}
+
+#[test]
+fn test_new_with_2024_edition() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("modern-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "modern-app",
+        "--bin",
+        "--yes",
+        "--edition",
+        "2024",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("edition = \"2024\""));
+    Ok(())
+}
+
+#[test]
+fn test_new_hides_loader_details_unless_verbose() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let mut cmd = create_test_command()?;
+    cmd.env_remove("RUST_LOG")
+        .args(["new", "quiet-app", "--bin", "--yes", "--path"])
+        .arg(temp.path().join("quiet-app"));
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Template directory").not())
+        .stderr(predicates::str::contains("Template directory").not());
+
+    let mut cmd = create_test_command()?;
+    cmd.env_remove("RUST_LOG")
+        .args(["--verbose", "new", "loud-app", "--bin", "--yes", "--path"])
+        .arg(temp.path().join("loud-app"));
+    cmd.assert()
+        .success()
+        .stderr(predicates::str::contains("Template directory"));
+    Ok(())
+}
+
+#[test]
+fn test_new_dry_run_writes_nothing() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("foo");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "foo", "--bin", "--dry-run", "--path"])
+        .arg(&project);
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Files that would be created"))
+        .stdout(predicates::str::contains("Cargo.toml"))
+        .stdout(predicates::str::contains("main.rs"));
+
+    assert!(!project.exists());
+    Ok(())
+}
+
+#[test]
+fn test_new_with_description_and_author() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("described");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "described",
+        "--lib",
+        "--yes",
+        "--description",
+        "Parses widgets quickly",
+        "--author-name",
+        "Jane Doe",
+        "--author-email",
+        "jane@example.com",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let readme = std::fs::read_to_string(project.join("README.md"))?;
+    assert!(readme.contains("Parses widgets quickly"));
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("Jane Doe <jane@example.com>"));
+    Ok(())
+}
+
+#[test]
+fn test_new_author_with_quotes_is_not_html_escaped() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("quoted-author");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "quoted-author",
+        "--bin",
+        "--yes",
+        "--author-name",
+        r#"Dan "The Man" O'Brien"#,
+        "--author-email",
+        "dan@example.com",
+        "--description",
+        "Turns <widgets> into \"gadgets\" & more",
+        "--license",
+        "MIT",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest: toml::Table =
+        toml::from_str(&std::fs::read_to_string(project.join("Cargo.toml"))?)?;
+    assert_eq!(
+        manifest["package"]["authors"][0].as_str(),
+        Some(r#"Dan "The Man" O'Brien <dan@example.com>"#)
+    );
+
+    let readme = std::fs::read_to_string(project.join("README.md"))?;
+    assert!(
+        readme.contains("Turns <widgets> into \"gadgets\" & more"),
+        "{readme}"
+    );
+    let license = std::fs::read_to_string(project.join("LICENSE"))?;
+    assert!(license.contains(r#"Dan "The Man" O'Brien"#), "{license}");
+    Ok(())
+}
+
+#[test]
+fn test_new_description_with_quotes_is_valid_toml() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("quoted");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "quoted",
+        "--lib",
+        "--yes",
+        "--description",
+        r#"Tom's "fast" \ tool"#,
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest: toml::Table =
+        toml::from_str(&std::fs::read_to_string(project.join("Cargo.toml"))?)?;
+    assert_eq!(
+        manifest["package"]["description"].as_str(),
+        Some(r#"Tom's "fast" \ tool"#)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_new_with_selected_features() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("featured");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "featured",
+        "--bin",
+        "--yes",
+        "--features",
+        "ci,readme",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    assert_file_exists(&project, ".github/workflows/ci.yml");
+    assert_file_exists(&project, "README.md");
+    assert!(!project.join(".vscode").exists());
+    assert!(!project.join(".gitignore").exists());
+    Ok(())
+}
+
+#[test]
+fn test_new_with_binary_and_library() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("hybrid-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "hybrid-app", "--bin", "--lib", "--yes", "--path"])
+        .arg(&project);
+    cmd.assert().success();
+
+    assert_file_exists(&project, "src/lib.rs");
+    assert_file_exists(&project, "src/main.rs");
+    let main = std::fs::read_to_string(project.join("src/main.rs"))?;
+    assert!(main.contains("use hybrid_app::add;"), "{main}");
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("clap"), "{manifest}");
+    Ok(())
+}
+
+#[test]
+fn test_new_with_bin_name() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("named-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "named-app",
+        "--bin",
+        "--bin-name",
+        "cli",
+        "--yes",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("name = \"named-app\""), "{manifest}");
+    assert!(
+        manifest.contains("[[bin]]\nname = \"cli\"\npath = \"src/main.rs\""),
+        "{manifest}"
+    );
+
+    // A library has no binary target to rename
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "named-lib",
+        "--lib",
+        "--bin-name",
+        "cli",
+        "--yes",
+        "--path",
+    ])
+    .arg(temp.path().join("named-lib"));
+    assert_failure_with_error(&mut cmd, "--bin-name needs a binary target");
+    Ok(())
+}
+
+#[test]
+fn test_new_with_msrv() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("pinned-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "pinned-app", "--msrv", "1.74.0", "--yes", "--path"])
+        .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("rust-version = \"1.74.0\""), "{manifest}");
+
+    let project = temp.path().join("unpinned-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "unpinned-app", "--yes", "--path"])
+        .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(!manifest.contains("rust-version"), "{manifest}");
+    Ok(())
+}
+
+#[test]
+fn test_new_with_keywords_and_categories() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("listed-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "listed-app",
+        "--keyword",
+        "cli",
+        "--keyword",
+        "scaffold",
+        "--category",
+        "command-line-utilities",
+        "--yes",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(
+        manifest.contains("keywords = [\"cli\", \"scaffold\"]"),
+        "{manifest}"
+    );
+    assert!(
+        manifest.contains("categories = [\"command-line-utilities\"]"),
+        "{manifest}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_new_rejects_too_many_keywords() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("wordy-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "wordy-app", "--yes"]);
+    for keyword in ["one", "two", "three", "four", "five", "six"] {
+        cmd.args(["--keyword", keyword]);
+    }
+    cmd.arg("--path").arg(&project);
+    assert_failure_with_error(&mut cmd, "crates.io allows at most 5");
+    assert!(!project.exists());
+    Ok(())
+}
+
+#[test]
+fn test_new_with_dependencies() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("seeded-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "seeded-app",
+        "--dep",
+        "serde@1",
+        "--dev-dep",
+        "proptest@>=1.4, <2",
+        "--yes",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert().success();
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    let (_, rest) = manifest
+        .split_once("[dependencies]\n")
+        .ok_or_else(|| anyhow::anyhow!("no [dependencies] in {manifest}"))?;
+    let (dependencies, dev_dependencies) = rest
+        .split_once("[dev-dependencies]\n")
+        .ok_or_else(|| anyhow::anyhow!("no [dev-dependencies] in {manifest}"))?;
+    assert!(dependencies.contains("serde = \"1\"\n"), "{manifest}");
+    assert!(
+        dev_dependencies.contains("proptest = \">=1.4, <2\"\n"),
+        "{manifest}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_new_cargo_add_falls_back_offline() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("offline-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "offline-app",
+        "--dep",
+        "serde@1",
+        "--cargo-add",
+        "--offline",
+        "--yes",
+        "--path",
+    ])
+    .arg(&project);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("instead of running cargo add"));
+
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    assert!(manifest.contains("serde = \"1\""), "{manifest}");
+    Ok(())
+}
+
+/// Run git in `dir`, failing the test with its stderr on error
+fn git(dir: &std::path::Path, args: &[&str]) -> Result<()> {
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_new_from_template_repo() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    // A bare repository on disk stands in for the remote
+    let temp = create_temp_project()?;
+    let work = temp.path().join("work");
+    std::fs::create_dir_all(work.join("templates/base"))?;
+    std::fs::create_dir_all(work.join("templates/binary/extended/src"))?;
+    std::fs::write(
+        work.join("templates/base/README.md.hbs"),
+        "# {{name}} from the org templates\n",
+    )?;
+    std::fs::write(
+        work.join("templates/binary/extended/src/main.rs.hbs"),
+        "fn main() {}\n",
+    )?;
+    git(&work, &["init", "--quiet", "--initial-branch=main"])?;
+    git(&work, &["add", "."])?;
+    git(&work, &["commit", "--quiet", "-m", "templates"])?;
+    git(
+        temp.path(),
+        &["clone", "--quiet", "--bare", "work", "remote.git"],
+    )?;
+    let url = format!("file://{}", temp.path().join("remote.git").display());
+
+    let project = temp.path().join("org-app");
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "new",
+        "org-app",
+        "--bin",
+        "--yes",
+        "--template-repo",
+        &url,
+        "--path",
+    ])
+    .arg(&project)
+    .env("XDG_CACHE_HOME", temp.path().join("cache"))
+    .env("HOME", temp.path());
+    cmd.assert().success();
+
+    let readme = std::fs::read_to_string(project.join("README.md"))?;
+    assert_eq!(readme, "# org-app from the org templates\n");
+    assert!(!project.join(".gitignore").exists());
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "other", "--yes", "--template-repo"])
+        .arg(format!(
+            "file://{}",
+            temp.path().join("missing.git").display()
+        ))
+        .arg("--path")
+        .arg(temp.path().join("other"))
+        .env("XDG_CACHE_HOME", temp.path().join("cache"))
+        .env("HOME", temp.path());
+    assert_failure_with_error(&mut cmd, "Failed to fetch templates");
+    assert!(!temp.path().join("other").exists());
+
+    // Offline, the cached clone is reused but nothing new is fetched
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "--offline",
+        "new",
+        "cached-app",
+        "--bin",
+        "--yes",
+        "--template-repo",
+        &url,
+    ])
+    .arg("--path")
+    .arg(temp.path().join("cached-app"))
+    .env("XDG_CACHE_HOME", temp.path().join("cache"))
+    .env("HOME", temp.path());
+    cmd.assert().success();
+
+    let mut cmd = create_test_command()?;
+    cmd.args([
+        "--offline",
+        "new",
+        "fresh-app",
+        "--yes",
+        "--refresh-templates",
+        "--template-repo",
+        &url,
+    ])
+    .arg("--path")
+    .arg(temp.path().join("fresh-app"))
+    .env("XDG_CACHE_HOME", temp.path().join("cache"))
+    .env("HOME", temp.path());
+    assert_failure_with_error(&mut cmd, "cloning is disabled while offline");
+    assert!(!temp.path().join("fresh-app").exists());
+
+    let cache_root = temp.path().join("cache/cargo-quickstart/templates");
+    assert!(cache_root.is_dir());
+    let mut cmd = create_test_command()?;
+    cmd.args(["cache", "clear"])
+        .env("XDG_CACHE_HOME", temp.path().join("cache"))
+        .env("HOME", temp.path());
+    cmd.assert().success();
+    assert!(!cache_root.exists());
+    Ok(())
+}
+
+#[test]
+fn test_new_from_cargo_generate_template() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let templates = temp.path().join("cargo-generate");
+    std::fs::create_dir_all(&templates)?;
+    std::fs::write(
+        templates.join("cargo-generate.toml"),
+        "[placeholders.gh-username]\ntype = \"string\"\nprompt = \"GitHub username?\"\n",
+    )?;
+    std::fs::write(
+        templates.join("README.md"),
+        "# {{project-name}} by {{gh-username}}\n",
+    )?;
+
+    let project = temp.path().join("cg-app");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "cg-app", "--yes", "--define", "gh-username=octocat"])
+        .arg("--path")
+        .arg(&project)
+        .env("CARGO_QUICKSTART_TEMPLATES", &templates);
+    cmd.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(project.join("README.md"))?,
+        "# cg-app by octocat\n"
+    );
+
+    // Without --define there is no value for the placeholder
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "other", "--yes", "--path"])
+        .arg(temp.path().join("other"))
+        .env("CARGO_QUICKSTART_TEMPLATES", &templates);
+    assert_failure_with_error(&mut cmd, "gh-username");
+    assert!(!temp.path().join("other").exists());
+    Ok(())
+}
+
+/// Every file under `dir`, relative to `root`
+fn files_under(root: &std::path::Path, dir: &std::path::Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(files_under(root, &path)?);
+        } else {
+            files.push(path.strip_prefix(root)?.to_string_lossy().into_owned());
+        }
+    }
+    Ok(files)
+}
+
+#[test]
+fn test_new_emits_manifest() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("listed");
+    let manifest_path = temp.path().join("manifest.json");
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "listed", "--lib", "--yes", "--emit-manifest"])
+        .arg(&manifest_path)
+        .arg("--path")
+        .arg(&project);
+    cmd.assert().success();
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&manifest_path)?)?;
+    let mut listed: Vec<String> = manifest["files"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("manifest has no files array"))?
+        .iter()
+        .filter_map(|file| file.as_str().map(str::to_string))
+        .collect();
+    listed.sort();
+    let mut written = files_under(&project, &project)?;
+    written.sort();
+
+    assert!(!listed.is_empty());
+    assert_eq!(listed, written);
+    assert_eq!(manifest["path"], project.display().to_string());
+    assert_eq!(manifest["git_initialized"], false);
+    assert_eq!(manifest["config"]["name"], "listed");
+    assert_eq!(manifest["config"]["project_type"], "library");
+    Ok(())
+}
+
+#[test]
+fn test_new_quiet_prints_nothing_on_success() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("silent");
+    let mut cmd = create_test_command()?;
+    cmd.env_remove("RUST_LOG")
+        .args(["--quiet", "new", "silent", "--bin", "--yes", "--path"])
+        .arg(&project);
+    cmd.assert().success().stdout("").stderr("");
+    assert_file_exists(&project, "Cargo.toml");
+
+    let mut cmd = create_test_command()?;
+    cmd.env_remove("RUST_LOG")
+        .args(["new", "loud", "--bin", "--yes", "--path"])
+        .arg(temp.path().join("loud"));
+    cmd.assert().success().stdout(predicates::str::contains(
+        "Successfully generated project: loud",
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_new_json_output() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let project = temp.path().join("scripted");
+    let mut cmd = create_test_command()?;
+    cmd.env_remove("RUST_LOG")
+        .args([
+            "new", "scripted", "--lib", "--yes", "--format", "json", "--path",
+        ])
+        .arg(&project);
+    let output = cmd.assert().success().get_output().stdout.clone();
+
+    // stdout holds nothing but the report
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["config"]["name"], "scripted");
+    assert_eq!(report["path"], project.display().to_string());
+    let files = report["files"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("report has no files array"))?;
+    assert!(files.iter().any(|file| file == "src/lib.rs"));
+    assert_file_exists(&project, "src/lib.rs");
+    Ok(())
+}
+
+#[test]
+fn test_new_exit_code_by_failure_kind() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+
+    // Invalid configuration
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "lib-only", "--lib", "--bin-name", "tool", "--yes"])
+        .arg("--path")
+        .arg(temp.path().join("lib-only"));
+    cmd.assert().code(2).stderr(predicates::str::contains(
+        "--bin-name needs a binary target",
+    ));
+
+    // Templates that cannot be found
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "no-templates", "--yes", "--path"])
+        .arg(temp.path().join("no-templates"))
+        .env("CARGO_QUICKSTART_TEMPLATES", temp.path().join("missing"));
+    cmd.assert().code(3);
+
+    // A target directory in the way
+    let mut cmd = create_test_command()?;
+    cmd.args(["new", "taken", "--yes", "--path"])
+        .arg(temp.path());
+    cmd.assert()
+        .code(5)
+        .stderr(predicates::str::contains("already exists"));
+    Ok(())
+}
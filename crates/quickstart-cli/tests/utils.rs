@@ -11,7 +11,7 @@ pub fn create_test_command() -> Result<Command> {
         return Err(anyhow::anyhow!("Skipping command tests under Miri"));
     }
 
-    Command::cargo_bin("cargo-quickstart").context("Failed to find cargo-quickstart binary")
+    Ok(assert_cmd::cargo::cargo_bin_cmd!("cargo-quickstart"))
 }
 
 /// Helper to create a temporary project for testing
@@ -24,6 +24,7 @@ pub fn create_temp_project() -> Result<tempfile::TempDir> {
     tempfile::tempdir().context("Failed to create temporary directory")
 }
 
+#[allow(dead_code)]
 /// Helper to assert command success with expected output
 pub fn assert_success_with_output(cmd: &mut Command, expected: &str) {
     cmd.assert()
@@ -0,0 +1,75 @@
+//! Integration tests for the validate-templates command
+
+mod utils;
+use anyhow::Result;
+use utils::*;
+
+/// Write a templates tree with a single binary template into `dir`
+fn write_templates(dir: &std::path::Path, main_rs: &str) -> Result<std::path::PathBuf> {
+    let templates = dir.join("templates");
+    std::fs::create_dir_all(templates.join("binary/minimal/src"))?;
+    std::fs::write(templates.join("binary/minimal/src/main.rs.hbs"), main_rs)?;
+    Ok(templates)
+}
+
+#[test]
+fn test_validate_templates_valid_tree() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let templates = write_templates(temp.path(), "// {{name}}\nfn main() {}\n")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["validate-templates", "--path"]).arg(&templates);
+    assert_success_with_output(&mut cmd, "Checked 1 templates, no problems found");
+    Ok(())
+}
+
+#[test]
+fn test_validate_templates_broken_tree() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let templates = write_templates(temp.path(), "{{#if name}\n")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.args(["validate-templates", "--path"]).arg(&templates);
+    assert_failure_with_error(&mut cmd, "binary/minimal/src/main.rs.hbs");
+    Ok(())
+}
+
+#[test]
+fn test_validate_templates_lookup_error_keeps_suggestions() -> Result<()> {
+    // Skip under Miri
+    if cfg!(miri) {
+        eprintln!("Skipping integration test under Miri");
+        return Ok(());
+    }
+
+    let temp = create_temp_project()?;
+    let not_a_dir = temp.path().join("templates.txt");
+    std::fs::write(&not_a_dir, "")?;
+
+    let mut cmd = create_test_command()?;
+    cmd.arg("validate-templates")
+        .env("CARGO_QUICKSTART_TEMPLATES", &not_a_dir)
+        .env("NO_COLOR", "1");
+    let output = cmd.assert().failure().code(3).get_output().stderr.clone();
+    let stderr = String::from_utf8(output)?;
+
+    assert!(stderr.contains("which is not a directory"), "{stderr}");
+    assert!(stderr.contains("Suggestions:"), "{stderr}");
+    assert!(
+        stderr.contains("Reinstall cargo-quickstart to restore its bundled templates"),
+        "{stderr}"
+    );
+    Ok(())
+}
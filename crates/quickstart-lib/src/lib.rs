@@ -3,18 +3,57 @@
 //! Library core for cargo-quickstart: project generator logic
 
 use color_eyre::Result;
-use std::{fmt, path::PathBuf};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 pub mod template;
 pub mod tools;
 
-/// Project type (binary or library)
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Project type (binary, library, or both)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
 pub enum ProjectType {
     /// A binary application
+    #[cfg_attr(feature = "clap", value(alias = "bin"))]
     Binary,
     /// A library crate
+    #[cfg_attr(feature = "clap", value(alias = "lib"))]
     Library,
+    /// A library crate with a binary that depends on it
+    Both,
+}
+
+impl ProjectType {
+    /// Whether the project has a `src/main.rs`
+    pub const fn has_binary(self) -> bool {
+        matches!(self, ProjectType::Binary | ProjectType::Both)
+    }
+
+    /// Whether the project has a `src/lib.rs`
+    pub const fn has_library(self) -> bool {
+        matches!(self, ProjectType::Library | ProjectType::Both)
+    }
+}
+
+impl std::str::FromStr for ProjectType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "binary" | "bin" => Ok(ProjectType::Binary),
+            "library" | "lib" => Ok(ProjectType::Library),
+            "both" => Ok(ProjectType::Both),
+            other => Err(format!(
+                "Unknown project type '{other}' (expected 'binary', 'library' or 'both')"
+            )),
+        }
+    }
 }
 
 impl fmt::Display for ProjectType {
@@ -22,16 +61,72 @@ impl fmt::Display for ProjectType {
         match self {
             ProjectType::Binary => write!(f, "Binary application"),
             ProjectType::Library => write!(f, "Library crate"),
+            ProjectType::Both => write!(f, "Binary and library"),
+        }
+    }
+}
+
+/// A dependency to add to the generated `Cargo.toml`, written `name` or `name@version`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencySpec {
+    /// Crate name
+    pub name: String,
+    /// Version requirement; `*` when none was given
+    pub version: String,
+}
+
+impl std::str::FromStr for DependencySpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, version) = match s.split_once('@') {
+            Some((name, version)) => (name, version.trim()),
+            None => (s, "*"),
+        };
+        validate_package_name(name, "Dependency name")
+            .map_err(|e| format!("Invalid dependency '{s}': {e}"))?;
+
+        let valid_version = !version.is_empty()
+            && version
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || " .*^~=<>,+-".contains(c));
+        if !valid_version {
+            return Err(format!(
+                "Invalid dependency '{s}': '{version}' is not a version requirement"
+            ));
         }
+
+        Ok(Self {
+            name: name.to_string(),
+            version: version.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for DependencySpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
     }
 }
 
+/// What to do with a planned file that already exists in the project directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExistingFileAction {
+    /// Replace the file with the rendered template
+    Overwrite,
+    /// Keep the file and do not render the template
+    Skip,
+    /// Rename the file with a `.bak` suffix, then write the rendered template
+    Backup,
+}
+
 /// Configuration for scaffolding a new project
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ProjectConfig {
     /// Project name
     pub name: String,
-    /// Project type (binary or library)
+    /// Project type (binary, library, or both)
     pub project_type: ProjectType,
     /// Rust edition
     pub edition: String,
@@ -43,201 +138,2068 @@ pub struct ProjectConfig {
     pub path: PathBuf,
     /// Accept all defaults without prompting
     pub yes: bool,
+    /// Template variant to generate from
+    pub variant: template::TemplateVariant,
+    /// Render templates without writing anything to disk
+    pub dry_run: bool,
+    /// Short project description
+    pub description: Option<String>,
+    /// Author name, overriding the default
+    pub author_name: Option<String>,
+    /// Author email, overriding the default
+    pub author_email: Option<String>,
+    /// Optional features to include (see [`template::FEATURES`]); `None` uses the defaults
+    pub features: Option<Vec<String>>,
+    /// Toolchain channel to pin in `rust-toolchain.toml`, which also selects the
+    /// `toolchain` feature; `None` pins `stable` if that feature is selected
+    pub toolchain: Option<String>,
+    /// Write the license texts for `license` (see [`template::license_files`])
+    pub license_files: bool,
+    /// Templates directory to use instead of looking one up, e.g. fetched remote templates
+    pub templates_dir: Option<PathBuf>,
+    /// Values for the placeholders of a cargo-generate template, keyed by name
+    pub placeholders: BTreeMap<String, String>,
+    /// Name of the binary target when it should differ from `name`
+    pub bin_name: Option<String>,
+    /// Minimum supported Rust version written as `rust-version` in `Cargo.toml`
+    pub msrv: Option<String>,
+    /// crates.io keywords, at most [`MAX_KEYWORDS`]
+    pub keywords: Vec<String>,
+    /// crates.io category slugs, e.g. `command-line-utilities`
+    pub categories: Vec<String>,
+    /// Extra entries for `[dependencies]`
+    pub dependencies: Vec<DependencySpec>,
+    /// Extra entries for `[dev-dependencies]`
+    pub dev_dependencies: Vec<DependencySpec>,
+    /// What to do with planned files that already exist, keyed by path relative to
+    /// `path`; existing files not listed are overwritten, or backed up if `backup` is set
+    pub existing_files: BTreeMap<PathBuf, ExistingFileAction>,
+    /// Back up existing files without an entry in `existing_files` before overwriting them
+    pub backup: bool,
+}
+
+impl Default for ProjectConfig {
+    /// An unnamed binary project in the current directory with the CLI's defaults:
+    /// edition 2021, `MIT OR Apache-2.0` with license files, no git repository
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            project_type: ProjectType::Binary,
+            edition: "2021".to_string(),
+            license: "MIT OR Apache-2.0".to_string(),
+            git: false,
+            path: PathBuf::new(),
+            yes: false,
+            variant: template::TemplateVariant::default(),
+            dry_run: false,
+            description: None,
+            author_name: None,
+            author_email: None,
+            features: None,
+            toolchain: None,
+            license_files: true,
+            templates_dir: None,
+            placeholders: BTreeMap::new(),
+            bin_name: None,
+            msrv: None,
+            keywords: Vec::new(),
+            categories: Vec::new(),
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            existing_files: BTreeMap::new(),
+            backup: false,
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// The explicit feature selection, including `toolchain` when a channel is pinned
+    pub fn selected_features(&self) -> Option<Vec<String>> {
+        let mut features = self.features.clone();
+        if self.toolchain.is_some() {
+            let features = features.get_or_insert_with(|| {
+                template::FEATURES
+                    .iter()
+                    .filter(|feature| feature.default)
+                    .map(|feature| feature.name.to_string())
+                    .collect()
+            });
+            if !features.iter().any(|feature| feature == "toolchain") {
+                features.push("toolchain".to_string());
+            }
+        }
+        features
+    }
+}
+
+/// Rust editions offered by the CLI and wizards, with the default first
+pub const RUST_EDITIONS: [&str; 4] = ["2021", "2024", "2018", "2015"];
+
+/// Licenses offered by the CLI and wizards, with the default first
+pub const LICENSES: [&str; 6] = [
+    "MIT OR Apache-2.0",
+    "MIT",
+    "Apache-2.0",
+    "GPL-3.0",
+    "BSD-3-Clause",
+    "Unlicense",
+];
+
+/// Most keywords crates.io accepts for a crate
+pub const MAX_KEYWORDS: usize = 5;
+
+/// Crate names Cargo refuses because they clash with the standard library or test harness
+const RESERVED_CRATE_NAMES: [&str; 5] = ["alloc", "core", "proc_macro", "std", "test"];
+
+/// Names Cargo disallows because they collide with build artifact directories or Windows devices
+const DISALLOWED_CRATE_NAMES: [&str; 26] = [
+    "build",
+    "deps",
+    "examples",
+    "incremental",
+    "aux",
+    "con",
+    "nul",
+    "prn",
+    "com1",
+    "com2",
+    "com3",
+    "com4",
+    "com5",
+    "com6",
+    "com7",
+    "com8",
+    "com9",
+    "lpt1",
+    "lpt2",
+    "lpt3",
+    "lpt4",
+    "lpt5",
+    "lpt6",
+    "lpt7",
+    "lpt8",
+    "lpt9",
+];
+
+/// Rust keywords, including those reserved for future use
+const RUST_KEYWORDS: [&str; 52] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "union", "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+/// Validate `name` against Cargo's rules for package names
+pub fn validate_crate_name(name: &str) -> Result<(), String> {
+    validate_package_name(name, "Project name")
+}
+
+/// Validate `name` against Cargo's rules for package names, referring to it as
+/// `subject` in error messages
+fn validate_package_name(name: &str, subject: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(format!("{subject} cannot be empty"));
+    }
+
+    if name.contains(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '-') {
+        return Err(format!(
+            "{subject} must contain only alphanumeric characters, '-', or '_'"
+        ));
+    }
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(format!("{subject} must start with a letter"));
+    }
+
+    let normalized = name.replace('-', "_");
+    if RUST_KEYWORDS.contains(&normalized.as_str()) {
+        return Err(format!(
+            "`{name}` is a Rust keyword and cannot be used as a crate name"
+        ));
+    }
+
+    if RESERVED_CRATE_NAMES.contains(&normalized.as_str()) {
+        return Err(format!("`{name}` is a reserved crate name"));
+    }
+
+    if DISALLOWED_CRATE_NAMES.contains(&normalized.to_ascii_lowercase().as_str()) {
+        return Err(format!("`{name}` is not allowed by Cargo as a crate name"));
+    }
+
+    Ok(())
+}
+
+/// Validate `toolchain` as a rustup channel: `stable`, `beta` or `nightly` (optionally
+/// dated, e.g. `nightly-2024-01-01`), or a Rust version such as `1.75` or `1.75.0`
+pub fn validate_toolchain(toolchain: &str) -> Result<(), String> {
+    let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    let valid = match toolchain.split_once('-') {
+        Some((channel, date)) => {
+            ["stable", "beta", "nightly"].contains(&channel) && {
+                let parts: Vec<&str> = date.split('-').collect();
+                parts.len() == 3
+                    && parts.iter().all(|part| is_number(part))
+                    && parts[0].len() == 4
+                    && parts[1].len() == 2
+                    && parts[2].len() == 2
+            }
+        }
+        None if ["stable", "beta", "nightly"].contains(&toolchain) => true,
+        None => is_rust_version(toolchain),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{toolchain}` is not a valid toolchain: use stable, beta, nightly, a dated channel such as nightly-2024-01-01, or a version such as 1.75.0"
+        ))
+    }
+}
+
+/// Validate `msrv` as a `rust-version` Cargo accepts: `1.74` or `1.74.0`
+pub fn validate_msrv(msrv: &str) -> Result<(), String> {
+    if is_rust_version(msrv) {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{msrv}` is not a valid minimum Rust version: use a version such as 1.74 or 1.74.0"
+        ))
+    }
+}
+
+/// Whether `version` is a bare Rust version with two or three numeric parts
+fn is_rust_version(version: &str) -> bool {
+    let parts: Vec<&str> = version.split('.').collect();
+    (2..=3).contains(&parts.len())
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Validate `keyword` against crates.io's rules: up to 20 ASCII letters, digits, `_`,
+/// `-` or `+`, starting with a letter
+pub fn validate_keyword(keyword: &str) -> Result<(), String> {
+    let starts_with_letter = keyword.starts_with(|c: char| c.is_ascii_alphabetic());
+    let allowed = keyword
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'));
+
+    if starts_with_letter && allowed && keyword.len() <= 20 {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{keyword}` is not a valid keyword: use up to 20 ASCII letters, digits, `_`, `-` or `+`, starting with a letter"
+        ))
+    }
+}
+
+/// Validate a keyword list: each [keyword](validate_keyword), and no more than
+/// [`MAX_KEYWORDS`] of them
+pub fn validate_keywords(keywords: &[String]) -> Result<(), String> {
+    if keywords.len() > MAX_KEYWORDS {
+        return Err(format!(
+            "{} keywords given, but crates.io allows at most {MAX_KEYWORDS}",
+            keywords.len()
+        ));
+    }
+    keywords
+        .iter()
+        .try_for_each(|keyword| validate_keyword(keyword))
+}
+
+/// Validate `category` as a crates.io category slug such as `command-line-utilities`
+/// or `development-tools::cargo-plugins`
+///
+/// Only the format is checked, not that crates.io knows the category.
+pub fn validate_category(category: &str) -> Result<(), String> {
+    let valid = category.split("::").all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{category}` is not a valid category slug: use lowercase words joined by `-`, e.g. command-line-utilities"
+        ))
+    }
+}
+
+/// Validate `license` as one of [`LICENSES`] or a well-formed SPDX expression
+///
+/// Custom expressions are only checked for syntax (identifiers joined by `AND`/`OR`,
+/// optional `WITH` exceptions and parentheses), not against the SPDX license list.
+pub fn validate_license(license: &str) -> Result<(), String> {
+    if LICENSES.contains(&license) {
+        return Ok(());
+    }
+
+    let spaced = license.replace('(', " ( ").replace(')', " ) ");
+    let mut tokens = spaced.split_whitespace().peekable();
+    let invalid = || format!("`{license}` is not a valid SPDX license expression");
+
+    if parse_spdx_expression(&mut tokens) && tokens.next().is_none() {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Parse `term ((AND | OR) term)*`, returning whether it was well-formed
+fn parse_spdx_expression<'a>(
+    tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+) -> bool {
+    if !parse_spdx_term(tokens) {
+        return false;
+    }
+
+    while tokens.next_if(|t| *t == "AND" || *t == "OR").is_some() {
+        if !parse_spdx_term(tokens) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Parse `( expression )` or `identifier [WITH identifier]`
+fn parse_spdx_term<'a>(tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>) -> bool {
+    if tokens.next_if_eq(&"(").is_some() {
+        return parse_spdx_expression(tokens) && tokens.next() == Some(")");
+    }
+
+    if !tokens.next().is_some_and(is_spdx_identifier) {
+        return false;
+    }
+
+    tokens.next_if_eq(&"WITH").is_none() || tokens.next().is_some_and(is_spdx_identifier)
+}
+
+/// Whether `token` is a license or exception identifier such as `GPL-2.0-or-later` or `MIT+`
+fn is_spdx_identifier(token: &str) -> bool {
+    let id = token.strip_suffix('+').unwrap_or(token);
+
+    !id.is_empty()
+        && !matches!(id, "AND" | "OR" | "WITH")
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+/// Environment variable pointing at a templates directory to use instead of the defaults
+pub const TEMPLATES_ENV_VAR: &str = "CARGO_QUICKSTART_TEMPLATES";
+
+/// Locate a templates directory on disk
+///
+/// Lookup order: the `CARGO_QUICKSTART_TEMPLATES` environment variable, then the nearest
+/// `templates/` directory in or above the current directory.
+pub fn find_templates_dir() -> Result<PathBuf, std::io::Error> {
+    find_templates_dir_from(templates_env_dir(), &std::env::current_dir()?)?.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Could not find a 'templates/' directory in this or any parent directory.",
+        )
+    })
+}
+
+/// Create a loader for the templates to use
+///
+/// Uses [`find_templates_dir`], falling back to the templates bundled into the binary.
+pub fn find_template_loader() -> Result<template::TemplateLoader, std::io::Error> {
+    match find_templates_dir_from(templates_env_dir(), &std::env::current_dir()?)? {
+        Some(dir) => Ok(template::TemplateLoader::new(dir)),
+        None => {
+            log::debug!("No templates directory found, using bundled templates");
+            Ok(template::TemplateLoader::bundled())
+        }
+    }
+}
+
+/// Directory named by `CARGO_QUICKSTART_TEMPLATES`, ignoring an empty value
+fn templates_env_dir() -> Option<PathBuf> {
+    std::env::var_os(TEMPLATES_ENV_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// On-disk part of [`find_templates_dir`]: the explicit directory, or an upward walk from `start`
+fn find_templates_dir_from(
+    env_dir: Option<PathBuf>,
+    start: &Path,
+) -> Result<Option<PathBuf>, std::io::Error> {
+    if let Some(dir) = env_dir {
+        if !dir.is_dir() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "{TEMPLATES_ENV_VAR} is set to '{}', which is not a directory.",
+                    dir.display()
+                ),
+            ));
+        }
+        return Ok(Some(dir));
+    }
+
+    Ok(start
+        .ancestors()
+        .map(|dir| dir.join("templates"))
+        .find(|candidate| candidate.is_dir()))
+}
+
+/// List the templates to render for `config` as (template path, destination) pairs
+///
+/// Both paths are relative: templates to the templates directory, destinations to the
+/// project root. Partial-only templates and deselected features are left out, and
+/// license texts are appended unless `config.license_files` is off.
+fn planned_templates(
+    loader: &template::TemplateLoader,
+    config: &ProjectConfig,
+) -> Result<Vec<(String, PathBuf)>> {
+    let templates = loader.list_templates(config.project_type, config.variant)?;
+    let selected = config.selected_features();
+    // cargo-generate templates have neither partials nor features: every file is rendered
+    let cargo_generate = loader.format() == template::TemplateFormat::CargoGenerate;
+
+    let mut planned: Vec<(String, PathBuf)> = templates
+        .into_iter()
+        .filter(|template| cargo_generate || !template::TemplateLoader::is_partial(template))
+        .map(|template| {
+            let destination = loader.get_destination_path(&template, Path::new(""));
+            (loader.relative_path(&template), destination)
+        })
+        .filter(|(_, destination)| {
+            cargo_generate || template::is_path_selected(destination, selected.as_deref())
+        })
+        .collect();
+
+    let licenses = if config.license_files {
+        template::license_files(&config.license)
+    } else {
+        Vec::new()
+    };
+    for license in licenses {
+        if loader.template_exists(license.template) {
+            planned.push((license.template.to_string(), license.destination.into()));
+        } else {
+            log::debug!("License template {} not found, skipping", license.template);
+        }
+    }
+
+    Ok(planned)
+}
+
+/// List the files `generate_project` would create, relative to the project root
+pub fn plan_project(config: &ProjectConfig) -> Result<Vec<PathBuf>> {
+    let loader = config_template_loader(config)?;
+    let engine = config_template_engine(config, &loader)?;
+    let mut files: Vec<PathBuf> = planned_templates(&loader, config)?
+        .into_iter()
+        .map(|(_, destination)| engine.render_path(&destination))
+        .collect::<template::Result<_>>()?;
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+/// The files [`plan_project`] lists that already exist in the project directory
+pub fn existing_files(config: &ProjectConfig) -> Result<Vec<PathBuf>> {
+    let mut files = plan_project(config)?;
+    files.retain(|file| config.path.join(file).exists());
+    Ok(files)
+}
+
+/// Path an existing file is moved to by [`ExistingFileAction::Backup`]: `<file>.bak`,
+/// or `<file>.bak.1`, `<file>.bak.2`, ... so earlier backups are never replaced
+pub fn backup_path(path: &Path) -> PathBuf {
+    let with_suffix = |suffix: &str| {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(suffix);
+        PathBuf::from(name)
+    };
+
+    let first = with_suffix(".bak");
+    if !first.exists() {
+        return first;
+    }
+    (1..)
+        .map(|n| with_suffix(&format!(".bak.{n}")))
+        .find(|candidate| !candidate.exists())
+        .unwrap_or(first)
+}
+
+/// What [`generate_project`] created
+#[derive(Debug, Serialize)]
+pub struct GenerationReport {
+    /// Project root
+    pub path: PathBuf,
+    /// Files written, relative to `path`, in the order they were written
+    pub files: Vec<PathBuf>,
+    /// Existing files left untouched by [`ExistingFileAction::Skip`], relative to `path`
+    pub skipped: Vec<PathBuf>,
+    /// Whether a Git repository was initialised
+    pub git_initialized: bool,
+    /// Configuration the project was generated from
+    pub config: ProjectConfig,
+}
+
+/// Generate a new project based on the provided configuration
+pub fn generate_project(config: ProjectConfig) -> Result<GenerationReport> {
+    generate_project_with_progress(config, &|_, _, _| {})
+}
+
+/// Generate a new project, reporting each file as it is written
+///
+/// `progress` receives the file's path relative to the project root, its 1-based
+/// index and the total number of files. Dry runs write nothing and never call it.
+pub fn generate_project_with_progress(
+    config: ProjectConfig,
+    progress: &dyn Fn(&Path, usize, usize),
+) -> Result<GenerationReport> {
+    let loader = config_template_loader(&config)?;
+    generate_project_from(config, loader, progress)
+}
+
+/// Loader for `config.templates_dir` if set, otherwise [`find_template_loader`]
+fn config_template_loader(config: &ProjectConfig) -> Result<template::TemplateLoader> {
+    match &config.templates_dir {
+        Some(dir) => Ok(template::TemplateLoader::new(dir)),
+        None => find_template_loader()
+            .map_err(|source| template::TemplateError::LookupError { source }.into()),
+    }
+}
+
+/// Engine rendering `loader`'s templates with the variables for `config`
+///
+/// cargo-generate templates also get their built-in and placeholder variables.
+fn config_template_engine(
+    config: &ProjectConfig,
+    loader: &template::TemplateLoader,
+) -> Result<template::TemplateEngine> {
+    let mut variables = template::TemplateVariables::from_config(config);
+    let manifest = loader.cargo_generate_manifest()?;
+    if let Some(manifest) = &manifest {
+        variables.placeholders = manifest.variables(&variables, &config.placeholders)?;
+    }
+
+    Ok(template::TemplateEngine::new(variables))
+}
+
+/// Compile every template `loader` has for `config`, plus the `planned` ones, under
+/// their partial names, so any template can include another with e.g.
+/// `{{> base/_header.md}}`
+fn register_templates(
+    engine: &mut template::TemplateEngine,
+    loader: &template::TemplateLoader,
+    config: &ProjectConfig,
+    planned: &[(String, PathBuf)],
+) -> Result<()> {
+    let mut sources: std::collections::BTreeSet<String> = loader
+        .list_templates(config.project_type, config.variant)?
+        .iter()
+        .map(|template| loader.relative_path(template))
+        .collect();
+    sources.extend(planned.iter().map(|(rel_path, _)| rel_path.clone()));
+    for rel_path in &sources {
+        let template_content = loader.load_template(rel_path)?;
+        engine.register_template_file(rel_path, &template_content)?;
+    }
+    Ok(())
+}
+
+/// Render the files `generate_project` would write, in memory
+///
+/// Returns each file's path relative to the project root with its content, sorted by
+/// path. Nothing is written and `config.existing_files` is not consulted.
+pub fn render_project(config: &ProjectConfig) -> Result<Vec<(PathBuf, String)>> {
+    let loader = config_template_loader(config)?;
+    let mut engine = config_template_engine(config, &loader)?;
+    let templates = planned_templates(&loader, config)?;
+    register_templates(&mut engine, &loader, config, &templates)?;
+
+    let mut rendered = std::collections::BTreeMap::new();
+    for (rel_path, destination) in templates {
+        let destination = engine.render_path(&destination)?;
+        let mut content = Vec::new();
+        engine.render_named_to_writer(
+            template::TemplateLoader::partial_name(&rel_path),
+            &mut content,
+        )?;
+        rendered.insert(destination, String::from_utf8_lossy(&content).into_owned());
+    }
+
+    Ok(rendered.into_iter().collect())
+}
+
+/// Generate a project from the templates provided by `loader`
+fn generate_project_from(
+    config: ProjectConfig,
+    loader: template::TemplateLoader,
+    progress: &dyn Fn(&Path, usize, usize),
+) -> Result<GenerationReport> {
+    use template::TemplateLoader;
+
+    // Validate that the parent directory exists
+    if let Some(parent) = config.path.parent() {
+        if !parent.exists() {
+            return Err(color_eyre::eyre::eyre!(
+                "Parent directory '{}' does not exist. Please create it first.",
+                parent.display()
+            ));
+        }
+    }
+
+    // Create the template engine with the variables for this config
+    let mut engine = config_template_engine(&config, &loader)?;
+
+    // List the templates for this project type and selected features
+    let templates = planned_templates(&loader, &config)?;
+
+    // Create the output directory
+    if !config.dry_run {
+        std::fs::create_dir_all(&config.path)?;
+    }
+
+    register_templates(&mut engine, &loader, &config, &templates)?;
+
+    // Render each template
+    let total = templates.len();
+    let mut files = Vec::with_capacity(total);
+    let mut skipped = Vec::new();
+    for (index, (rel_path, destination)) in templates.into_iter().enumerate() {
+        // Templated file and directory names, e.g. `src/{{crate_name}}.rs`
+        let destination = engine.render_path(&destination)?;
+
+        // Dry runs still render, to surface template errors, but discard the output
+        if config.dry_run {
+            engine
+                .render_named_to_writer(TemplateLoader::partial_name(&rel_path), std::io::sink())?;
+            continue;
+        }
+
+        // Determine output path
+        let output_path = config.path.join(&destination);
+
+        // Apply the decision for a file that is already there
+        if output_path.exists() {
+            let default_action = if config.backup {
+                ExistingFileAction::Backup
+            } else {
+                ExistingFileAction::Overwrite
+            };
+            match config
+                .existing_files
+                .get(&destination)
+                .copied()
+                .unwrap_or(default_action)
+            {
+                ExistingFileAction::Skip => {
+                    log::debug!("Keeping existing {}", destination.display());
+                    skipped.push(destination);
+                    continue;
+                }
+                ExistingFileAction::Backup => {
+                    let backup = backup_path(&output_path);
+                    log::debug!(
+                        "Backing up {} to {}",
+                        output_path.display(),
+                        backup.display()
+                    );
+                    std::fs::rename(&output_path, backup)?;
+                }
+                ExistingFileAction::Overwrite => {}
+            }
+        }
+
+        // Create parent directories if needed
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Stream the rendered content into the file
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(output_path)?);
+        engine.render_named_to_writer(TemplateLoader::partial_name(&rel_path), &mut writer)?;
+        writer.flush()?;
+
+        progress(&destination, index + 1, total);
+        files.push(destination);
+    }
+
+    let report = GenerationReport {
+        path: config.path.clone(),
+        files,
+        skipped,
+        git_initialized: false,
+        config,
+    };
+
+    if report.config.dry_run {
+        return Ok(report);
+    }
+
+    // TODO: Initialize Git repository if requested
+    if report.config.git {
+        // git::init_repository(&report.config.path)?;
+    }
+
+    Ok(report)
+}
+
+/// Config type for backward compatibility
+#[derive(Debug)]
+pub struct Config {
+    pub name: String,
+    pub bin: bool,
+    pub lib: bool,
+    pub edition: String,
+    pub license: String,
+    pub git: bool,
+    pub path: PathBuf,
+    pub yes: bool,
 }
 
-/// Find the nearest `templates/` directory by walking up from the current directory.
-pub fn find_templates_dir() -> Result<PathBuf, std::io::Error> {
-    let mut dir = std::env::current_dir()?;
-    loop {
-        let candidate = dir.join("templates");
-        if candidate.is_dir() {
-            return Ok(candidate);
-        }
-        if !dir.pop() {
-            break;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use tempfile::tempdir;
+    use template::TemplateLoader;
+
+    #[test]
+    fn test_project_type_display() {
+        assert_eq!(ProjectType::Binary.to_string(), "Binary application");
+        assert_eq!(ProjectType::Library.to_string(), "Library crate");
+        assert_eq!(ProjectType::Both.to_string(), "Binary and library");
+    }
+
+    #[test]
+    fn test_project_type_from_str() {
+        assert_eq!("binary".parse(), Ok(ProjectType::Binary));
+        assert_eq!("bin".parse(), Ok(ProjectType::Binary));
+        assert_eq!("library".parse(), Ok(ProjectType::Library));
+        assert_eq!("lib".parse(), Ok(ProjectType::Library));
+        assert_eq!("both".parse(), Ok(ProjectType::Both));
+        assert!("app".parse::<ProjectType>().is_err());
+    }
+
+    #[test]
+    fn test_validate_crate_name_valid() {
+        for name in ["my-app", "my_lib", "app2", "a"] {
+            assert_eq!(validate_crate_name(name), Ok(()), "{name} should be valid");
+        }
+    }
+
+    #[test]
+    fn test_validate_crate_name_invalid_characters() {
+        assert!(validate_crate_name("").is_err());
+        assert!(validate_crate_name("bad@name").is_err());
+        assert!(validate_crate_name("1app").is_err());
+        assert!(validate_crate_name("-app").is_err());
+        // Non-ASCII letters are not accepted by Cargo
+        assert!(validate_crate_name("café").is_err());
+    }
+
+    #[test]
+    fn test_validate_crate_name_reserved() {
+        for name in ["test", "std", "core", "alloc", "proc_macro", "proc-macro"] {
+            let err = validate_crate_name(name).unwrap_err();
+            assert!(err.contains("reserved"), "{name}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_validate_crate_name_keywords() {
+        for name in ["self", "crate", "Self", "fn", "async", "gen"] {
+            let err = validate_crate_name(name).unwrap_err();
+            assert!(err.contains("keyword"), "{name}: {err}");
+        }
+        // Keywords only matter as whole names
+        assert_eq!(validate_crate_name("self-help"), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_crate_name_disallowed() {
+        for name in ["deps", "build", "CON", "nul", "com1", "lpt9"] {
+            let err = validate_crate_name(name).unwrap_err();
+            assert!(err.contains("not allowed"), "{name}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_validate_license_known() {
+        for license in LICENSES {
+            assert_eq!(validate_license(license), Ok(()), "{license}");
+        }
+    }
+
+    #[test]
+    fn test_validate_license_custom_expressions() {
+        for license in [
+            "MPL-2.0",
+            "GPL-2.0-or-later",
+            "LicenseRef-Proprietary",
+            "Apache-2.0 WITH LLVM-exception",
+            "(MIT OR Apache-2.0) AND Unicode-3.0",
+            "GPL-2.0+",
+        ] {
+            assert_eq!(validate_license(license), Ok(()), "{license}");
+        }
+    }
+
+    #[test]
+    fn test_validate_license_malformed() {
+        for license in [
+            "",
+            "MIT OR",
+            "OR MIT",
+            "MIT Apache-2.0",
+            "(MIT OR Apache-2.0",
+            "MIT WITH",
+            "MIT/Apache-2.0",
+            "not a license",
+        ] {
+            assert!(validate_license(license).is_err(), "{license}");
+        }
+    }
+
+    #[test]
+    fn test_validate_toolchain() {
+        for toolchain in [
+            "stable",
+            "beta",
+            "nightly",
+            "nightly-2024-01-01",
+            "1.75",
+            "1.75.0",
+        ] {
+            assert!(validate_toolchain(toolchain).is_ok(), "{toolchain}");
+        }
+        for toolchain in [
+            "",
+            "latest",
+            "1",
+            "1.75.0.1",
+            "v1.75",
+            "nightly-2024-1-1",
+            "1.x",
+        ] {
+            assert!(validate_toolchain(toolchain).is_err(), "{toolchain}");
+        }
+    }
+
+    #[test]
+    fn test_validate_keywords() {
+        for keyword in ["cli", "no_std", "web-framework", "c++", "a2"] {
+            assert!(validate_keyword(keyword).is_ok(), "{keyword}");
+        }
+        for keyword in [
+            "",
+            "2d",
+            "-cli",
+            "has space",
+            "café",
+            "twenty-one-characters",
+        ] {
+            assert!(validate_keyword(keyword).is_err(), "{keyword}");
+        }
+
+        let keywords =
+            |count: usize| -> Vec<String> { (0..count).map(|i| format!("keyword{i}")).collect() };
+        assert!(validate_keywords(&keywords(MAX_KEYWORDS)).is_ok());
+        let err = validate_keywords(&keywords(MAX_KEYWORDS + 1)).unwrap_err();
+        assert!(err.contains("at most 5"), "{err}");
+        assert!(validate_keywords(&["2d".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_validate_category() {
+        for category in ["command-line-utilities", "development-tools::cargo-plugins"] {
+            assert!(validate_category(category).is_ok(), "{category}");
+        }
+        for category in [
+            "",
+            "Command Line",
+            "development-tools::",
+            "web\"programming",
+        ] {
+            assert!(validate_category(category).is_err(), "{category}");
+        }
+    }
+
+    #[test]
+    fn test_dependency_spec_from_str() {
+        let spec: DependencySpec = "serde@1".parse().unwrap();
+        assert_eq!(spec.name, "serde");
+        assert_eq!(spec.version, "1");
+        assert_eq!(spec.to_string(), "serde@1");
+
+        let spec: DependencySpec = "anyhow".parse().unwrap();
+        assert_eq!(spec.version, "*");
+        assert_eq!(
+            "tokio@>=1.0, <2".parse::<DependencySpec>().unwrap().version,
+            ">=1.0, <2"
+        );
+
+        for spec in ["", "@1", "bad name@1", "serde@", "serde@\"1\""] {
+            assert!(spec.parse::<DependencySpec>().is_err(), "{spec}");
+        }
+
+        let err = "bad name@1".parse::<DependencySpec>().unwrap_err();
+        assert_eq!(
+            err,
+            "Invalid dependency 'bad name@1': Dependency name must contain only \
+             alphanumeric characters, '-', or '_'"
+        );
+    }
+
+    #[test]
+    fn test_validate_msrv() {
+        for msrv in ["1.74", "1.74.0"] {
+            assert!(validate_msrv(msrv).is_ok(), "{msrv}");
+        }
+        for msrv in [
+            "",
+            "stable",
+            "1",
+            "1.74.0.1",
+            "v1.74",
+            "1.74.0-beta",
+            "1..0",
+        ] {
+            assert!(validate_msrv(msrv).is_err(), "{msrv}");
+        }
+    }
+
+    #[test]
+    fn test_find_templates_dir_env_var() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let custom = tempdir().unwrap();
+        let start = tempdir().unwrap();
+        fs::create_dir(start.path().join("templates")).unwrap();
+
+        // The env var wins over a templates/ directory found by walking up
+        let found =
+            find_templates_dir_from(Some(custom.path().to_path_buf()), start.path()).unwrap();
+        assert_eq!(found, Some(custom.path().to_path_buf()));
+
+        let missing = custom.path().join("missing");
+        let err = find_templates_dir_from(Some(missing), start.path()).unwrap_err();
+        assert!(err.to_string().contains(TEMPLATES_ENV_VAR));
+    }
+
+    #[test]
+    fn test_find_templates_dir_walks_up() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let nested = dir.path().join("a/b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir(dir.path().join("templates")).unwrap();
+
+        let found = find_templates_dir_from(None, &nested).unwrap();
+        assert_eq!(found, Some(dir.path().join("templates")));
+    }
+
+    #[test]
+    fn test_find_templates_dir_falls_back_to_bundled() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        // Use a temp dir with no templates/ parent
+        let dir = tempdir().unwrap();
+        assert_eq!(find_templates_dir_from(None, dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_generate_project_from_bundled_templates() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("bundled-project");
+        let mut config = license_config(project.clone(), "MIT");
+        config.features = None;
+
+        generate_project_from(config, template::TemplateLoader::bundled(), &|_, _, _| {}).unwrap();
+
+        let manifest = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"feature-test\""), "{manifest}");
+        assert!(project.join("src/main.rs").is_file());
+        assert!(project.join("README.md").is_file());
+        assert!(project.join("LICENSE").is_file());
+    }
+
+    #[test]
+    fn test_config_struct_instantiation() {
+        let config = Config {
+            name: "foo".to_string(),
+            bin: true,
+            lib: false,
+            edition: "2021".to_string(),
+            license: "MIT".to_string(),
+            git: true,
+            path: PathBuf::from("/tmp/foo"),
+            yes: false,
+        };
+        assert_eq!(config.name, "foo");
+        assert!(config.bin);
+    }
+
+    #[test]
+    fn test_project_config_default() {
+        let config = ProjectConfig::default();
+        assert_eq!(config.project_type, ProjectType::Binary);
+        assert_eq!(config.edition, "2021");
+        assert_eq!(config.license, "MIT OR Apache-2.0");
+        assert!(config.license_files);
+        assert!(!config.git && !config.dry_run && !config.backup);
+        assert_eq!(config.selected_features(), None);
+    }
+
+    #[test]
+    fn test_template_flags_follow_variant() {
+        for (variant, minimal) in [
+            (template::TemplateVariant::Minimal, true),
+            (template::TemplateVariant::Extended, false),
+        ] {
+            let config = ProjectConfig {
+                name: "flags".to_string(),
+                variant,
+                ..Default::default()
+            };
+            let flags = template::TemplateVariables::from_config(&config).template;
+            assert_eq!(flags.is_minimal, minimal, "{variant:?}");
+            assert_eq!(flags.is_extended, !minimal, "{variant:?}");
+        }
+    }
+
+    #[test]
+    fn test_project_config_edge_cases() {
+        let config = ProjectConfig {
+            name: "".to_string(),
+            project_type: ProjectType::Library,
+            edition: "2015".to_string(),
+            license: "GPL-3.0".to_string(),
+            path: PathBuf::from("/tmp/empty"),
+            yes: true,
+            ..Default::default()
+        };
+        assert_eq!(config.name, "");
+        match config.project_type {
+            ProjectType::Library => {}
+            _ => panic!("Expected Library variant"),
+        }
+        assert_eq!(config.edition, "2015");
+        assert_eq!(config.license, "GPL-3.0");
+        assert!(!config.git);
+        assert!(config.yes);
+    }
+
+    #[test]
+    fn test_generate_project_template_error() {
+        // Instead of relying on directory structure, we'll directly test the error case
+        // by creating a nonexistent path
+        let _nonexistent_path = PathBuf::from("/path/that/definitely/does/not/exist/templates");
+
+        // Create a result that mimics what find_templates_dir would return if no templates dir exists
+        let template_error = std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Could not find a 'templates/' directory in this or any parent directory.",
+        );
+        let result: Result<(), template::TemplateError> = Err(template::TemplateError::LoadError {
+            path: "templates".to_string(),
+            source: template_error,
+        });
+
+        // Verify the result is an error
+        assert!(result.is_err(), "Should error if templates/ dir is missing");
+
+        // Verify the error message is the one we expect
+        if let Err(e) = result {
+            assert!(
+                e.to_string()
+                    .contains("Could not find a 'templates/' directory"),
+                "Error should mention missing templates directory, got: {e}"
+            );
+        }
+    }
+
+    /// Create a minimal templates tree containing a CI workflow
+    fn write_feature_templates(templates_dir: &Path) {
+        let workflows = templates_dir.join("base/.github/workflows");
+        let binary = templates_dir.join("binary/extended/src");
+        fs::create_dir_all(&workflows).unwrap();
+        fs::create_dir_all(&binary).unwrap();
+
+        fs::write(templates_dir.join("base/README.md.hbs"), "# {{name}}\n").unwrap();
+        fs::write(workflows.join("ci.yml.hbs"), "name: {{name}}\n").unwrap();
+        fs::write(binary.join("main.rs.hbs"), "fn main() {}\n").unwrap();
+    }
+
+    fn feature_config(path: PathBuf, features: &[&str]) -> ProjectConfig {
+        ProjectConfig {
+            name: "feature-test".to_string(),
+            license: "MIT".to_string(),
+            path,
+            yes: true,
+            features: Some(features.iter().map(|f| f.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_project_from_config_templates_dir() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates = dir.path().join("custom");
+        fs::create_dir_all(templates.join("base")).unwrap();
+        fs::create_dir_all(templates.join("binary/extended/src")).unwrap();
+        fs::write(templates.join("base/README.md.hbs"), "# Custom {{name}}\n").unwrap();
+        fs::write(
+            templates.join("binary/extended/src/main.rs.hbs"),
+            "fn main() {}\n",
+        )
+        .unwrap();
+
+        let project = dir.path().join("project");
+        let config = ProjectConfig {
+            license_files: false,
+            templates_dir: Some(templates),
+            ..feature_config(project.clone(), &["readme"])
+        };
+        assert_eq!(
+            plan_project(&config).unwrap(),
+            vec![PathBuf::from("README.md"), PathBuf::from("src/main.rs")]
+        );
+
+        let report = generate_project(config).unwrap();
+        assert_eq!(
+            fs::read_to_string(project.join("README.md")).unwrap(),
+            "# Custom feature-test\n"
+        );
+
+        let mut files = report.files;
+        files.sort();
+        assert_eq!(
+            files,
+            vec![PathBuf::from("README.md"), PathBuf::from("src/main.rs")]
+        );
+        assert_eq!(report.path, project);
+        assert!(!report.git_initialized);
+    }
+
+    #[test]
+    fn test_generate_project_existing_file_actions() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates = dir.path().join("custom");
+        fs::create_dir_all(templates.join("base")).unwrap();
+        fs::create_dir_all(templates.join("binary/extended/src")).unwrap();
+        fs::write(templates.join("base/README.md.hbs"), "# {{name}}\n").unwrap();
+        fs::write(templates.join("base/CHANGELOG.md.hbs"), "# Changes\n").unwrap();
+        fs::write(
+            templates.join("binary/extended/src/main.rs.hbs"),
+            "fn main() {}\n",
+        )
+        .unwrap();
+
+        let project = dir.path().join("project");
+        fs::create_dir_all(project.join("src")).unwrap();
+        fs::write(project.join("README.md"), "mine\n").unwrap();
+        fs::write(project.join("CHANGELOG.md"), "old\n").unwrap();
+        fs::write(project.join("src/main.rs"), "// old\n").unwrap();
+
+        let config = ProjectConfig {
+            license_files: false,
+            templates_dir: Some(templates),
+            existing_files: BTreeMap::from([
+                (PathBuf::from("README.md"), ExistingFileAction::Skip),
+                (PathBuf::from("CHANGELOG.md"), ExistingFileAction::Backup),
+            ]),
+            ..feature_config(project.clone(), &["readme", "changelog"])
+        };
+        assert_eq!(
+            existing_files(&config).unwrap(),
+            vec![
+                PathBuf::from("CHANGELOG.md"),
+                PathBuf::from("README.md"),
+                PathBuf::from("src/main.rs")
+            ]
+        );
+
+        let report = generate_project(config).unwrap();
+        assert_eq!(report.skipped, vec![PathBuf::from("README.md")]);
+        assert_eq!(
+            fs::read_to_string(project.join("README.md")).unwrap(),
+            "mine\n"
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("CHANGELOG.md")).unwrap(),
+            "# Changes\n"
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("CHANGELOG.md.bak")).unwrap(),
+            "old\n"
+        );
+        // Files without a decision are overwritten, as before
+        assert_eq!(
+            fs::read_to_string(project.join("src/main.rs")).unwrap(),
+            "fn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_backup_path_avoids_collisions() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let readme = dir.path().join("README.md");
+        assert_eq!(backup_path(&readme), dir.path().join("README.md.bak"));
+
+        fs::write(dir.path().join("README.md.bak"), "").unwrap();
+        assert_eq!(backup_path(&readme), dir.path().join("README.md.bak.1"));
+
+        fs::write(dir.path().join("README.md.bak.1"), "").unwrap();
+        assert_eq!(backup_path(&readme), dir.path().join("README.md.bak.2"));
+    }
+
+    #[test]
+    fn test_generate_project_from_cargo_generate_template() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates = dir.path().join("cargo-generate");
+        fs::create_dir_all(templates.join("src")).unwrap();
+        fs::create_dir_all(templates.join(".github/workflows")).unwrap();
+        fs::create_dir_all(templates.join("hooks")).unwrap();
+        fs::write(
+            templates.join("cargo-generate.toml"),
+            r#"
+[template]
+ignore = ["hooks"]
+
+[placeholders.gh-username]
+type = "string"
+prompt = "GitHub username?"
+
+[placeholders.use_serde]
+type = "bool"
+prompt = "Use serde?"
+default = false
+"#,
+        )
+        .unwrap();
+        fs::write(
+            templates.join("Cargo.toml"),
+            "[package]\nname = \"{{project-name}}\"\nauthors = [\"{{authors}}\"]\n\
+             {{#if use_serde}}serde = \"1\"\n{{/if}}",
+        )
+        .unwrap();
+        fs::write(
+            templates.join("README.md.liquid"),
+            "# {{ project-name }} by {{gh-username}}\n",
+        )
+        .unwrap();
+        fs::write(templates.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(templates.join(".github/workflows/ci.yml"), "name: CI\n").unwrap();
+        fs::write(templates.join("hooks/pre.rhai"), "{% unsupported %}").unwrap();
+
+        let project = dir.path().join("project");
+        let config = ProjectConfig {
+            author_name: Some("Jane Doe".to_string()),
+            author_email: Some("jane@example.com".to_string()),
+            templates_dir: Some(templates),
+            placeholders: [("gh-username".to_string(), "octocat".to_string())].into(),
+            bin_name: None,
+            existing_files: Default::default(),
+            backup: false,
+            ..feature_config(project.clone(), &["readme"])
+        };
+        // Neither feature selection nor license texts apply to cargo-generate templates
+        assert_eq!(
+            plan_project(&config).unwrap(),
+            vec![
+                PathBuf::from(".github/workflows/ci.yml"),
+                PathBuf::from("Cargo.toml"),
+                PathBuf::from("README.md"),
+                PathBuf::from("src/main.rs"),
+            ]
+        );
+
+        generate_project(config).unwrap();
+        assert_eq!(
+            fs::read_to_string(project.join("Cargo.toml")).unwrap(),
+            "[package]\nname = \"feature-test\"\nauthors = [\"Jane Doe <jane@example.com>\"]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("README.md")).unwrap(),
+            "# feature-test by octocat\n"
+        );
+        assert!(!project.join("hooks").exists());
+        assert!(!project.join("cargo-generate.toml").exists());
+    }
+
+    #[test]
+    fn test_generate_project_from_cargo_generate_template_without_value() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates = dir.path().join("cargo-generate");
+        fs::create_dir_all(&templates).unwrap();
+        fs::write(
+            templates.join("cargo-generate.toml"),
+            "[placeholders.gh-username]\ntype = \"string\"\nprompt = \"GitHub username?\"\n",
+        )
+        .unwrap();
+        fs::write(templates.join("README.md"), "# {{gh-username}}\n").unwrap();
+
+        let project = dir.path().join("project");
+        let config = ProjectConfig {
+            templates_dir: Some(templates),
+            ..feature_config(project.clone(), &[])
+        };
+        let err = generate_project(config).unwrap_err().to_string();
+        assert!(err.contains("gh-username"), "{err}");
+        assert!(!project.exists());
+    }
+
+    #[test]
+    fn test_generate_project_with_ci_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let project = dir.path().join("project");
+
+        generate_project_from(
+            feature_config(project.clone(), &["readme", "ci"]),
+            TemplateLoader::new(templates_dir),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        assert!(project.join(".github/workflows/ci.yml").is_file());
+        assert!(project.join("README.md").is_file());
+        assert!(project.join("src/main.rs").is_file());
+    }
+
+    #[test]
+    fn test_generate_project_without_ci_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let project = dir.path().join("project");
+
+        generate_project_from(
+            feature_config(project.clone(), &["readme"]),
+            TemplateLoader::new(templates_dir),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        assert!(!project.join(".github").exists());
+        assert!(project.join("README.md").is_file());
+        assert!(project.join("src/main.rs").is_file());
+    }
+
+    #[test]
+    fn test_generate_project_ci_workflow() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        for edition in ["2021", "2024"] {
+            let project = dir.path().join(edition);
+            let config = ProjectConfig {
+                edition: edition.to_string(),
+                ..feature_config(project.clone(), &["ci"])
+            };
+            generate_project_from(
+                config,
+                TemplateLoader::new(repo_templates_dir()),
+                &|_, _, _| {},
+            )
+            .unwrap();
+
+            let workflow = fs::read_to_string(project.join(".github/workflows/ci.yml")).unwrap();
+            assert!(workflow.contains("cargo test"), "{workflow}");
+            assert!(
+                workflow.contains("cargo fmt --all -- --check"),
+                "{workflow}"
+            );
+            assert!(workflow.contains("cargo clippy"), "{workflow}");
+            assert!(
+                workflow.contains(&format!("Test feature-test (edition {edition}")),
+                "{workflow}"
+            );
+            assert!(workflow.contains("runs-on: ${{ matrix.os }}"), "{workflow}");
+            assert_eq!(
+                workflow.contains("\"1.85\""),
+                edition == "2024",
+                "{workflow}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_project_vscode_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("vscode");
+        generate_project_from(
+            feature_config(project.clone(), &["vscode"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let settings = fs::read_to_string(project.join(".vscode/settings.json")).unwrap();
+        let settings: serde_json::Value = serde_json::from_str(&settings).unwrap();
+        assert_eq!(
+            settings["editor.defaultFormatter"],
+            "rust-lang.rust-analyzer"
+        );
+
+        let extensions = fs::read_to_string(project.join(".vscode/extensions.json")).unwrap();
+        let extensions: serde_json::Value = serde_json::from_str(&extensions).unwrap();
+        assert!(extensions["recommendations"]
+            .as_array()
+            .unwrap()
+            .contains(&"rust-lang.rust-analyzer".into()));
+
+        let plain = dir.path().join("plain");
+        generate_project_from(
+            feature_config(plain.clone(), &[]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!plain.join(".vscode").exists());
+    }
+
+    #[test]
+    fn test_generate_project_pins_toolchain() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+
+        // A pinned channel selects the feature on top of the defaults
+        let pinned = dir.path().join("pinned");
+        let config = ProjectConfig {
+            features: None,
+            toolchain: Some("1.75.0".to_string()),
+            msrv: None,
+            keywords: Vec::new(),
+            categories: Vec::new(),
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            ..feature_config(pinned.clone(), &[])
+        };
+        generate_project_from(
+            config,
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        let toolchain = fs::read_to_string(pinned.join("rust-toolchain.toml")).unwrap();
+        assert!(toolchain.contains("channel = \"1.75.0\""), "{toolchain}");
+        assert!(pinned.join("README.md").is_file());
+
+        // Selecting the feature alone pins stable
+        let stable = dir.path().join("stable");
+        generate_project_from(
+            feature_config(stable.clone(), &["toolchain"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        let toolchain = fs::read_to_string(stable.join("rust-toolchain.toml")).unwrap();
+        assert!(toolchain.contains("channel = \"stable\""), "{toolchain}");
+
+        let plain = dir.path().join("plain");
+        generate_project_from(
+            feature_config(plain.clone(), &[]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!plain.join("rust-toolchain.toml").exists());
+    }
+
+    #[test]
+    fn test_generate_project_changelog_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("changelog");
+        generate_project_from(
+            feature_config(project.clone(), &["changelog"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let changelog = fs::read_to_string(project.join("CHANGELOG.md")).unwrap();
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert!(changelog.contains("## [Unreleased]"), "{changelog}");
+        assert!(
+            changelog.contains(&format!("## [0.1.0] - {today}")),
+            "{changelog}"
+        );
+
+        let plain = dir.path().join("plain");
+        generate_project_from(
+            feature_config(plain.clone(), &["readme"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!plain.join("CHANGELOG.md").exists());
+    }
+
+    #[test]
+    fn test_generate_project_editorconfig_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("editorconfig");
+        generate_project_from(
+            feature_config(project.clone(), &["editorconfig"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let editorconfig = fs::read_to_string(project.join(".editorconfig")).unwrap();
+        assert!(editorconfig.contains("indent_size = 4"), "{editorconfig}");
+        assert!(editorconfig.contains("end_of_line = lf"), "{editorconfig}");
+        assert!(
+            editorconfig.contains("trim_trailing_whitespace = true"),
+            "{editorconfig}"
+        );
+
+        let plain = dir.path().join("plain");
+        generate_project_from(
+            feature_config(plain.clone(), &["readme"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!plain.join(".editorconfig").exists());
+    }
+
+    fn license_config(path: PathBuf, license: &str) -> ProjectConfig {
+        ProjectConfig {
+            license: license.to_string(),
+            author_name: Some("Jane Doe".to_string()),
+            ..feature_config(path, &[])
         }
     }
-    Err(std::io::Error::new(
-        std::io::ErrorKind::NotFound,
-        "Could not find a 'templates/' directory in this or any parent directory.",
-    ))
-}
 
-/// Generate a new project based on the provided configuration
-pub fn generate_project(config: ProjectConfig) -> Result<()> {
-    use template::{TemplateEngine, TemplateLoader, TemplateVariables, TemplateVariant};
+    fn repo_templates_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../templates")
+    }
 
-    // Validate that the parent directory exists
-    if let Some(parent) = config.path.parent() {
-        if !parent.exists() {
-            return Err(color_eyre::eyre::eyre!(
-                "Parent directory '{}' does not exist. Please create it first.",
-                parent.display()
-            ));
+    #[test]
+    fn test_generate_project_dual_license_files() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
         }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("project");
+
+        generate_project_from(
+            license_config(project.clone(), "MIT OR Apache-2.0"),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let year = chrono::Datelike::year(&chrono::Local::now());
+        let mit = fs::read_to_string(project.join("LICENSE-MIT")).unwrap();
+        let apache = fs::read_to_string(project.join("LICENSE-APACHE")).unwrap();
+        assert!(mit.contains(&format!("Copyright (c) {year} Jane Doe")));
+        assert!(apache.contains("Apache License"));
+        assert!(apache.contains(&format!("Copyright {year} Jane Doe")));
+        assert!(!project.join("LICENSE").exists());
     }
 
-    // Initialize template variables from config
-    let variables = TemplateVariables::from_config(&config);
+    #[test]
+    fn test_generate_project_single_license_file() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("project");
 
-    // Create the template engine
-    let engine = TemplateEngine::new(variables);
+        generate_project_from(
+            license_config(project.clone(), "MIT"),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
 
-    // Smarter template path resolution: search upwards for templates/
-    let template_path = find_templates_dir()?;
-    let loader = TemplateLoader::new(template_path);
+        let license = fs::read_to_string(project.join("LICENSE")).unwrap();
+        assert!(license.starts_with("MIT License"));
+        assert!(license.contains("Jane Doe"));
+        assert!(!project.join("LICENSE-MIT").exists());
+        assert!(!project.join("LICENSE-APACHE").exists());
+    }
 
-    // Use extended template variant by default
-    let variant = TemplateVariant::Extended;
+    #[test]
+    fn test_generate_project_without_license_files() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
 
-    // List all templates for this project type
-    let templates = loader.list_templates(config.project_type, variant)?;
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("project");
+        let config = ProjectConfig {
+            license_files: false,
+            templates_dir: None,
+            placeholders: Default::default(),
+            bin_name: None,
+            existing_files: Default::default(),
+            backup: false,
+            ..license_config(project.clone(), "MIT OR Apache-2.0")
+        };
+        generate_project_from(
+            config,
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
 
-    // Create the output directory
-    std::fs::create_dir_all(&config.path)?;
+        assert!(project.join("Cargo.toml").is_file());
+        assert!(!project.join("LICENSE-MIT").exists());
+        assert!(!project.join("LICENSE-APACHE").exists());
+    }
 
-    // Process each template
-    for template_path in templates {
-        // Get relative path for loading template
-        let rel_path = pathdiff::diff_paths(&template_path, loader.base_path())
-            .unwrap_or_else(|| template_path.clone());
-        let rel_path_str = rel_path.to_string_lossy();
+    #[test]
+    fn test_generate_project_examples_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
 
-        // Load template content
-        let template_content = loader.load_template(&rel_path_str)?;
+        let dir = tempdir().unwrap();
+        for variant in [
+            template::TemplateVariant::Minimal,
+            template::TemplateVariant::Extended,
+        ] {
+            let project = dir.path().join(variant.name());
+            let config = ProjectConfig {
+                name: "my-lib".to_string(),
+                project_type: ProjectType::Library,
+                variant,
+                ..feature_config(project.clone(), &["examples"])
+            };
+            generate_project_from(
+                config,
+                TemplateLoader::new(repo_templates_dir()),
+                &|_, _, _| {},
+            )
+            .unwrap();
 
-        // Render the template
-        let rendered = engine.render_template(&template_content)?;
+            let example = fs::read_to_string(project.join("examples/basic.rs")).unwrap();
+            assert!(example.contains("use my_lib::add;"), "{example}");
+        }
 
-        // Determine output path
-        let output_path = loader.get_destination_path(&template_path, &config.path);
+        // Not generated unless selected, and never for binaries
+        let library = dir.path().join("library");
+        let config = ProjectConfig {
+            project_type: ProjectType::Library,
+            ..feature_config(library.clone(), &[])
+        };
+        generate_project_from(
+            config,
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!library.join("examples").exists());
 
-        // Create parent directories if needed
-        if let Some(parent) = output_path.parent() {
-            std::fs::create_dir_all(parent)?;
+        let binary = dir.path().join("binary");
+        generate_project_from(
+            feature_config(binary.clone(), &["examples"]),
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!binary.join("examples").exists());
+    }
+
+    #[test]
+    fn test_generate_project_benchmarks_feature() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
         }
 
-        // Write rendered content to file
-        std::fs::write(output_path, rendered)?;
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("benchmarks");
+        let config = ProjectConfig {
+            name: "my-lib".to_string(),
+            project_type: ProjectType::Library,
+            ..feature_config(project.clone(), &["benchmarks"])
+        };
+        generate_project_from(
+            config,
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let bench = fs::read_to_string(project.join("benches/benchmark.rs")).unwrap();
+        assert!(bench.contains("use my_lib::add;"), "{bench}");
+        let manifest = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("criterion = "), "{manifest}");
+        assert!(
+            manifest.contains("[[bench]]\nname = \"benchmark\""),
+            "{manifest}"
+        );
+
+        // Neither the bench nor the dependency without the feature
+        let plain = dir.path().join("plain");
+        let config = ProjectConfig {
+            project_type: ProjectType::Library,
+            ..feature_config(plain.clone(), &[])
+        };
+        generate_project_from(
+            config,
+            TemplateLoader::new(repo_templates_dir()),
+            &|_, _, _| {},
+        )
+        .unwrap();
+        assert!(!plain.join("benches").exists());
+        let manifest = fs::read_to_string(plain.join("Cargo.toml")).unwrap();
+        assert!(!manifest.contains("criterion"), "{manifest}");
     }
 
-    // TODO: Initialize Git repository if requested
-    if config.git {
-        // git::init_repository(&config.path)?;
+    #[test]
+    fn test_generate_project_branches_on_edition() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        for edition in ["2021", "2024"] {
+            let project = dir.path().join(edition);
+            let config = ProjectConfig {
+                edition: edition.to_string(),
+                ..feature_config(project.clone(), &[])
+            };
+            generate_project_from(
+                config,
+                TemplateLoader::new(repo_templates_dir()),
+                &|_, _, _| {},
+            )
+            .unwrap();
+
+            let manifest = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+            let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+            let is_2024 = edition == "2024";
+            assert!(manifest.contains(&format!("edition = \"{edition}\"")));
+            assert_eq!(manifest.contains("rust-version = \"1.85\""), is_2024);
+            assert_eq!(main.contains("println!(\"Hello, {name}!\")"), is_2024);
+            assert_eq!(main.contains("match cli.name"), !is_2024);
+        }
     }
 
-    println!("Successfully generated project: {}", config.name);
-    Ok(())
-}
+    #[test]
+    fn test_generate_project_with_partials() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
 
-/// Config type for backward compatibility
-#[derive(Debug)]
-pub struct Config {
-    pub name: String,
-    pub bin: bool,
-    pub lib: bool,
-    pub edition: String,
-    pub license: String,
-    pub git: bool,
-    pub path: PathBuf,
-    pub yes: bool,
-}
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        fs::write(
+            templates_dir.join("base/_header.md.hbs"),
+            "<!-- {{name}} header -->\n",
+        )
+        .unwrap();
+        fs::write(
+            templates_dir.join("base/README.md.hbs"),
+            "{{> base/_header.md}}\n# {{name}}\n",
+        )
+        .unwrap();
+        let project = dir.path().join("project");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq;
-    use std::fs;
-    use tempfile::tempdir;
+        generate_project_from(
+            feature_config(project.clone(), &["readme"]),
+            TemplateLoader::new(templates_dir),
+            &|_, _, _| {},
+        )
+        .unwrap();
+
+        let readme = fs::read_to_string(project.join("README.md")).unwrap();
+        assert_eq!(readme, "<!-- feature-test header -->\n# feature-test\n");
+        // Partial-only templates are not written out
+        assert!(!project.join("_header.md").exists());
+    }
 
     #[test]
-    fn test_project_type_display() {
-        assert_eq!(ProjectType::Binary.to_string(), "Binary application");
-        assert_eq!(ProjectType::Library.to_string(), "Library crate");
+    fn test_generate_project_reports_progress() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let project = dir.path().join("project");
+        let calls = std::cell::RefCell::new(Vec::new());
+
+        generate_project_from(
+            feature_config(project.clone(), &["readme", "ci"]),
+            TemplateLoader::new(templates_dir),
+            &|path, current, total| {
+                calls
+                    .borrow_mut()
+                    .push((path.to_path_buf(), current, total))
+            },
+        )
+        .unwrap();
+
+        let calls = calls.into_inner();
+        assert_eq!(
+            calls
+                .iter()
+                .map(|(_, current, total)| (*current, *total))
+                .collect::<Vec<_>>(),
+            vec![(1, 3), (2, 3), (3, 3)]
+        );
+        for (path, _, _) in &calls {
+            assert!(project.join(path).is_file(), "{}", path.display());
+        }
     }
 
     #[test]
-    fn test_find_templates_dir_error() {
+    fn test_render_project_matches_generated_files() {
         // Skip under Miri
         if cfg!(miri) {
             eprintln!("Skipping file system test under Miri");
             return;
         }
 
-        // Use a temp dir with no templates/ parent
         let dir = tempdir().unwrap();
-        let prev = std::env::current_dir().unwrap();
-        std::env::set_current_dir(dir.path()).unwrap();
-        let result = find_templates_dir();
-        std::env::set_current_dir(prev).unwrap();
-        assert!(result.is_err());
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let mut config = feature_config(dir.path().join("project"), &["readme"]);
+        config.templates_dir = Some(templates_dir);
+
+        let rendered = render_project(&config).unwrap();
+        assert!(!config.path.exists(), "render_project must not write");
+
+        let report = generate_project(config).unwrap();
+        let mut files = report.files.clone();
+        files.sort();
+        assert_eq!(
+            rendered
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>(),
+            files
+        );
+        for (path, content) in rendered {
+            assert_eq!(fs::read_to_string(report.path.join(path)).unwrap(), content);
+        }
     }
 
     #[test]
-    fn test_config_struct_instantiation() {
-        let config = Config {
-            name: "foo".to_string(),
-            bin: true,
-            lib: false,
-            edition: "2021".to_string(),
-            license: "MIT".to_string(),
-            git: true,
-            path: PathBuf::from("/tmp/foo"),
-            yes: false,
-        };
-        assert_eq!(config.name, "foo");
-        assert!(config.bin);
+    fn test_dry_run_reports_no_progress() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let mut config = feature_config(dir.path().join("project"), &["readme"]);
+        config.dry_run = true;
+        let calls = std::cell::Cell::new(0);
+
+        generate_project_from(config, TemplateLoader::new(templates_dir), &|_, _, _| {
+            calls.set(calls.get() + 1)
+        })
+        .unwrap();
+        assert_eq!(calls.get(), 0);
     }
 
     #[test]
-    fn test_project_config_edge_cases() {
+    fn test_generate_project_templated_paths() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        fs::write(
+            templates_dir.join("binary/extended/src/{{crate_name}}.rs.hbs"),
+            "pub const NAME: &str = \"{{name}}\";\n",
+        )
+        .unwrap();
+        let project = dir.path().join("project");
         let config = ProjectConfig {
-            name: "".to_string(),
-            project_type: ProjectType::Library,
-            edition: "2015".to_string(),
-            license: "GPL-3.0".to_string(),
-            git: false,
-            path: PathBuf::from("/tmp/empty"),
-            yes: true,
+            name: "my-crate".to_string(),
+            ..feature_config(project.clone(), &[])
         };
-        assert_eq!(config.name, "");
-        match config.project_type {
-            ProjectType::Library => {}
-            _ => panic!("Expected Library variant"),
-        }
-        assert_eq!(config.edition, "2015");
-        assert_eq!(config.license, "GPL-3.0");
-        assert!(!config.git);
-        assert!(config.yes);
+
+        generate_project_from(config, TemplateLoader::new(templates_dir), &|_, _, _| {}).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(project.join("src/my_crate.rs")).unwrap(),
+            "pub const NAME: &str = \"my-crate\";\n"
+        );
+        assert!(!project.join("src/{{crate_name}}.rs").exists());
     }
 
     #[test]
-    fn test_generate_project_template_error() {
-        // Instead of relying on directory structure, we'll directly test the error case
-        // by creating a nonexistent path
-        let _nonexistent_path = PathBuf::from("/path/that/definitely/does/not/exist/templates");
+    fn test_generate_project_rejects_empty_templated_name() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
 
-        // Create a result that mimics what find_templates_dir would return if no templates dir exists
-        let template_error = std::io::Error::new(
-            std::io::ErrorKind::NotFound,
-            "Could not find a 'templates/' directory in this or any parent directory.",
-        );
-        let result: Result<(), template::TemplateError> = Err(template::TemplateError::LoadError {
-            path: "templates".to_string(),
-            source: template_error,
-        });
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        let templated_dir = templates_dir.join("binary/extended/src/{{description}}");
+        fs::create_dir_all(&templated_dir).unwrap();
+        fs::write(templated_dir.join("mod.rs.hbs"), "\n").unwrap();
+        let config = ProjectConfig {
+            description: Some(String::new()),
+            ..feature_config(dir.path().join("project"), &[])
+        };
 
-        // Verify the result is an error
-        assert!(result.is_err(), "Should error if templates/ dir is missing");
+        let err = generate_project_from(config, TemplateLoader::new(templates_dir), &|_, _, _| {})
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid name"), "{err}");
+    }
 
-        // Verify the error message is the one we expect
-        if let Err(e) = result {
-            assert!(
-                e.to_string()
-                    .contains("Could not find a 'templates/' directory"),
-                "Error should mention missing templates directory, got: {e}"
-            );
+    #[test]
+    fn test_generate_project_render_error_names_template() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
         }
+
+        let dir = tempdir().unwrap();
+        let templates_dir = dir.path().join("templates");
+        write_feature_templates(&templates_dir);
+        fs::write(
+            templates_dir.join("base/README.md.hbs"),
+            "# {{name}}\n{{shout name}}\n",
+        )
+        .unwrap();
+
+        let err = generate_project_from(
+            feature_config(dir.path().join("project"), &["readme"]),
+            TemplateLoader::new(templates_dir),
+            &|_, _, _| {},
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("base/README.md.hbs:2:1"), "{err}");
     }
 
     #[test]
@@ -320,12 +2282,10 @@ mod tests {
         // Create config pointing to the file (not directory) as output path
         let config = ProjectConfig {
             name: "test-project".to_string(),
-            project_type: ProjectType::Binary,
-            edition: "2021".to_string(),
             license: "MIT".to_string(),
-            git: false,
             path: output_file,
             yes: true,
+            ..Default::default()
         };
 
         // This should fail because the output path is a file, not a directory
@@ -0,0 +1,20 @@
+//! Default templates embedded in the binary at build time
+
+use include_dir::{include_dir, Dir};
+
+/// The workspace `templates/` tree, as it was when the crate was built
+pub(crate) static BUNDLED_TEMPLATES: Dir<'static> =
+    include_dir!("$CARGO_MANIFEST_DIR/../../templates");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_templates_present() {
+        assert!(BUNDLED_TEMPLATES.get_file("base/Cargo.toml.hbs").is_some());
+        assert!(BUNDLED_TEMPLATES
+            .get_file("binary/minimal/src/main.rs.hbs")
+            .is_some());
+    }
+}
@@ -0,0 +1,340 @@
+//! Interop with the cargo-generate template format
+//!
+//! A templates directory containing `cargo-generate.toml` is a cargo-generate template:
+//! every file in it is rendered to the same path in the project, minus any `.liquid`
+//! suffix. `{{project-name}}`-style built-ins and the `[placeholders]` table become
+//! template variables. Only plain `{{ variable }}` substitutions are supported, not
+//! Liquid tags or filters.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Result, TemplateError, TemplateVariables};
+
+/// File that marks a directory as a cargo-generate template
+pub const MANIFEST_FILE: &str = "cargo-generate.toml";
+
+/// Paths never rendered from a cargo-generate template
+const ALWAYS_IGNORED: [&str; 2] = [MANIFEST_FILE, ".git"];
+
+/// The parts of `cargo-generate.toml` used for generation; other tables are ignored
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CargoGenerateManifest {
+    /// The `[template]` table
+    #[serde(default)]
+    pub template: TemplateSection,
+
+    /// Values to ask for, keyed by variable name
+    #[serde(default)]
+    pub placeholders: BTreeMap<String, Placeholder>,
+}
+
+/// The `[template]` table of `cargo-generate.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TemplateSection {
+    /// Files and directories, relative to the template root, that are not rendered
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+/// A value the template asks for, e.g. `[placeholders.gh-username]`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Placeholder {
+    /// Whether the value is a string or a bool
+    #[serde(rename = "type")]
+    pub kind: PlaceholderKind,
+
+    /// Question shown when asking for the value
+    pub prompt: String,
+
+    /// Value used when none is given
+    pub default: Option<PlaceholderValue>,
+
+    /// Allowed values of a string placeholder
+    pub choices: Option<Vec<String>>,
+}
+
+/// Type of a placeholder value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaceholderKind {
+    /// Free text, or one of `choices`
+    String,
+    /// `true` or `false`
+    Bool,
+}
+
+/// Value of a placeholder or built-in variable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PlaceholderValue {
+    /// A bool, usable in `{{#if}}` blocks
+    Bool(bool),
+    /// Any text
+    String(String),
+}
+
+impl fmt::Display for PlaceholderValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlaceholderValue::Bool(value) => write!(f, "{value}"),
+            PlaceholderValue::String(value) => f.write_str(value),
+        }
+    }
+}
+
+impl Placeholder {
+    /// Parse `raw` as a value of this placeholder, checking it against `choices`
+    pub fn parse(&self, raw: &str) -> std::result::Result<PlaceholderValue, String> {
+        match self.kind {
+            PlaceholderKind::Bool => raw
+                .parse()
+                .map(PlaceholderValue::Bool)
+                .map_err(|_| format!("'{raw}' is not true or false")),
+            PlaceholderKind::String => match &self.choices {
+                Some(choices) if !choices.iter().any(|choice| choice == raw) => {
+                    Err(format!("'{raw}' is not one of: {}", choices.join(", ")))
+                }
+                _ => Ok(PlaceholderValue::String(raw.to_string())),
+            },
+        }
+    }
+}
+
+impl CargoGenerateManifest {
+    /// Read `cargo-generate.toml` from `dir`, or `None` if `dir` is not a cargo-generate template
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(TemplateError::LoadError {
+                    path: path.display().to_string(),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| TemplateError::InvalidManifest {
+                path: path.display().to_string(),
+                reason: e.message().to_string(),
+            })
+    }
+
+    /// Whether `rel_path` (relative to the template root) is left out of the project
+    pub fn is_ignored(&self, rel_path: &Path) -> bool {
+        ALWAYS_IGNORED
+            .iter()
+            .copied()
+            .chain(self.template.ignore.iter().map(String::as_str))
+            .any(|ignored| rel_path.starts_with(ignored.trim_end_matches('/')))
+    }
+
+    /// Value of every placeholder: the one in `values`, else its default
+    ///
+    /// Entries of `values` that are not declared placeholders are kept as strings, so
+    /// templates can also use variables they do not prompt for.
+    pub fn placeholder_values(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, PlaceholderValue>> {
+        let mut resolved: BTreeMap<String, PlaceholderValue> = values
+            .iter()
+            .filter(|(name, _)| !self.placeholders.contains_key(*name))
+            .map(|(name, value)| (name.clone(), PlaceholderValue::String(value.clone())))
+            .collect();
+
+        for (name, placeholder) in &self.placeholders {
+            let invalid = |reason: String| TemplateError::InvalidPlaceholder {
+                name: name.clone(),
+                reason,
+            };
+            let value = match (values.get(name), &placeholder.default) {
+                (Some(raw), _) => placeholder.parse(raw).map_err(invalid)?,
+                (None, Some(default)) => {
+                    placeholder.parse(&default.to_string()).map_err(invalid)?
+                }
+                (None, None) => return Err(invalid("no value was given".to_string())),
+            };
+            resolved.insert(name.clone(), value);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Variables for rendering the template: cargo-generate's built-ins plus the
+    /// [placeholder values](Self::placeholder_values)
+    ///
+    /// `crate_name` is already one of our own variables, with the same meaning.
+    pub fn variables(
+        &self,
+        variables: &TemplateVariables,
+        values: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, PlaceholderValue>> {
+        let authors = match &variables.author.email {
+            Some(email) => format!("{} <{email}>", variables.author.name),
+            None => variables.author.name.clone(),
+        };
+        let crate_type = if variables.project.is_library {
+            "lib"
+        } else {
+            "bin"
+        };
+        let builtins = [
+            ("project-name", variables.name.clone()),
+            ("crate_type", crate_type.to_string()),
+            ("authors", authors),
+            (
+                "os-arch",
+                format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+            ),
+        ];
+
+        let mut resolved = self.placeholder_values(values)?;
+        for (name, value) in builtins {
+            resolved
+                .entry(name.to_string())
+                .or_insert(PlaceholderValue::String(value));
+        }
+        Ok(resolved)
+    }
+}
+
+/// Whether `dir` is a cargo-generate template
+pub fn is_cargo_generate_template(dir: &Path) -> bool {
+    dir.join(MANIFEST_FILE).is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn manifest(content: &str) -> CargoGenerateManifest {
+        toml::from_str(content).unwrap()
+    }
+
+    const PLACEHOLDERS: &str = r#"
+[template]
+ignore = ["hooks/", "notes.txt"]
+
+[placeholders.gh-username]
+type = "string"
+prompt = "GitHub username?"
+
+[placeholders.use_serde]
+type = "bool"
+prompt = "Use serde?"
+default = true
+
+[placeholders.license]
+type = "string"
+prompt = "License?"
+choices = ["MIT", "Apache-2.0"]
+default = "MIT"
+"#;
+
+    #[test]
+    fn test_is_ignored() {
+        let manifest = manifest(PLACEHOLDERS);
+        assert!(manifest.is_ignored(Path::new("cargo-generate.toml")));
+        assert!(manifest.is_ignored(Path::new(".git/HEAD")));
+        assert!(manifest.is_ignored(Path::new("hooks/pre.rhai")));
+        assert!(manifest.is_ignored(Path::new("notes.txt")));
+        assert!(!manifest.is_ignored(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_placeholder_values() {
+        let manifest = manifest(PLACEHOLDERS);
+        let values = BTreeMap::from([
+            ("gh-username".to_string(), "octocat".to_string()),
+            ("extra".to_string(), "value".to_string()),
+        ]);
+
+        let resolved = manifest.placeholder_values(&values).unwrap();
+        assert_eq!(
+            resolved,
+            BTreeMap::from([
+                (
+                    "extra".to_string(),
+                    PlaceholderValue::String("value".to_string())
+                ),
+                (
+                    "gh-username".to_string(),
+                    PlaceholderValue::String("octocat".to_string())
+                ),
+                (
+                    "license".to_string(),
+                    PlaceholderValue::String("MIT".to_string())
+                ),
+                ("use_serde".to_string(), PlaceholderValue::Bool(true)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_placeholder_values() {
+        let manifest = manifest(PLACEHOLDERS);
+        let with = |name: &str, value: &str| {
+            BTreeMap::from([
+                ("gh-username".to_string(), "octocat".to_string()),
+                (name.to_string(), value.to_string()),
+            ])
+        };
+
+        let missing = manifest.placeholder_values(&BTreeMap::new()).unwrap_err();
+        assert!(missing.to_string().contains("gh-username"), "{missing}");
+        assert!(manifest
+            .placeholder_values(&with("use_serde", "maybe"))
+            .is_err());
+        assert!(manifest
+            .placeholder_values(&with("license", "GPL-3.0"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_builtin_variables() {
+        let manifest = manifest("");
+        let variables = TemplateVariables::default_test_variables();
+
+        let resolved = manifest.variables(&variables, &BTreeMap::new()).unwrap();
+        assert_eq!(
+            resolved["project-name"],
+            PlaceholderValue::String("test-project".to_string())
+        );
+        assert_eq!(
+            resolved["crate_type"],
+            PlaceholderValue::String("bin".to_string())
+        );
+        assert_eq!(
+            resolved["authors"],
+            PlaceholderValue::String("Test Author <test@example.com>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_load_without_manifest() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        assert!(CargoGenerateManifest::load(dir.path()).unwrap().is_none());
+        assert!(!is_cargo_generate_template(dir.path()));
+
+        fs::write(dir.path().join(MANIFEST_FILE), "[placeholders.x]\n").unwrap();
+        assert!(is_cargo_generate_template(dir.path()));
+        assert!(CargoGenerateManifest::load(dir.path()).is_err());
+    }
+}
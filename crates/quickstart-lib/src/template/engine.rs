@@ -3,10 +3,17 @@
 //! This module provides the core template rendering functionality
 //! using Handlebars as the template engine.
 
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+
 use handlebars::Handlebars;
 
-use super::{Result, TemplateError, TemplateVariables};
-use crate::tools::{KebabCaseHelper, LowercaseHelper, SnakeCaseHelper, UppercaseHelper};
+use super::{Result, TemplateError, TemplateLoader, TemplateVariables};
+use crate::tools::{
+    DateFormatHelper, EqHelper, KebabCaseHelper, LowercaseHelper, NeHelper, PluralizeHelper,
+    SnakeCaseHelper, TomlStringHelper, TruncateHelper, UppercaseHelper,
+};
 
 /// Template engine for rendering project templates
 pub struct TemplateEngine {
@@ -14,6 +21,8 @@ pub struct TemplateEngine {
     handlebars: Handlebars<'static>,
     /// Template variables
     variables: TemplateVariables,
+    /// Source path of each template registered from a file, keyed by its registered name
+    sources: HashMap<String, String>,
 }
 
 impl TemplateEngine {
@@ -21,51 +30,145 @@ impl TemplateEngine {
     pub fn new(variables: TemplateVariables) -> Self {
         let mut handlebars = Handlebars::new();
 
-        // Configure handlebars
+        // Configure handlebars; nothing rendered here is HTML, so values are inserted
+        // verbatim and templates escape them for their format, e.g. `toml_string`
         handlebars.set_strict_mode(true);
+        handlebars.register_escape_fn(handlebars::no_escape);
 
         // Register helpers
         handlebars.register_helper("lowercase", Box::new(LowercaseHelper));
         handlebars.register_helper("uppercase", Box::new(UppercaseHelper));
         handlebars.register_helper("snake_case", Box::new(SnakeCaseHelper));
         handlebars.register_helper("kebab_case", Box::new(KebabCaseHelper));
+        handlebars.register_helper("eq", Box::new(EqHelper));
+        handlebars.register_helper("ne", Box::new(NeHelper));
+        handlebars.register_helper("pluralize", Box::new(PluralizeHelper));
+        handlebars.register_helper("date_format", Box::new(DateFormatHelper::default()));
+        handlebars.register_helper("truncate", Box::new(TruncateHelper));
+        handlebars.register_helper("toml_string", Box::new(TomlStringHelper));
 
         Self {
             handlebars,
             variables,
+            sources: HashMap::new(),
         }
     }
 
-    /// Register a template from a string
+    /// Register a template from a string, compiling it once for repeated `render_named` calls
     pub fn register_template(&mut self, name: &str, content: &str) -> Result<()> {
         self.handlebars
             .register_template_string(name, content)
-            .map_err(|e| TemplateError::RenderError {
-                name: name.to_string(),
-                source: e.into(),
+            .map_err(|e| {
+                let name = match e.pos() {
+                    Some((line, column)) => format!("{}:{line}:{column}", self.source_path(name)),
+                    None => self.source_path(name).to_string(),
+                };
+                TemplateError::RenderError {
+                    name,
+                    source: e.into(),
+                }
             })
     }
 
-    /// Render a template with the stored variables
-    pub fn render(&self, template_name: &str) -> Result<String> {
+    /// Register a template file under its partial name (see [`TemplateLoader::partial_name`])
+    ///
+    /// Render errors in this template are reported with `rel_path`.
+    pub fn register_template_file(&mut self, rel_path: &str, content: &str) -> Result<()> {
+        let name = TemplateLoader::partial_name(rel_path);
+        self.sources.insert(name.to_string(), rel_path.to_string());
+        self.register_template(name, content)
+    }
+
+    /// Render a registered template with the stored variables
+    pub fn render_named(&self, template_name: &str) -> Result<String> {
         self.handlebars
             .render(template_name, &self.variables)
+            .map_err(|e| self.render_error(template_name, e))
+    }
+
+    /// Render a registered template with the stored variables
+    #[deprecated(since = "0.1.5", note = "renamed to `render_named`")]
+    pub fn render(&self, template_name: &str) -> Result<String> {
+        self.render_named(template_name)
+    }
+
+    /// Render a registered template straight into `writer`
+    pub fn render_named_to_writer<W: Write>(&self, template_name: &str, writer: W) -> Result<()> {
+        self.handlebars
+            .render_to_write(template_name, &self.variables, writer)
+            .map_err(|e| self.render_error(template_name, e))
+    }
+
+    /// Render a template string directly, compiling it on every call
+    pub fn render_template(&self, template_content: &str) -> Result<String> {
+        self.handlebars
+            .render_template(template_content, &self.variables)
             .map_err(|e| TemplateError::RenderError {
-                name: template_name.to_string(),
+                name: "string_template".to_string(),
                 source: e,
             })
     }
 
-    /// Render a template string directly
-    pub fn render_template(&self, template_content: &str) -> Result<String> {
+    /// Render a template string straight into `writer` without building a `String`
+    pub fn render_to_writer<W: Write>(&self, template_content: &str, writer: W) -> Result<()> {
         self.handlebars
-            .render_template(template_content, &self.variables)
+            .render_template_to_write(template_content, &self.variables, writer)
             .map_err(|e| TemplateError::RenderError {
                 name: "string_template".to_string(),
                 source: e,
             })
     }
 
+    /// Render variables in the file and directory names of `path`
+    ///
+    /// Only components containing `{{` are rendered. Each must render to a single,
+    /// non-empty name, so a variable cannot add, remove or escape directories.
+    pub fn render_path(&self, path: &Path) -> Result<PathBuf> {
+        let invalid = |reason: String| TemplateError::InvalidPath {
+            path: path.display().to_string(),
+            reason,
+        };
+
+        path.components()
+            .map(|component| {
+                let Component::Normal(name) = component else {
+                    return Ok(component.as_os_str().to_os_string());
+                };
+                let Some(name) = name.to_str().filter(|name| name.contains("{{")) else {
+                    return Ok(name.to_os_string());
+                };
+
+                let rendered = self
+                    .render_template(name)
+                    .map_err(|e| invalid(e.to_string()))?;
+                if rendered.trim().is_empty() {
+                    return Err(invalid(format!("'{name}' renders to an empty name")));
+                }
+                if rendered == "." || rendered == ".." || rendered.contains(['/', '\\', '\0']) {
+                    return Err(invalid(format!("'{name}' renders to '{rendered}'")));
+                }
+                Ok(rendered.into())
+            })
+            .collect()
+    }
+
+    /// Source path for a registered template name, or the name itself
+    fn source_path<'a>(&'a self, name: &'a str) -> &'a str {
+        self.sources.get(name).map_or(name, String::as_str)
+    }
+
+    /// Wrap a Handlebars error with the failing template's path and position
+    ///
+    /// Errors raised inside a partial name the partial, not the template that included it.
+    fn render_error(&self, template_name: &str, e: handlebars::RenderError) -> TemplateError {
+        let path = self.source_path(e.template_name.as_deref().unwrap_or(template_name));
+        let name = match (e.line_no, e.column_no) {
+            (Some(line), Some(column)) => format!("{path}:{line}:{column}"),
+            _ => path.to_string(),
+        };
+        TemplateError::RenderError { name, source: e }
+    }
+
     /// Get a reference to the template variables
     pub fn variables(&self) -> &TemplateVariables {
         &self.variables
@@ -96,6 +199,27 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_values_are_not_html_escaped() {
+        let mut variables = TemplateVariables::default_test_variables();
+        variables.author.name = r#"Dan "The Man" O'Brien"#.to_string();
+        variables.description = Some("Parses <widgets> & \"gadgets\"".to_string());
+        let engine = TemplateEngine::new(variables);
+
+        assert_eq!(
+            engine
+                .render_template("{{author.name}}: {{description}}")
+                .unwrap(),
+            r#"Dan "The Man" O'Brien: Parses <widgets> & "gadgets""#
+        );
+        assert_eq!(
+            engine
+                .render_template(r#"authors = ["{{toml_string author.name}}"]"#)
+                .unwrap(),
+            r#"authors = ["Dan \"The Man\" O'Brien"]"#
+        );
+    }
+
     #[test]
     fn test_conditional_sections() {
         let mut variables = TemplateVariables::default_test_variables();
@@ -122,6 +246,167 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_edition_conditions() {
+        let template =
+            "{{#if project.is_edition_2024}}2024 idioms{{else}}edition {{edition}}{{/if}}";
+
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+        assert_eq!(engine.render_template(template).unwrap(), "edition 2021");
+
+        let mut variables = TemplateVariables::default_test_variables();
+        variables.edition = "2024".to_string();
+        variables.project.is_edition_2024 = true;
+        let engine = TemplateEngine::new(variables);
+        assert_eq!(engine.render_template(template).unwrap(), "2024 idioms");
+    }
+
+    #[test]
+    fn test_eq_helper_on_edition() {
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+
+        let template = r#"{{#if (eq edition "2021")}}2021{{else}}other{{/if}} {{#ne license "MIT"}}dual{{else}}mit{{/ne}}"#;
+        assert_eq!(engine.render_template(template).unwrap(), "2021 mit");
+    }
+
+    #[test]
+    fn test_date_format_helper_registered() {
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+
+        // default_test_variables uses a fixed timestamp in late April 2021
+        let result = engine.render_template(r#"{{date_format "%Y"}}"#).unwrap();
+        assert_eq!(result, "2021");
+    }
+
+    #[test]
+    fn test_render_to_writer_matches_render_template() {
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+        let template = "# {{name}}\n\n{{#if project.is_binary}}Binary{{/if}} by {{author.name}}";
+
+        let mut buffer = Vec::new();
+        engine.render_to_writer(template, &mut buffer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            engine.render_template(template).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render_to_writer_error() {
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+
+        let result = engine.render_to_writer("{{#if}", std::io::sink());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_render_named() {
+        let variables = TemplateVariables::default_test_variables();
+        let mut engine = TemplateEngine::new(variables);
+        let template = "# {{name}} ({{edition}})";
+        engine
+            .register_template("base/README.md", template)
+            .unwrap();
+
+        let expected = engine.render_template(template).unwrap();
+        assert_eq!(engine.render_named("base/README.md").unwrap(), expected);
+        #[allow(deprecated)]
+        let renamed = engine.render("base/README.md").unwrap();
+        assert_eq!(renamed, expected);
+
+        let mut buffer = Vec::new();
+        engine
+            .render_named_to_writer("base/README.md", &mut buffer)
+            .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_render_error_names_template_path() {
+        let variables = TemplateVariables::default_test_variables();
+        let mut engine = TemplateEngine::new(variables);
+        engine
+            .register_template_file(
+                "base/README.md.hbs",
+                "# {{name}}\n{{no_such_helper name}}\n",
+            )
+            .unwrap();
+
+        let err = engine.render_named("base/README.md").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("base/README.md.hbs:2:1"), "{message}");
+        assert!(message.contains("no_such_helper"), "{message}");
+    }
+
+    #[test]
+    fn test_render_error_in_partial_names_partial() {
+        let variables = TemplateVariables::default_test_variables();
+        let mut engine = TemplateEngine::new(variables);
+        engine
+            .register_template_file("base/_header.md.hbs", "{{no_such_helper name}}")
+            .unwrap();
+        engine
+            .register_template_file("base/README.md.hbs", "{{> base/_header.md}}")
+            .unwrap();
+
+        let err = engine.render_named("base/README.md").unwrap_err();
+        assert!(err.to_string().contains("base/_header.md.hbs"), "{err}");
+    }
+
+    #[test]
+    fn test_register_template_file_syntax_error_position() {
+        let variables = TemplateVariables::default_test_variables();
+        let mut engine = TemplateEngine::new(variables);
+        let err = engine
+            .register_template_file("base/Cargo.toml.hbs", "name = 1\n{{#if}")
+            .unwrap_err();
+        assert!(err.to_string().contains("base/Cargo.toml.hbs:2:"), "{err}");
+    }
+
+    #[test]
+    fn test_render_path() {
+        let variables = TemplateVariables::default_test_variables();
+        let engine = TemplateEngine::new(variables);
+
+        assert_eq!(
+            engine
+                .render_path(Path::new("/tmp/out/src/{{crate_name}}/mod.rs"))
+                .unwrap(),
+            PathBuf::from("/tmp/out/src/test_project/mod.rs")
+        );
+        assert_eq!(
+            engine.render_path(Path::new("src/main.rs")).unwrap(),
+            PathBuf::from("src/main.rs")
+        );
+    }
+
+    #[test]
+    fn test_render_path_rejects_invalid_names() {
+        let mut variables = TemplateVariables::default_test_variables();
+        variables.description = Some("a/b".to_string());
+        variables.version = String::new();
+        let engine = TemplateEngine::new(variables);
+
+        for path in [
+            "src/{{version}}/lib.rs",
+            "{{description}}.md",
+            "{{#if project.is_binary}}..{{/if}}/x",
+            "{{missing}}.rs",
+        ] {
+            let err = engine.render_path(Path::new(path)).unwrap_err();
+            assert!(
+                matches!(err, TemplateError::InvalidPath { .. }),
+                "{path}: {err}"
+            );
+        }
+    }
+
     #[test]
     fn test_register_template_invalid_syntax() {
         let variables = TemplateVariables::default_test_variables();
@@ -136,7 +421,7 @@ mod tests {
         let variables = TemplateVariables::default_test_variables();
         let engine = TemplateEngine::new(variables);
         // Try to render a template that was never registered
-        let result = engine.render("not_registered");
+        let result = engine.render_named("not_registered");
         assert!(result.is_err());
     }
 
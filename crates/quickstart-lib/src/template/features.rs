@@ -0,0 +1,164 @@
+//! Optional project features and the generated files each one controls
+
+use std::path::Path;
+
+/// An optional feature that can be toggled when generating a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feature {
+    /// Short identifier (e.g. `ci`)
+    pub name: &'static str,
+    /// Human-readable label shown in the wizard
+    pub label: &'static str,
+    /// Destination paths (files or directories) this feature emits
+    pub paths: &'static [&'static str],
+    /// Whether the feature is enabled when no explicit selection is made
+    pub default: bool,
+}
+
+/// All optional features, in the order they are offered
+pub const FEATURES: [Feature; 10] = [
+    Feature {
+        name: "readme",
+        label: "README.md",
+        paths: &["README.md"],
+        default: true,
+    },
+    Feature {
+        name: "gitignore",
+        label: ".gitignore",
+        paths: &[".gitignore"],
+        default: true,
+    },
+    Feature {
+        name: "contributing",
+        label: "CONTRIBUTING.md",
+        paths: &["CONTRIBUTING.md"],
+        default: true,
+    },
+    Feature {
+        name: "ci",
+        label: "CI configuration",
+        paths: &[".github"],
+        default: false,
+    },
+    Feature {
+        name: "vscode",
+        label: "VS Code configuration",
+        paths: &[".vscode"],
+        default: true,
+    },
+    Feature {
+        name: "benchmarks",
+        label: "benchmarks",
+        paths: &["benches"],
+        default: false,
+    },
+    Feature {
+        name: "examples",
+        label: "examples",
+        paths: &["examples"],
+        default: false,
+    },
+    Feature {
+        name: "toolchain",
+        label: "rust-toolchain.toml",
+        paths: &["rust-toolchain.toml"],
+        default: false,
+    },
+    Feature {
+        name: "changelog",
+        label: "CHANGELOG.md",
+        paths: &["CHANGELOG.md"],
+        default: true,
+    },
+    Feature {
+        name: "editorconfig",
+        label: ".editorconfig",
+        paths: &[".editorconfig"],
+        default: true,
+    },
+];
+
+/// Look up a feature by its name or wizard label
+pub fn find_feature(name_or_label: &str) -> Option<&'static Feature> {
+    FEATURES
+        .iter()
+        .find(|f| f.name == name_or_label || f.label == name_or_label)
+}
+
+/// Whether `feature` is enabled by an explicit selection, or by default without one
+pub fn is_feature_selected(feature: &Feature, selected: Option<&[String]>) -> bool {
+    match selected {
+        Some(selected) => selected.iter().any(|s| s == feature.name),
+        None => feature.default,
+    }
+}
+
+/// Whether a destination path (relative to the project root) should be generated
+///
+/// Paths not owned by any feature are always generated. With no explicit
+/// selection, only features enabled by default are included.
+pub fn is_path_selected(path: &Path, selected: Option<&[String]>) -> bool {
+    let Some(feature) = FEATURES
+        .iter()
+        .find(|f| f.paths.iter().any(|p| path.starts_with(p)))
+    else {
+        return true;
+    };
+
+    is_feature_selected(feature, selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_find_feature() {
+        assert_eq!(
+            find_feature("ci").map(|f| f.label),
+            Some("CI configuration")
+        );
+        assert_eq!(find_feature("CI configuration").map(|f| f.name), Some("ci"));
+        assert!(find_feature("docker").is_none());
+    }
+
+    #[test]
+    fn test_unowned_paths_are_always_selected() {
+        assert!(is_path_selected(Path::new("Cargo.toml"), None));
+        assert!(is_path_selected(Path::new("src/main.rs"), Some(&[])));
+    }
+
+    #[test]
+    fn test_ci_selection() {
+        let workflow = Path::new(".github/workflows/ci.yml");
+
+        assert!(!is_path_selected(workflow, None));
+        assert!(!is_path_selected(workflow, Some(&["readme".to_string()])));
+        assert!(is_path_selected(workflow, Some(&["ci".to_string()])));
+    }
+
+    #[test]
+    fn test_feature_selection() {
+        let benchmarks = find_feature("benchmarks").unwrap();
+        let vscode = find_feature("vscode").unwrap();
+
+        assert!(!is_feature_selected(benchmarks, None));
+        assert!(is_feature_selected(vscode, None));
+        assert!(is_feature_selected(
+            benchmarks,
+            Some(&["benchmarks".to_string()])
+        ));
+        assert!(!is_feature_selected(vscode, Some(&[])));
+    }
+
+    #[test]
+    fn test_default_features() {
+        assert!(is_path_selected(Path::new(".vscode/settings.json"), None));
+        assert!(!is_path_selected(
+            Path::new(".vscode/settings.json"),
+            Some(&["ci".to_string()])
+        ));
+    }
+}
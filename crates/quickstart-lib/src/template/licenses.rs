@@ -0,0 +1,79 @@
+//! License files generated for a project's SPDX license expression
+
+/// A license text template and the file it is rendered to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LicenseFile {
+    /// Template path relative to the templates directory
+    pub template: &'static str,
+    /// File name in the generated project
+    pub destination: &'static str,
+}
+
+const MIT_TEMPLATE: &str = "licenses/MIT.hbs";
+const APACHE_TEMPLATE: &str = "licenses/Apache-2.0.hbs";
+
+/// License files to generate for `license`
+///
+/// The `MIT OR Apache-2.0` dual license writes `LICENSE-MIT` and `LICENSE-APACHE`;
+/// single licenses with a bundled text write `LICENSE`. Anything else writes nothing.
+pub fn license_files(license: &str) -> Vec<LicenseFile> {
+    let single = |template| {
+        vec![LicenseFile {
+            template,
+            destination: "LICENSE",
+        }]
+    };
+
+    match license.trim() {
+        "MIT OR Apache-2.0" | "Apache-2.0 OR MIT" => vec![
+            LicenseFile {
+                template: MIT_TEMPLATE,
+                destination: "LICENSE-MIT",
+            },
+            LicenseFile {
+                template: APACHE_TEMPLATE,
+                destination: "LICENSE-APACHE",
+            },
+        ],
+        "MIT" => single(MIT_TEMPLATE),
+        "Apache-2.0" => single(APACHE_TEMPLATE),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn destinations(license: &str) -> Vec<&'static str> {
+        license_files(license)
+            .iter()
+            .map(|file| file.destination)
+            .collect()
+    }
+
+    #[test]
+    fn test_dual_license_files() {
+        assert_eq!(
+            destinations("MIT OR Apache-2.0"),
+            vec!["LICENSE-MIT", "LICENSE-APACHE"]
+        );
+        assert_eq!(
+            destinations("Apache-2.0 OR MIT"),
+            vec!["LICENSE-MIT", "LICENSE-APACHE"]
+        );
+    }
+
+    #[test]
+    fn test_single_license_files() {
+        assert_eq!(license_files("MIT")[0].template, MIT_TEMPLATE);
+        assert_eq!(license_files("Apache-2.0")[0].template, APACHE_TEMPLATE);
+        assert_eq!(destinations("MIT"), vec!["LICENSE"]);
+    }
+
+    #[test]
+    fn test_unknown_license_files() {
+        assert!(license_files("LicenseRef-Proprietary").is_empty());
+    }
+}
@@ -1,100 +1,212 @@
 //! Template loader implementation
 //!
 //! This module provides functionality for loading templates from the filesystem
-//! and managing template paths.
+//! or the templates bundled into the binary, and managing template paths.
 
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 
+use include_dir::{Dir, DirEntry};
+
 use crate::ProjectType;
 
+use super::bundled::BUNDLED_TEMPLATES;
+use super::cargo_generate::{is_cargo_generate_template, CargoGenerateManifest};
 use super::{Result, TemplateError, TemplateVariant};
 
+/// Where a loader reads its templates from
+enum TemplateSource {
+    /// A templates directory on disk
+    Disk,
+    /// The templates embedded at build time; paths are relative to its root
+    Embedded(&'static Dir<'static>),
+}
+
+/// Layout of a templates directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateFormat {
+    /// `base/` plus a directory per project type and variant, with `.hbs` templates
+    Quickstart,
+    /// A cargo-generate template: every file is rendered, see `cargo-generate.toml`
+    CargoGenerate,
+}
+
 /// Template loader for file-based templates
 pub struct TemplateLoader {
-    /// Base directory for templates
+    /// Base directory for templates (empty for bundled templates)
     base_path: PathBuf,
+    /// Backing storage for the templates
+    source: TemplateSource,
+    /// Layout of the templates
+    format: TemplateFormat,
 }
 
 impl TemplateLoader {
     /// Create a new template loader with the given base path
+    ///
+    /// A directory containing `cargo-generate.toml` is read as a cargo-generate template.
     pub fn new<P: AsRef<Path>>(base_path: P) -> Self {
+        let format = if is_cargo_generate_template(base_path.as_ref()) {
+            TemplateFormat::CargoGenerate
+        } else {
+            TemplateFormat::Quickstart
+        };
+
         Self {
             base_path: base_path.as_ref().to_path_buf(),
+            source: TemplateSource::Disk,
+            format,
+        }
+    }
+
+    /// Create a loader for the default templates bundled into the binary
+    pub fn bundled() -> Self {
+        Self {
+            base_path: PathBuf::new(),
+            source: TemplateSource::Embedded(&BUNDLED_TEMPLATES),
+            format: TemplateFormat::Quickstart,
+        }
+    }
+
+    /// Layout of the templates
+    pub fn format(&self) -> TemplateFormat {
+        self.format
+    }
+
+    /// The parsed `cargo-generate.toml` of a cargo-generate template, `None` otherwise
+    pub fn cargo_generate_manifest(&self) -> Result<Option<CargoGenerateManifest>> {
+        match self.format {
+            TemplateFormat::CargoGenerate => CargoGenerateManifest::load(&self.base_path),
+            TemplateFormat::Quickstart => Ok(None),
         }
     }
 
-    /// Load a template from the filesystem
+    /// Whether templates come from the bundled set rather than a directory
+    pub fn is_bundled(&self) -> bool {
+        matches!(self.source, TemplateSource::Embedded(_))
+    }
+
+    /// Load a template from the filesystem or the bundled set
     pub fn load_template(&self, template_path: &str) -> Result<String> {
-        let full_path = self.base_path.join(template_path);
-        fs::read_to_string(&full_path).map_err(|e| TemplateError::LoadError {
+        let load_error = |source| TemplateError::LoadError {
             path: template_path.to_string(),
-            source: e,
-        })
+            source,
+        };
+
+        match self.source {
+            TemplateSource::Disk => {
+                fs::read_to_string(self.base_path.join(template_path)).map_err(load_error)
+            }
+            TemplateSource::Embedded(dir) => {
+                let file = dir
+                    .get_file(template_path)
+                    .ok_or_else(|| load_error(io::ErrorKind::NotFound.into()))?;
+                file.contents_utf8().map(str::to_string).ok_or_else(|| {
+                    load_error(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "template is not valid UTF-8",
+                    ))
+                })
+            }
+        }
     }
 
     /// Check if a template exists at the given path
     pub fn template_exists(&self, template_path: &str) -> bool {
-        let full_path = self.base_path.join(template_path);
-        full_path.exists()
+        match self.source {
+            TemplateSource::Disk => self.base_path.join(template_path).exists(),
+            TemplateSource::Embedded(dir) => dir.get_file(template_path).is_some(),
+        }
+    }
+
+    /// Check if a template directory exists
+    fn dir_exists(&self, dir: &Path) -> bool {
+        match self.source {
+            TemplateSource::Disk => dir.exists(),
+            TemplateSource::Embedded(root) => root.get_dir(dir).is_some(),
+        }
     }
 
     /// List all templates applicable for a project type and variant
+    ///
+    /// A cargo-generate template has no types or variants: all of its files are listed.
     pub fn list_templates(
         &self,
         project_type: ProjectType,
         variant: TemplateVariant,
     ) -> Result<Vec<PathBuf>> {
-        // Build the directory path for this project type and variant
-        let type_dir = match project_type {
-            ProjectType::Binary => "binary",
-            ProjectType::Library => "library",
-        };
+        if let Some(manifest) = self.cargo_generate_manifest()? {
+            let mut templates = Vec::new();
+            self.collect_cargo_generate_files(&self.base_path, &manifest, &mut templates)?;
+            log::debug!("Found {} cargo-generate templates", templates.len());
+            return Ok(templates);
+        }
 
-        let variant_dir = match variant {
-            TemplateVariant::Minimal => "minimal",
-            TemplateVariant::Extended => "extended",
+        // Build the directory paths for this project type and variant; a project with
+        // both a binary and a library uses the templates of each
+        let type_dirs: &[&str] = match project_type {
+            ProjectType::Binary => &["binary"],
+            ProjectType::Library => &["library"],
+            ProjectType::Both => &["library", "binary"],
         };
 
-        let template_dir = self.base_path.join(type_dir).join(variant_dir);
+        let variant_dir = variant.name();
+
+        let template_dirs: Vec<PathBuf> = type_dirs
+            .iter()
+            .map(|type_dir| self.base_path.join(type_dir).join(variant_dir))
+            .collect();
         let base_dir = self.base_path.join("base");
 
-        println!("Template directory: {}", template_dir.display());
-        println!("Base directory: {}", base_dir.display());
+        log::debug!("Base directory: {}", base_dir.display());
 
-        // Return error if template directory doesn't exist
-        if !template_dir.exists() {
-            println!("Template directory does not exist!");
-            return Err(TemplateError::TemplateNotFound {
-                path: template_dir.to_string_lossy().to_string(),
-            });
+        // Return error if a template directory doesn't exist
+        for template_dir in &template_dirs {
+            log::debug!("Template directory: {}", template_dir.display());
+            if !self.dir_exists(template_dir) {
+                log::debug!("Template directory does not exist");
+                return Err(TemplateError::TemplateNotFound {
+                    path: template_dir.to_string_lossy().to_string(),
+                });
+            }
         }
 
         // Collect templates from base directory if it exists
-        let mut templates = if base_dir.exists() {
-            println!("Base directory exists, collecting templates...");
+        let mut templates = if self.dir_exists(&base_dir) {
             self.collect_templates_from_dir(&base_dir)?
         } else {
-            println!("Base directory does not exist!");
+            log::debug!("Base directory does not exist, skipping shared templates");
             Vec::new()
         };
 
-        // Collect templates from project type directory
-        println!("Collecting templates from project type directory...");
-        let type_templates = self.collect_templates_from_dir(&template_dir)?;
-        templates.extend(type_templates);
+        // Collect templates from project type directories
+        for template_dir in &template_dirs {
+            let type_templates = self.collect_templates_from_dir(template_dir)?;
+            templates.extend(type_templates);
+        }
 
-        println!("Found {} templates", templates.len());
+        log::debug!("Found {} templates", templates.len());
         for template in &templates {
-            println!("  - {}", template.display());
+            log::trace!("  - {}", template.display());
         }
 
         Ok(templates)
     }
 
     /// Recursively collect templates from a directory
-    #[allow(clippy::only_used_in_recursion)]
     fn collect_templates_from_dir(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        if let TemplateSource::Embedded(root) = self.source {
+            let embedded = root
+                .get_dir(dir)
+                .ok_or_else(|| TemplateError::TemplateNotFound {
+                    path: dir.to_string_lossy().to_string(),
+                })?;
+            let mut templates = Vec::new();
+            collect_embedded_templates(embedded, &mut templates);
+            return Ok(templates);
+        }
+
         if !dir.exists() {
             return Err(TemplateError::TemplateNotFound {
                 path: dir.to_string_lossy().to_string(),
@@ -131,12 +243,50 @@ impl TemplateLoader {
         Ok(templates)
     }
 
+    /// Recursively collect every file of a cargo-generate template that is not ignored
+    fn collect_cargo_generate_files(
+        &self,
+        dir: &Path,
+        manifest: &CargoGenerateManifest,
+        templates: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let load_error = |source| TemplateError::LoadError {
+            path: dir.to_string_lossy().to_string(),
+            source,
+        };
+
+        for entry in fs::read_dir(dir).map_err(load_error)? {
+            let path = entry.map_err(load_error)?.path();
+            let rel_path = path.strip_prefix(&self.base_path).unwrap_or(&path);
+            if manifest.is_ignored(rel_path) {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.collect_cargo_generate_files(&path, manifest, templates)?;
+            } else {
+                templates.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the destination path for a template
     pub fn get_destination_path(&self, template_path: &Path, dest_root: &Path) -> PathBuf {
         // Calculate the relative path from base_path to template_path
         let rel_path = pathdiff::diff_paths(template_path, &self.base_path)
             .unwrap_or_else(|| template_path.to_path_buf());
 
+        // cargo-generate templates mirror the project, only dropping `.liquid` suffixes
+        if self.format == TemplateFormat::CargoGenerate {
+            let dest_path = dest_root.join(rel_path);
+            return match dest_path.extension() {
+                Some(ext) if ext == "liquid" => dest_path.with_extension(""),
+                _ => dest_path,
+            };
+        }
+
         // If the template is from the 'base/' directory, strip 'base/' from the path
         let rel_path = rel_path
             .strip_prefix("base")
@@ -161,8 +311,8 @@ impl TemplateLoader {
             rel_path
         };
 
-        // Join with destination root to get final path
-        let dest_path = dest_root.join(rel_path);
+        // Join with destination root to get final path, turning `dot-` prefixes into dots
+        let dest_path = dest_root.join(Self::expand_dot_prefixes(&rel_path));
 
         // For template files (.hbs extension), remove the extension
         if let Some(ext) = dest_path.extension() {
@@ -174,10 +324,89 @@ impl TemplateLoader {
         dest_path
     }
 
+    /// Replace a leading `dot-` in each path component with `.`
+    ///
+    /// Lets templates avoid dotfiles in their own tree: `dot-gitignore.hbs` renders to `.gitignore`.
+    fn expand_dot_prefixes(path: &Path) -> PathBuf {
+        path.components()
+            .map(|component| {
+                let name = component.as_os_str();
+                match name.to_str().and_then(|name| name.strip_prefix("dot-")) {
+                    Some(rest) if !rest.is_empty() => format!(".{rest}").into(),
+                    _ => name.to_os_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Path of `template` relative to the templates directory, with `/` separators
+    pub fn relative_path(&self, template_path: &Path) -> String {
+        let rel_path = pathdiff::diff_paths(template_path, &self.base_path)
+            .unwrap_or_else(|| template_path.to_path_buf());
+
+        rel_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Name a template is registered under and included by as a partial: its relative
+    /// path without the `.hbs` extension (e.g. `base/_header`)
+    pub fn partial_name(rel_path: &str) -> &str {
+        rel_path.strip_suffix(".hbs").unwrap_or(rel_path)
+    }
+
+    /// Whether a template only exists to be included as a partial (its file name starts with `_`)
+    pub fn is_partial(template_path: &Path) -> bool {
+        template_path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('_'))
+    }
+
     /// Get the base path of the template loader
     pub fn base_path(&self) -> &Path {
         &self.base_path
     }
+
+    /// List the template names (variant directories) available for any project type
+    pub fn available_templates(&self) -> Vec<String> {
+        let type_dirs = ["binary", "library"];
+        let mut names: Vec<String> = match self.source {
+            TemplateSource::Disk => type_dirs
+                .iter()
+                .filter_map(|type_dir| fs::read_dir(self.base_path.join(type_dir)).ok())
+                .flatten()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                .collect(),
+            TemplateSource::Embedded(root) => type_dirs
+                .iter()
+                .filter_map(|type_dir| root.get_dir(type_dir))
+                .flat_map(Dir::dirs)
+                .filter_map(|dir| dir.path().file_name()?.to_str().map(str::to_string))
+                .collect(),
+        };
+
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+/// Recursively collect the `.hbs` files of an embedded directory
+fn collect_embedded_templates(dir: &Dir<'static>, templates: &mut Vec<PathBuf>) {
+    for entry in dir.entries() {
+        match entry {
+            DirEntry::Dir(sub_dir) => collect_embedded_templates(sub_dir, templates),
+            DirEntry::File(file) => {
+                if file.path().extension().is_some_and(|ext| ext == "hbs") {
+                    templates.push(file.path().to_path_buf());
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -259,6 +488,19 @@ mod tests {
         assert!(!loader.template_exists("nonexistent.hbs"));
     }
 
+    #[test]
+    fn test_partial_names() {
+        let loader = TemplateLoader::new("/templates");
+
+        let rel_path = loader.relative_path(Path::new("/templates/base/_header.md.hbs"));
+        assert_eq!(rel_path, "base/_header.md.hbs");
+        assert_eq!(TemplateLoader::partial_name(&rel_path), "base/_header.md");
+
+        assert!(TemplateLoader::is_partial(Path::new("base/_header.md.hbs")));
+        assert!(!TemplateLoader::is_partial(Path::new("base/README.md.hbs")));
+        assert!(!TemplateLoader::is_partial(Path::new("_dir/README.md.hbs")));
+    }
+
     #[test]
     fn test_list_templates() {
         // Skip under Miri
@@ -308,4 +550,130 @@ mod tests {
         // Should be /tmp/my-project/README.md (without .hbs extension)
         assert_eq!(dest_path, PathBuf::from("/tmp/my-project/README.md"));
     }
+
+    #[test]
+    fn test_available_templates() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping file system test under Miri");
+            return;
+        }
+
+        let temp_dir = create_test_template_dir();
+        fs::create_dir_all(temp_dir.path().join("library/web")).unwrap();
+        let loader = TemplateLoader::new(temp_dir.path());
+
+        assert_eq!(
+            loader.available_templates(),
+            vec![
+                "extended".to_string(),
+                "minimal".to_string(),
+                "web".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bundled_loader() {
+        let loader = TemplateLoader::bundled();
+        assert!(loader.is_bundled());
+        assert!(loader.template_exists("base/Cargo.toml.hbs"));
+        assert!(!loader.template_exists("base/missing.hbs"));
+        assert!(loader
+            .load_template("base/Cargo.toml.hbs")
+            .unwrap()
+            .contains("{{name}}"));
+        assert!(loader.load_template("base/missing.hbs").is_err());
+        assert_eq!(
+            loader.available_templates(),
+            vec!["extended".to_string(), "minimal".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_bundled_loader_list_templates() {
+        let loader = TemplateLoader::bundled();
+        let templates = loader
+            .list_templates(ProjectType::Binary, TemplateVariant::Minimal)
+            .unwrap();
+
+        let main = templates
+            .iter()
+            .find(|t| t.ends_with("main.rs.hbs"))
+            .unwrap();
+        assert_eq!(loader.relative_path(main), "binary/minimal/src/main.rs.hbs");
+        assert_eq!(
+            loader.get_destination_path(main, Path::new("/tmp/my-project")),
+            PathBuf::from("/tmp/my-project/src/main.rs")
+        );
+
+        let readme = templates
+            .iter()
+            .find(|t| t.ends_with("README.md.hbs"))
+            .unwrap();
+        assert_eq!(
+            loader.get_destination_path(readme, Path::new("")),
+            PathBuf::from("README.md")
+        );
+    }
+
+    #[test]
+    fn test_list_templates_for_both() {
+        let loader = TemplateLoader::bundled();
+        let templates = loader
+            .list_templates(ProjectType::Both, TemplateVariant::Minimal)
+            .unwrap();
+
+        let destinations: Vec<PathBuf> = templates
+            .iter()
+            .map(|t| loader.get_destination_path(t, Path::new("")))
+            .collect();
+        assert!(destinations.contains(&PathBuf::from("src/main.rs")));
+        assert!(destinations.contains(&PathBuf::from("src/lib.rs")));
+        assert!(destinations.contains(&PathBuf::from("Cargo.toml")));
+    }
+
+    #[test]
+    fn test_dot_prefix_rename() {
+        let loader = TemplateLoader::new("/templates");
+        let dest_root = Path::new("/tmp/my-project");
+        let dest = |template: &str| {
+            loader.get_destination_path(&Path::new("/templates").join(template), dest_root)
+        };
+
+        assert_eq!(
+            dest("base/dot-gitignore.hbs"),
+            PathBuf::from("/tmp/my-project/.gitignore")
+        );
+        assert_eq!(
+            dest("base/dot-github/workflows/ci.yml.hbs"),
+            PathBuf::from("/tmp/my-project/.github/workflows/ci.yml")
+        );
+        assert_eq!(
+            dest("binary/minimal/dot-cargo/config.toml.hbs"),
+            PathBuf::from("/tmp/my-project/.cargo/config.toml")
+        );
+    }
+
+    #[test]
+    fn test_names_without_dot_prefix_unchanged() {
+        let loader = TemplateLoader::new("/templates");
+        let dest_root = Path::new("/tmp/my-project");
+        let dest = |template: &str| {
+            loader.get_destination_path(&Path::new("/templates").join(template), dest_root)
+        };
+
+        assert_eq!(
+            dest("base/.gitignore.hbs"),
+            PathBuf::from("/tmp/my-project/.gitignore")
+        );
+        assert_eq!(
+            dest("base/my-dot-file.md.hbs"),
+            PathBuf::from("/tmp/my-project/my-dot-file.md")
+        );
+        assert_eq!(
+            dest("binary/minimal/src/main.rs.hbs"),
+            PathBuf::from("/tmp/my-project/src/main.rs")
+        );
+    }
 }
@@ -3,26 +3,58 @@
 //! This module handles loading, rendering, and managing templates for
 //! project scaffolding with variable substitution and conditional sections.
 
+mod bundled;
+mod cargo_generate;
 mod engine;
+mod features;
+mod licenses;
 mod loader;
+mod remote;
 mod variables;
 
+pub use cargo_generate::{
+    is_cargo_generate_template, CargoGenerateManifest, Placeholder, PlaceholderKind,
+    PlaceholderValue,
+};
 pub use engine::TemplateEngine;
-pub use loader::TemplateLoader;
+pub use features::{find_feature, is_feature_selected, is_path_selected, Feature, FEATURES};
+pub use licenses::{license_files, LicenseFile};
+pub use loader::{TemplateFormat, TemplateLoader};
+pub use remote::RemoteTemplates;
 pub use variables::TemplateVariables;
 
 /// Represents the variant of templates to use
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TemplateVariant {
     /// Essential files only
     Minimal,
     /// Full-featured setup with additional tooling
+    #[default]
     Extended,
 }
 
-impl Default for TemplateVariant {
-    fn default() -> Self {
-        Self::Extended
+impl TemplateVariant {
+    /// Directory name used for this variant in the templates tree
+    pub const fn name(self) -> &'static str {
+        match self {
+            TemplateVariant::Minimal => "minimal",
+            TemplateVariant::Extended => "extended",
+        }
+    }
+}
+
+impl std::str::FromStr for TemplateVariant {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "minimal" => Ok(TemplateVariant::Minimal),
+            "extended" => Ok(TemplateVariant::Extended),
+            other => Err(format!(
+                "Unknown template '{other}' (expected 'minimal' or 'extended')"
+            )),
+        }
     }
 }
 
@@ -47,12 +79,55 @@ pub enum TemplateError {
         source: handlebars::RenderError,
     },
 
+    /// The templates directory could not be located
+    #[error("{source}")]
+    LookupError {
+        /// Why the lookup failed
+        source: std::io::Error,
+    },
+
     /// Template was not found
     #[error("Template not found: {path}")]
     TemplateNotFound {
         /// Template path
         path: String,
     },
+
+    /// A templated file or directory name rendered to an unusable name
+    #[error("Template path {path} renders to an invalid name: {reason}")]
+    InvalidPath {
+        /// Destination path before rendering
+        path: String,
+        /// Why the rendered name was rejected
+        reason: String,
+    },
+
+    /// Templates could not be fetched from a remote repository
+    #[error("Failed to fetch templates from {url}: {reason}")]
+    FetchError {
+        /// Repository URL
+        url: String,
+        /// What went wrong
+        reason: String,
+    },
+
+    /// A template manifest such as `cargo-generate.toml` could not be parsed
+    #[error("Invalid template manifest {path}: {reason}")]
+    InvalidManifest {
+        /// Manifest path
+        path: String,
+        /// Parse error
+        reason: String,
+    },
+
+    /// A template placeholder has no usable value
+    #[error("Invalid value for placeholder '{name}': {reason}")]
+    InvalidPlaceholder {
+        /// Placeholder name
+        name: String,
+        /// Why no value could be used
+        reason: String,
+    },
 }
 
 /// Result type for template operations
@@ -76,6 +151,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_template_variant_names() {
+        for variant in [TemplateVariant::Minimal, TemplateVariant::Extended] {
+            assert_eq!(
+                variant
+                    .name()
+                    .parse::<TemplateVariant>()
+                    .map(TemplateVariant::name),
+                Ok(variant.name())
+            );
+        }
+        assert!("web".parse::<TemplateVariant>().is_err());
+    }
+
     #[test]
     fn test_template_error_variants() {
         // LoadError
@@ -97,6 +186,13 @@ mod tests {
             path: "baz".to_string(),
         };
         assert!(format!("{not_found}").contains("Template not found"));
+
+        // InvalidPath
+        let invalid = TemplateError::InvalidPath {
+            path: "src/{{name}}.rs".to_string(),
+            reason: "empty name".to_string(),
+        };
+        assert!(format!("{invalid}").contains("src/{{name}}.rs"));
     }
 
     #[test]
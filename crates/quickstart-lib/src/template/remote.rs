@@ -0,0 +1,376 @@
+//! Templates fetched from a remote git repository
+
+use super::{is_cargo_generate_template, Result, TemplateError};
+use crate::tools::fnv1a;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A git repository whose `templates/` directory is used for generation, or a
+/// cargo-generate template repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteTemplates {
+    /// Anything `git clone` accepts: an https/ssh URL or a `file://` path
+    pub url: String,
+    /// Branch or tag to check out; `None` uses the remote's default branch
+    pub reference: Option<String>,
+    /// Never clone; only an already cached copy can be used
+    pub offline: bool,
+}
+
+impl RemoteTemplates {
+    /// Templates from `url`, optionally pinned to a branch or tag
+    pub fn new(url: impl Into<String>, reference: Option<String>) -> Self {
+        Self {
+            url: url.into(),
+            reference,
+            offline: false,
+        }
+    }
+
+    /// Set whether cloning is forbidden, leaving only cached copies usable
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Directory under `cache_root` holding the clone of this repository and ref
+    pub fn cache_dir(&self, cache_root: &Path) -> PathBuf {
+        let key = match &self.reference {
+            Some(reference) => format!("{}#{reference}", self.url),
+            None => self.url.clone(),
+        };
+        cache_root.join(format!("{:016x}", fnv1a(key.as_bytes())))
+    }
+
+    /// Return the templates directory of the repository, cloning it into `cache_root`
+    /// unless a clone is already cached there
+    ///
+    /// Clones are shallow and land in the cache only once complete, so an interrupted
+    /// or failed fetch never leaves a half-populated entry behind.
+    pub fn fetch(&self, cache_root: &Path) -> Result<PathBuf> {
+        let dir = self.cache_dir(cache_root);
+        if !dir.is_dir() {
+            self.clone_into(&dir)?;
+        } else {
+            log::debug!("Using cached templates from {}", dir.display());
+        }
+
+        self.templates_in(&dir)
+    }
+
+    /// Like [`fetch`](Self::fetch), but always clone again
+    ///
+    /// The cached copy is only replaced once the new clone succeeded, so a failed
+    /// refresh (e.g. while offline) leaves it intact.
+    pub fn refetch(&self, cache_root: &Path) -> Result<PathBuf> {
+        let dir = self.cache_dir(cache_root);
+        self.clone_into(&dir)?;
+        self.templates_in(&dir)
+    }
+
+    /// The `templates/` directory of the clone in `dir`, or `dir` itself for a
+    /// cargo-generate template
+    fn templates_in(&self, dir: &Path) -> Result<PathBuf> {
+        if is_cargo_generate_template(dir) {
+            return Ok(dir.to_path_buf());
+        }
+
+        let templates = dir.join("templates");
+        if !templates.is_dir() {
+            return Err(self.fetch_error("the repository has no 'templates/' directory"));
+        }
+        Ok(templates)
+    }
+
+    /// Shallow-clone the repository into `dir`, replacing anything already there
+    fn clone_into(&self, dir: &Path) -> Result<()> {
+        if self.offline {
+            return Err(self.fetch_error(
+                "cloning is disabled while offline and no cached copy can be used; \
+                 fetch the templates once with network access",
+            ));
+        }
+
+        let io_error = |e: std::io::Error| self.fetch_error(e.to_string());
+
+        let parent = dir.parent().unwrap_or(dir);
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+        let staging = staging_dir(dir);
+        let _ = std::fs::remove_dir_all(&staging);
+
+        log::debug!("Cloning templates from {} into {}", self.url, dir.display());
+        let mut command = Command::new("git");
+        command.args(["clone", "--quiet", "--depth", "1"]);
+        if let Some(reference) = &self.reference {
+            command.args(["--branch", reference]);
+        }
+        let output = command
+            // Keep a URL starting with '-' from being parsed as an option
+            .arg("--")
+            .arg(&self.url)
+            .arg(&staging)
+            // Fail instead of prompting for credentials
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .output()
+            .map_err(|e| self.fetch_error(format!("could not run git: {e}")))?;
+
+        if !output.status.success() {
+            let _ = std::fs::remove_dir_all(&staging);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(self.fetch_error(format!(
+                "{}. Check the URL{} and your network connection",
+                stderr.trim(),
+                if self.reference.is_some() {
+                    " and ref"
+                } else {
+                    ""
+                }
+            )));
+        }
+
+        if dir.exists() {
+            std::fs::remove_dir_all(dir).map_err(io_error)?;
+        }
+        std::fs::rename(&staging, dir).map_err(io_error)
+    }
+
+    fn fetch_error(&self, reason: impl Into<String>) -> TemplateError {
+        TemplateError::FetchError {
+            url: self.url.clone(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Sibling of `dir` a clone is staged in before it is moved into place
+fn staging_dir(dir: &Path) -> PathBuf {
+    let mut name = dir.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".partial-{}", std::process::id()));
+    dir.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use tempfile::tempdir;
+
+    /// Run git in `dir`, panicking with its output on failure
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Commit a new README template to `main` of the repository built by
+    /// [`bare_templates_repo`] and push it to the bare remote
+    fn push_readme(root: &Path, content: &str) {
+        let work = root.join("work");
+        fs::write(work.join("templates/base/README.md.hbs"), content).unwrap();
+        git(&work, &["commit", "--quiet", "-am", "update"]);
+        git(&work, &["push", "--quiet", "../remote.git", "main"]);
+    }
+
+    /// Create a bare repository under `root` with a `templates/` tree on `main`, plus a
+    /// `v1` tag whose README template differs, and return its `file://` URL
+    fn bare_templates_repo(root: &Path) -> String {
+        let work = root.join("work");
+        fs::create_dir_all(work.join("templates/base")).unwrap();
+        fs::write(work.join("templates/base/README.md.hbs"), "# {{name}} v1\n").unwrap();
+        git(&work, &["init", "--quiet", "--initial-branch=main"]);
+        git(&work, &["add", "."]);
+        git(&work, &["commit", "--quiet", "-m", "v1"]);
+        git(&work, &["tag", "v1"]);
+        fs::write(work.join("templates/base/README.md.hbs"), "# {{name}} v2\n").unwrap();
+        git(&work, &["commit", "--quiet", "-am", "v2"]);
+
+        let bare = root.join("remote.git");
+        git(
+            root,
+            &["clone", "--quiet", "--bare", "work", bare.to_str().unwrap()],
+        );
+        format!("file://{}", bare.display())
+    }
+
+    #[test]
+    fn test_cache_dir_keyed_by_url_and_ref() {
+        let root = Path::new("/cache");
+        let main = RemoteTemplates::new("https://example.com/t.git", None);
+        let tagged = RemoteTemplates::new("https://example.com/t.git", Some("v1".to_string()));
+
+        assert_eq!(main.cache_dir(root), main.clone().cache_dir(root));
+        assert_ne!(main.cache_dir(root), tagged.cache_dir(root));
+        assert!(main.cache_dir(root).starts_with(root));
+    }
+
+    #[test]
+    fn test_fetch_from_local_bare_repo() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let url = bare_templates_repo(dir.path());
+        let cache = dir.path().join("cache");
+
+        let templates = RemoteTemplates::new(&url, None).fetch(&cache).unwrap();
+        assert!(templates.starts_with(&cache));
+        assert_eq!(
+            fs::read_to_string(templates.join("base/README.md.hbs")).unwrap(),
+            "# {{name}} v2\n"
+        );
+
+        let tagged = RemoteTemplates::new(&url, Some("v1".to_string()))
+            .fetch(&cache)
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(tagged.join("base/README.md.hbs")).unwrap(),
+            "# {{name}} v1\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_reuses_cache_and_refetch_updates_it() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let url = bare_templates_repo(dir.path());
+        let cache = dir.path().join("cache");
+        let remote = RemoteTemplates::new(url, None);
+        let readme = |templates: &Path| fs::read_to_string(templates.join("base/README.md.hbs"));
+
+        let templates = remote.fetch(&cache).unwrap();
+        assert_eq!(readme(&templates).unwrap(), "# {{name}} v2\n");
+
+        // A second run reuses the cached clone without seeing the new commit
+        push_readme(dir.path(), "# {{name}} v3\n");
+        let cached = remote.fetch(&cache).unwrap();
+        assert_eq!(cached, templates);
+        assert_eq!(readme(&cached).unwrap(), "# {{name}} v2\n");
+
+        let refreshed = remote.refetch(&cache).unwrap();
+        assert_eq!(refreshed, templates);
+        assert_eq!(readme(&refreshed).unwrap(), "# {{name}} v3\n");
+    }
+
+    #[test]
+    fn test_failed_refetch_keeps_cache() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let url = bare_templates_repo(dir.path());
+        let cache = dir.path().join("cache");
+        let remote = RemoteTemplates::new(url, None);
+        let templates = remote.fetch(&cache).unwrap();
+
+        fs::remove_dir_all(dir.path().join("remote.git")).unwrap();
+        assert!(remote.refetch(&cache).is_err());
+        assert!(templates.join("base/README.md.hbs").is_file());
+    }
+
+    #[test]
+    fn test_fetch_unreachable_repo() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let cache = dir.path().join("cache");
+        let remote = RemoteTemplates::new(
+            format!("file://{}", dir.path().join("missing.git").display()),
+            None,
+        );
+
+        let err = remote.fetch(&cache).unwrap_err().to_string();
+        assert!(err.contains("Failed to fetch templates"), "{err}");
+        assert!(err.contains("network connection"), "{err}");
+        assert!(!remote.cache_dir(&cache).exists());
+    }
+
+    #[test]
+    fn test_fetch_url_is_not_parsed_as_option() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let cache = dir.path().join("cache");
+        let remote = RemoteTemplates::new("--upload-pack=touch pwned", None);
+
+        let err = remote.fetch(&cache).unwrap_err().to_string();
+        assert!(
+            err.contains("'--upload-pack=touch pwned' does not exist"),
+            "{err}"
+        );
+        assert!(!remote.cache_dir(&cache).exists());
+    }
+
+    #[test]
+    fn test_offline_uses_cache_but_never_clones() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let url = bare_templates_repo(dir.path());
+        let cache = dir.path().join("cache");
+        let offline = RemoteTemplates::new(url, None).with_offline(true);
+
+        let err = offline.fetch(&cache).unwrap_err().to_string();
+        assert!(err.contains("offline"), "{err}");
+        assert!(!cache.exists());
+
+        let templates = offline.clone().with_offline(false).fetch(&cache).unwrap();
+        assert_eq!(offline.fetch(&cache).unwrap(), templates);
+
+        push_readme(dir.path(), "# {{name}} v3\n");
+        assert!(offline.refetch(&cache).is_err());
+        assert_eq!(
+            fs::read_to_string(templates.join("base/README.md.hbs")).unwrap(),
+            "# {{name}} v2\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_repo_without_templates() {
+        // Skip under Miri
+        if cfg!(miri) {
+            eprintln!("Skipping git test under Miri");
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let url = bare_templates_repo(dir.path());
+        let remote = RemoteTemplates::new(url, None);
+        let cache = dir.path().join("cache");
+        fs::create_dir_all(remote.cache_dir(&cache)).unwrap();
+
+        let err = remote.fetch(&cache).unwrap_err().to_string();
+        assert!(err.contains("no 'templates/' directory"), "{err}");
+    }
+}
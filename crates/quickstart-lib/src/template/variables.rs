@@ -5,10 +5,13 @@
 
 use chrono::{Datelike, Local};
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::ProjectConfig;
-use crate::ProjectType;
+use super::cargo_generate::PlaceholderValue;
+use super::features::{is_feature_selected, FEATURES};
+use super::TemplateVariant;
+use crate::{DependencySpec, ProjectConfig};
 
 /// Variables available for template substitution
 #[derive(Debug, Clone, Serialize)]
@@ -16,6 +19,12 @@ pub struct TemplateVariables {
     /// Project name
     pub name: String,
 
+    /// Project name as a Rust identifier (dashes replaced with underscores)
+    pub crate_name: String,
+
+    /// Name of the binary target; the project name unless overridden
+    pub bin_name: String,
+
     /// Project description (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -43,6 +52,32 @@ pub struct TemplateVariables {
 
     /// Template variant flags
     pub template: TemplateFlags,
+
+    /// Whether each optional feature is selected, keyed by name (e.g. `features.ci`)
+    pub features: BTreeMap<&'static str, bool>,
+
+    /// Toolchain channel pinned in `rust-toolchain.toml`
+    pub toolchain: String,
+
+    /// Minimum supported Rust version (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msrv: Option<String>,
+
+    /// crates.io keywords
+    pub keywords: Vec<String>,
+
+    /// crates.io category slugs
+    pub categories: Vec<String>,
+
+    /// Extra `[dependencies]` entries
+    pub dependencies: Vec<DependencySpec>,
+
+    /// Extra `[dev-dependencies]` entries
+    pub dev_dependencies: Vec<DependencySpec>,
+
+    /// Extra top-level variables, such as cargo-generate's `project-name` and placeholders
+    #[serde(flatten)]
+    pub placeholders: BTreeMap<String, PlaceholderValue>,
 }
 
 /// Information about the project author
@@ -64,6 +99,9 @@ pub struct ProjectFlags {
 
     /// Whether the project is a library crate
     pub is_library: bool,
+
+    /// Whether the project targets the 2024 edition
+    pub is_edition_2024: bool,
 }
 
 /// Git configuration flags
@@ -114,20 +152,33 @@ impl TemplateVariables {
             .as_secs();
 
         // Create project type flags
-        let is_binary = matches!(config.project_type, ProjectType::Binary);
-        let is_library = matches!(config.project_type, ProjectType::Library);
+        let is_binary = config.project_type.has_binary();
+        let is_library = config.project_type.has_library();
+
+        // Create template variant flags
+        let is_minimal = matches!(config.variant, TemplateVariant::Minimal);
+        let is_extended = matches!(config.variant, TemplateVariant::Extended);
 
-        // Default to extended templates
-        let is_extended = true;
-        let is_minimal = !is_extended;
+        // TODO: Get default author information from Git config or environment
+        let author_name = config
+            .author_name
+            .clone()
+            .unwrap_or_else(|| "Your Name".to_string());
+        let author_email = config
+            .author_email
+            .clone()
+            .or_else(|| Some("your.email@example.com".to_string()));
 
-        // TODO: Get author information from Git config or environment
-        let author_name = "Your Name".to_string();
-        let author_email = Some("your.email@example.com".to_string());
+        let selected = config.selected_features();
 
         Self {
             name: config.name.clone(),
-            description: None, // TODO: Add description to config or prompt for it
+            crate_name: config.name.replace('-', "_"),
+            bin_name: config
+                .bin_name
+                .clone()
+                .unwrap_or_else(|| config.name.clone()),
+            description: config.description.clone(),
             version: "0.1.0".to_string(),
             edition: config.edition.clone(),
             author: Author {
@@ -138,6 +189,7 @@ impl TemplateVariables {
             project: ProjectFlags {
                 is_binary,
                 is_library,
+                is_edition_2024: config.edition == "2024",
             },
             git: GitConfig {
                 initialize: config.git,
@@ -152,6 +204,20 @@ impl TemplateVariables {
                 is_minimal,
                 is_extended,
             },
+            features: FEATURES
+                .iter()
+                .map(|f| (f.name, is_feature_selected(f, selected.as_deref())))
+                .collect(),
+            toolchain: config
+                .toolchain
+                .clone()
+                .unwrap_or_else(|| "stable".to_string()),
+            msrv: config.msrv.clone(),
+            keywords: config.keywords.clone(),
+            categories: config.categories.clone(),
+            dependencies: config.dependencies.clone(),
+            dev_dependencies: config.dev_dependencies.clone(),
+            placeholders: BTreeMap::new(),
         }
     }
 
@@ -168,6 +234,8 @@ impl TemplateVariables {
 
         Self {
             name: "test-project".to_string(),
+            crate_name: "test_project".to_string(),
+            bin_name: "test-project".to_string(),
             description: Some("A test project".to_string()),
             version: "0.1.0".to_string(),
             edition: "2021".to_string(),
@@ -179,6 +247,7 @@ impl TemplateVariables {
             project: ProjectFlags {
                 is_binary: true,
                 is_library: false,
+                is_edition_2024: false,
             },
             git: GitConfig {
                 initialize: true,
@@ -193,6 +262,14 @@ impl TemplateVariables {
                 is_minimal: false,
                 is_extended: true,
             },
+            features: FEATURES.iter().map(|f| (f.name, f.default)).collect(),
+            toolchain: "stable".to_string(),
+            msrv: None,
+            keywords: Vec::new(),
+            categories: Vec::new(),
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            placeholders: BTreeMap::new(),
         }
     }
 }
@@ -1,9 +1,16 @@
 //! Handlebars template helpers
 //!
 //! This module provides custom helpers for use in handlebars templates,
-//! such as text case conversion utilities.
-
-use handlebars::{Context, Helper, HelperDef, Output, RenderContext, RenderError};
+//! such as text case conversion utilities and comparisons.
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Local, TimeZone,
+};
+use handlebars::{
+    Context, Helper, HelperDef, JsonValue, Output, RenderContext, RenderError, Renderable,
+    ScopedJson,
+};
 use thiserror::Error;
 
 #[allow(dead_code)]
@@ -125,6 +132,287 @@ impl HelperDef for KebabCaseHelper {
     }
 }
 
+/// Compare the first two params of `h` for equality
+fn params_equal(h: &Helper<'_>, name: &'static str) -> Result<bool, RenderError> {
+    let lhs = h
+        .param(0)
+        .ok_or(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+            name, 0,
+        ))?;
+    let rhs = h
+        .param(1)
+        .ok_or(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+            name, 1,
+        ))?;
+
+    Ok(lhs.value() == rhs.value())
+}
+
+/// Render a comparison: the block or its `{{else}}` when used as a block, the boolean otherwise
+fn render_comparison<'reg: 'rc, 'rc>(
+    value: bool,
+    h: &Helper<'rc>,
+    r: &'reg handlebars::Handlebars<'reg>,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+    out: &mut dyn Output,
+) -> handlebars::HelperResult {
+    if !h.is_block() {
+        out.write(&value.to_string())?;
+        return Ok(());
+    }
+
+    match if value { h.template() } else { h.inverse() } {
+        Some(template) => template.render(r, ctx, rc, out),
+        None => Ok(()),
+    }
+}
+
+/// Helper comparing two values for equality, as a block or a subexpression
+#[derive(Clone, Copy)]
+pub struct EqHelper;
+
+impl HelperDef for EqHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        Ok(ScopedJson::Derived(JsonValue::Bool(params_equal(h, "eq")?)))
+    }
+
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg handlebars::Handlebars<'reg>,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> handlebars::HelperResult {
+        render_comparison(params_equal(h, "eq")?, h, r, ctx, rc, out)
+    }
+}
+
+/// Helper comparing two values for inequality, as a block or a subexpression
+#[derive(Clone, Copy)]
+pub struct NeHelper;
+
+impl HelperDef for NeHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        Ok(ScopedJson::Derived(JsonValue::Bool(!params_equal(
+            h, "ne",
+        )?)))
+    }
+
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg handlebars::Handlebars<'reg>,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> handlebars::HelperResult {
+        render_comparison(!params_equal(h, "ne")?, h, r, ctx, rc, out)
+    }
+}
+
+/// Helper choosing between a singular and plural word: `{{pluralize count "item" "items"}}`
+#[derive(Clone, Copy)]
+pub struct PluralizeHelper;
+
+impl HelperDef for PluralizeHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> handlebars::HelperResult {
+        let param = |index| {
+            h.param(index).ok_or_else(|| {
+                RenderError::from(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+                    "pluralize",
+                    index,
+                ))
+            })
+        };
+
+        // Non-numeric counts fall back to the plural form
+        let is_one = param(0)?.value().as_f64() == Some(1.0);
+        let word = if is_one { param(1)? } else { param(2)? };
+
+        out.write(word.value().as_str().unwrap_or_default())?;
+
+        Ok(())
+    }
+}
+
+/// Helper formatting the current date with a strftime pattern: `{{date_format "%B %Y"}}`
+///
+/// The date comes from `date.timestamp` in the context when present, so it agrees with
+/// the other `date.*` variables, and from the helper's clock otherwise.
+#[derive(Clone, Copy)]
+pub struct DateFormatHelper {
+    clock: fn() -> DateTime<Local>,
+}
+
+impl DateFormatHelper {
+    /// Create a helper that reads the time from `clock` instead of the system clock
+    pub fn with_clock(clock: fn() -> DateTime<Local>) -> Self {
+        Self { clock }
+    }
+}
+
+impl Default for DateFormatHelper {
+    fn default() -> Self {
+        Self::with_clock(Local::now)
+    }
+}
+
+impl HelperDef for DateFormatHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        ctx: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> handlebars::HelperResult {
+        let param = h.param(0).ok_or_else(|| {
+            RenderError::from(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+                "date_format",
+                0,
+            ))
+        })?;
+        let pattern = param
+            .value()
+            .as_str()
+            .ok_or(handlebars::RenderErrorReason::InvalidParamType("string"))?;
+
+        let items: Vec<Item<'_>> = StrftimeItems::new(pattern).collect();
+        if items.contains(&Item::Error) {
+            return Err(handlebars::RenderErrorReason::Other(format!(
+                "Invalid date format pattern: {pattern}"
+            ))
+            .into());
+        }
+
+        let now = ctx
+            .data()
+            .pointer("/date/timestamp")
+            .and_then(JsonValue::as_i64)
+            .and_then(|timestamp| Local.timestamp_opt(timestamp, 0).single())
+            .unwrap_or_else(self.clock);
+
+        out.write(&now.format_with_items(items.into_iter()).to_string())?;
+
+        Ok(())
+    }
+}
+
+/// Helper limiting text to a number of characters: `{{truncate description 200}}`
+///
+/// Cut text ends in `…`, which counts towards the limit. A missing limit leaves the
+/// text unchanged and non-string input renders as an empty string. Also usable as a
+/// subexpression, e.g. `{{toml_string (truncate description 200)}}`.
+#[derive(Clone, Copy)]
+pub struct TruncateHelper;
+
+impl HelperDef for TruncateHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let param = h.param(0).ok_or_else(|| {
+            RenderError::from(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+                "truncate", 0,
+            ))
+        })?;
+
+        let input = param.value().as_str().unwrap_or_default();
+        let value = match h.param(1).and_then(|limit| limit.value().as_u64()) {
+            Some(limit) => truncate(input, usize::try_from(limit).unwrap_or(usize::MAX)),
+            None => input.to_string(),
+        };
+
+        Ok(ScopedJson::Derived(JsonValue::String(value)))
+    }
+}
+
+/// Helper escaping text for the inside of a TOML basic string:
+/// `description = "{{toml_string description}}"`
+///
+/// Non-string input renders as an empty string.
+#[derive(Clone, Copy)]
+pub struct TomlStringHelper;
+
+impl HelperDef for TomlStringHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg handlebars::Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> handlebars::HelperResult {
+        let param = h.param(0).ok_or_else(|| {
+            RenderError::from(handlebars::RenderErrorReason::ParamNotFoundForIndex(
+                "toml_string",
+                0,
+            ))
+        })?;
+
+        out.write(&toml_escape(param.value().as_str().unwrap_or_default()))?;
+
+        Ok(())
+    }
+}
+
+/// Escape backslashes, quotes and control characters so `input` can be placed
+/// between the quotes of a TOML basic string
+pub fn toml_escape(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{c}' => result.push_str("\\f"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04X}", u32::from(c))),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Truncate `input` to at most `limit` characters, ending cut text with `…`
+pub fn truncate(input: &str, limit: usize) -> String {
+    if input.chars().count() <= limit {
+        return input.to_string();
+    }
+
+    let mut result: String = input.chars().take(limit.saturating_sub(1)).collect();
+    if limit > 0 {
+        result.push('…');
+    }
+    result
+}
+
 /// Convert a string to snake_case
 pub fn to_snake_case(input: &str) -> String {
     let mut result = String::new();
@@ -276,6 +564,261 @@ mod tests {
         }
     }
 
+    fn comparison_registry() -> Handlebars<'static> {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("eq", Box::new(EqHelper));
+        handlebars.register_helper("ne", Box::new(NeHelper));
+        handlebars
+    }
+
+    #[test]
+    fn test_eq_helper_subexpression() {
+        let handlebars = comparison_registry();
+        let template = r#"{{#if (eq edition "2021")}}current{{else}}other{{/if}}"#;
+
+        for (edition, expected) in [("2021", "current"), ("2024", "other")] {
+            let mut data = std::collections::HashMap::new();
+            data.insert("edition", edition);
+            let result = handlebars.render_template(template, &data).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_ne_helper_subexpression() {
+        let handlebars = comparison_registry();
+        let template = r#"{{#if (ne license "MIT")}}not mit{{else}}mit{{/if}}"#;
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("license", "Apache-2.0");
+        assert_eq!(
+            handlebars.render_template(template, &data).unwrap(),
+            "not mit"
+        );
+
+        data.insert("license", "MIT");
+        assert_eq!(handlebars.render_template(template, &data).unwrap(), "mit");
+    }
+
+    #[test]
+    fn test_comparison_helpers_as_blocks() {
+        let handlebars = comparison_registry();
+        let mut data = std::collections::HashMap::new();
+        data.insert("edition", "2024");
+
+        let eq = r#"{{#eq edition "2024"}}yes{{else}}no{{/eq}}"#;
+        let ne = r#"{{#ne edition "2024"}}yes{{else}}no{{/ne}}"#;
+        assert_eq!(handlebars.render_template(eq, &data).unwrap(), "yes");
+        assert_eq!(handlebars.render_template(ne, &data).unwrap(), "no");
+
+        // Inline use writes the boolean
+        let inline = r#"{{eq edition "2021"}}"#;
+        assert_eq!(handlebars.render_template(inline, &data).unwrap(), "false");
+    }
+
+    #[test]
+    fn test_comparison_helpers_missing_param() {
+        let handlebars = comparison_registry();
+        let mut data = std::collections::HashMap::new();
+        data.insert("edition", "2024");
+
+        assert!(handlebars.render_template("{{eq edition}}", &data).is_err());
+        assert!(handlebars
+            .render_template("{{#if (ne edition)}}x{{/if}}", &data)
+            .is_err());
+    }
+
+    #[test]
+    fn test_pluralize_helper() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("pluralize", Box::new(PluralizeHelper));
+
+        let template = r#"{{count}} {{pluralize count "item" "items"}}"#;
+        for (count, expected) in [
+            (0, "0 items"),
+            (1, "1 item"),
+            (2, "2 items"),
+            (42, "42 items"),
+        ] {
+            let mut data = std::collections::HashMap::new();
+            data.insert("count", count);
+            let result = handlebars.render_template(template, &data).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_pluralize_helper_non_numeric() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("pluralize", Box::new(PluralizeHelper));
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("count", "one");
+        let result = handlebars
+            .render_template(r#"{{pluralize count "item" "items"}}"#, &data)
+            .unwrap();
+        assert_eq!(result, "items");
+
+        // Only the form that is chosen has to be present
+        assert!(handlebars
+            .render_template(r#"{{pluralize 1 "item"}}"#, &data)
+            .is_ok());
+        assert!(handlebars
+            .render_template(r#"{{pluralize 2 "item"}}"#, &data)
+            .is_err());
+    }
+
+    fn fixed_clock() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_date_format_helper_with_clock() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper(
+            "date_format",
+            Box::new(DateFormatHelper::with_clock(fixed_clock)),
+        );
+
+        let data = std::collections::HashMap::<&str, &str>::new();
+        let result = handlebars
+            .render_template(
+                r#"{{date_format "%B %Y"}} / {{date_format "%Y-%m-%d"}}"#,
+                &data,
+            )
+            .unwrap();
+        assert_eq!(result, "June 2024 / 2024-06-15");
+    }
+
+    #[test]
+    fn test_date_format_helper_uses_context_timestamp() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper(
+            "date_format",
+            Box::new(DateFormatHelper::with_clock(fixed_clock)),
+        );
+
+        // Midday UTC, so the date is the same in every local time zone
+        let timestamp = Local
+            .with_ymd_and_hms(2021, 4, 29, 12, 0, 0)
+            .unwrap()
+            .timestamp();
+        let data = std::collections::HashMap::from([(
+            "date",
+            std::collections::HashMap::from([("timestamp", timestamp)]),
+        )]);
+        let result = handlebars
+            .render_template(r#"{{date_format "%B %Y"}}"#, &data)
+            .unwrap();
+        assert_eq!(result, "April 2021");
+    }
+
+    #[test]
+    fn test_date_format_helper_invalid_pattern() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper(
+            "date_format",
+            Box::new(DateFormatHelper::with_clock(fixed_clock)),
+        );
+
+        let data = std::collections::HashMap::<&str, &str>::new();
+        assert!(handlebars
+            .render_template(r#"{{date_format "%Q"}}"#, &data)
+            .is_err());
+        assert!(handlebars
+            .render_template("{{date_format}}", &data)
+            .is_err());
+        assert!(handlebars
+            .render_template("{{date_format 42}}", &data)
+            .is_err());
+    }
+
+    #[test]
+    fn test_truncate_helper() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("truncate", Box::new(TruncateHelper));
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("value", "A short crate");
+        let render = |template: &str, data: &std::collections::HashMap<&str, &str>| {
+            handlebars.render_template(template, data).unwrap()
+        };
+
+        assert_eq!(render("{{truncate value 20}}", &data), "A short crate");
+        assert_eq!(render("{{truncate value 13}}", &data), "A short crate");
+        assert_eq!(render("{{truncate value 8}}", &data), "A short…");
+        assert_eq!(render("{{truncate value}}", &data), "A short crate");
+        assert_eq!(render("{{truncate value 0}}", &data), "");
+    }
+
+    #[test]
+    fn test_truncate_helper_non_string_param() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("truncate", Box::new(TruncateHelper));
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("value", 12345);
+        let result = handlebars.render_template("{{truncate value 3}}", &data);
+        assert_eq!(result.unwrap(), "");
+
+        assert!(handlebars.render_template("{{truncate}}", &data).is_err());
+    }
+
+    #[test]
+    fn test_truncate_helper_subexpression() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("truncate", Box::new(TruncateHelper));
+        handlebars.register_helper("uppercase", Box::new(UppercaseHelper));
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("value", "A short crate");
+        let result = handlebars.render_template("{{uppercase (truncate value 8)}}", &data);
+        assert_eq!(result.unwrap(), "A SHORT…");
+    }
+
+    #[test]
+    fn test_toml_string_helper() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("toml_string", Box::new(TomlStringHelper));
+        handlebars.register_helper("truncate", Box::new(TruncateHelper));
+
+        let mut data = std::collections::HashMap::new();
+        data.insert("value", r#"Tom's "fast" tool"#);
+        let rendered = handlebars
+            .render_template(
+                r#"description = "{{toml_string (truncate value 200)}}""#,
+                &data,
+            )
+            .unwrap();
+        assert_eq!(rendered, r#"description = "Tom's \"fast\" tool""#);
+
+        let parsed: toml::Table = toml::from_str(&rendered).unwrap();
+        assert_eq!(parsed["description"].as_str(), Some(r#"Tom's "fast" tool"#));
+
+        assert!(handlebars
+            .render_template("{{toml_string}}", &data)
+            .is_err());
+    }
+
+    #[test]
+    fn test_toml_escape() {
+        assert_eq!(toml_escape("plain text"), "plain text");
+        assert_eq!(toml_escape(r"C:\dir"), r"C:\\dir");
+        assert_eq!(toml_escape("line\nnext\ttab"), r"line\nnext\ttab");
+        assert_eq!(toml_escape("bell\u{7}"), r"bell\u0007");
+        assert_eq!(toml_escape("del\u{7f}"), r"del\u007F");
+        assert_eq!(toml_escape("héllo 🦀"), "héllo 🦀");
+    }
+
+    #[test]
+    fn test_truncate_multibyte() {
+        assert_eq!(truncate("héllo wörld", 11), "héllo wörld");
+        assert_eq!(truncate("héllo wörld", 7), "héllo …");
+        assert_eq!(truncate("🦀🦀🦀", 2), "🦀…");
+        assert_eq!(truncate("🦀", 1), "🦀");
+        assert_eq!(truncate("", 0), "");
+    }
+
     #[test]
     fn test_to_snake_case_edge_cases() {
         assert_eq!(to_snake_case("").as_str(), "");
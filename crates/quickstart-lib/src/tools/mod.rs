@@ -3,6 +3,28 @@
 mod helpers;
 
 pub use helpers::{
-    to_kebab_case, to_snake_case, KebabCaseHelper, LowercaseHelper, SnakeCaseHelper,
-    UppercaseHelper,
+    to_kebab_case, to_snake_case, toml_escape, truncate, DateFormatHelper, EqHelper,
+    KebabCaseHelper, LowercaseHelper, NeHelper, PluralizeHelper, SnakeCaseHelper, TomlStringHelper,
+    TruncateHelper, UppercaseHelper,
 };
+
+/// 64-bit FNV-1a, stable across Rust releases unlike `DefaultHasher`
+///
+/// Used where a hash ends up on disk, such as cache directory and file names.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_reference_values() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}